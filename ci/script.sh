@@ -6,12 +6,25 @@ cargo doc
 
 cargo build --no-default-features
 
-if [ ${TRAVIS_RUST_VERSION} != "1.31.0" ]; then
-  cargo test --no-default-features
+cargo build --no-default-features --features alloc
+
+cargo build --no-default-features --features avx2
+
+cargo build --no-default-features --features avx512vbmi
+
+cargo build --no-default-features --features ssse3
+
+# msp430 has a 16-bit usize, so lengths read from the input, such as SSH
+# field lengths, don't always fit. It has no prebuilt standard library.
+if [ ${TRAVIS_RUST_VERSION} = "nightly" ]; then
+  rustup component add rust-src
+  cargo build -Zbuild-std=core,alloc --target msp430-none-elf --no-default-features --features alloc
 fi
 
+cargo test --no-default-features --features std
+
 cargo build
 
-if [ ${TRAVIS_RUST_VERSION} != "1.31.0" ]; then
-  cargo test
-fi
+cargo test
+
+cargo test --all-features
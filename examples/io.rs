@@ -6,7 +6,7 @@
 /// foo
 ///
 use radix64::{
-    io::{DecodeReader, EncodeWriter},
+    io::{copy_decode, copy_encode},
     STD as MY_CONFIG,
 };
 use std::{env, error::Error, io, iter::FromIterator};
@@ -34,20 +34,15 @@ impl FromIterator<String> for Mode {
     }
 }
 
-fn main() -> Result<(), Box<Error>> {
+fn main() -> Result<(), Box<dyn Error>> {
     let mode: Mode = env::args().collect();
 
     match mode {
         Mode::Encode => {
-            let mut dst = EncodeWriter::new(MY_CONFIG, io::stdout());
-            let mut src = io::stdin();
-            io::copy(&mut src, &mut dst)?;
-            dst.finish()?;
+            copy_encode(MY_CONFIG, &mut io::stdin().lock(), &mut io::stdout().lock())?;
         }
         Mode::Decode => {
-            let mut dst = io::stdout();
-            let mut src = DecodeReader::new(MY_CONFIG, io::stdin());
-            io::copy(&mut src, &mut dst)?;
+            copy_decode(MY_CONFIG, &mut io::stdin().lock(), &mut io::stdout().lock())?;
         }
     }
     Ok(())
@@ -0,0 +1,14 @@
+//! Low level SIMD kernels for building fused pipelines.
+//!
+//! These expose the block kernels used internally by the builtin configs, so
+//! code that already holds data in vector registers, such as a cipher
+//! producing a keystream, can encode or decode it without a round trip through
+//! memory. Most users should prefer the methods on
+//! [Config](../trait.Config.html), which handle input of any length, padding,
+//! and CPU feature detection.
+//!
+//! The functions in this module are tied to the internal layout of the
+//! kernels and are **not covered by semver**. They may change or be removed
+//! in any release.
+
+pub mod avx2;
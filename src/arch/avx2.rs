@@ -0,0 +1,79 @@
+//! The AVX2 kernels. Only available on x86 and x86_64 with the `avx2` feature.
+//!
+//! Each function processes a single block held in a `__m256i`. Callers must
+//! verify that the CPU supports AVX2, for example with
+//! `is_x86_feature_detected!("avx2")`, before calling them.
+//!
+//! ```
+//! # #[cfg(target_arch = "x86")]
+//! # use std::arch::x86::*;
+//! # #[cfg(target_arch = "x86_64")]
+//! # use std::arch::x86_64::*;
+//! use radix64::{arch::avx2, STD};
+//!
+//! if is_x86_feature_detected!("avx2") {
+//!     let input: Vec<u8> = (0..24).collect();
+//!     let mut encoded = [0u8; 32];
+//!     unsafe {
+//!         let lo = _mm_loadu_si128(input.as_ptr() as *const __m128i);
+//!         let hi = _mm_loadu_si128(input[8..].as_ptr() as *const __m128i);
+//!         // Move the second 12 bytes to the start of the high lane.
+//!         let hi = _mm_srli_si128(hi, 4);
+//!         let block = avx2::encode_block(STD, _mm256_set_m128i(hi, lo));
+//!         _mm256_storeu_si256(encoded.as_mut_ptr() as *mut __m256i, block);
+//!
+//!         let decoded = avx2::decode_block(STD, block).unwrap();
+//!         let mut output = [0u8; 32];
+//!         _mm256_storeu_si256(output.as_mut_ptr() as *mut __m256i, decoded);
+//!         assert_eq!(&output[..24], &input[..]);
+//!     }
+//!     assert_eq!(&encoded[..], STD.encode(&input).as_bytes());
+//! }
+//! ```
+#[cfg(target_arch = "x86")]
+use core::arch::x86::__m256i;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::__m256i;
+
+use crate::decode::block::arch::x86::avx2::Translate256i as DecodeTranslate;
+use crate::encode::block::arch::x86::avx2::Translate256i as EncodeTranslate;
+use crate::Config;
+
+/// The configs that have AVX2 kernels. This is implemented for each of the
+/// builtin configs and cannot be implemented outside of this crate.
+pub trait Avx2Config: Config + EncodeTranslate + DecodeTranslate {}
+
+impl<C> Avx2Config for C where C: Config + EncodeTranslate + DecodeTranslate {}
+
+/// Encode 24 bytes of input into 32 bytes of base64.
+///
+/// The first 12 bytes of input are read from bytes 0 through 11 of the low
+/// 128-bit lane, and the next 12 bytes from bytes 0 through 11 of the high
+/// lane. The remaining bytes of each lane are ignored. All 32 bytes of the
+/// result are encoded characters in order. No padding is produced.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+#[inline]
+pub unsafe fn encode_block<C: Avx2Config>(config: C, input: __m256i) -> __m256i {
+    crate::encode::block::arch::x86::avx2::encode_block(config, input)
+}
+
+/// Decode 32 bytes of base64 into 24 bytes of output.
+///
+/// The decoded bytes are stored in order in bytes 0 through 23 of the result.
+/// The final 8 bytes of the result are unspecified. If any of the input bytes
+/// is not part of the config's alphabet, including padding, the index of the
+/// first such byte is returned as the error.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+#[inline]
+pub unsafe fn decode_block<C: Avx2Config>(config: C, input: __m256i) -> Result<__m256i, usize> {
+    crate::decode::block::arch::x86::avx2::decode_block(config, input)
+        .map_err(|invalid_mask| invalid_mask.trailing_zeros() as usize)
+}
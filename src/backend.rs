@@ -0,0 +1,304 @@
+//! Reporting and overriding which implementation is used to encode and decode.
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::{error, fmt};
+
+/// An implementation used to encode and decode the builtin configs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Backend {
+    /// The portable implementation used on all platforms.
+    Scalar,
+    /// The x86 and x86_64 AVX2 implementation.
+    Avx2,
+    /// The x86 and x86_64 AVX-512 VBMI implementation, which uses AVX2 for
+    /// input too short to fill a 512-bit block.
+    Avx512Vbmi,
+    /// The x86 and x86_64 SSSE3 implementation, for CPUs without AVX2.
+    Ssse3,
+}
+
+impl Backend {
+    /// A short name for the backend, suitable for logging.
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Scalar => "scalar",
+            Backend::Avx2 => "avx2",
+            Backend::Avx512Vbmi => "avx512vbmi",
+            Backend::Ssse3 => "ssse3",
+        }
+    }
+
+    /// Whether this backend was compiled in and is supported by the CPU. When
+    /// the target features enabled at compile time select the backend, it's
+    /// the only one supported.
+    pub fn is_supported(self) -> bool {
+        if let Some(backend) = STATIC_BACKEND {
+            return self == backend;
+        }
+        match self {
+            Backend::Scalar => true,
+            Backend::Avx2 => avx2_detected(),
+            Backend::Avx512Vbmi => avx512vbmi_detected(),
+            Backend::Ssse3 => ssse3_detected(),
+        }
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    const fn to_u8(self) -> u8 {
+        match self {
+            Backend::Scalar => 1,
+            Backend::Avx2 => 2,
+            Backend::Avx512Vbmi => 3,
+            Backend::Ssse3 => 4,
+        }
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    fn from_u8(v: u8) -> Option<Backend> {
+        match v {
+            1 => Some(Backend::Scalar),
+            2 => Some(Backend::Avx2),
+            3 => Some(Backend::Avx512Vbmi),
+            4 => Some(Backend::Ssse3),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// UnsupportedBackend is returned from `set_backend` when the requested backend
+/// was not compiled in, is not supported by the CPU, or differs from the
+/// backend selected at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedBackend(Backend);
+
+impl UnsupportedBackend {
+    /// The backend that was requested.
+    pub fn backend(&self) -> Backend {
+        self.0
+    }
+}
+
+impl fmt::Display for UnsupportedBackend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the {} backend is not supported on this host", self.0)
+    }
+}
+
+impl error::Error for UnsupportedBackend {}
+
+// The backend selected at compile time, if any. This is the widest backend
+// that is compiled in and that the target features enabled at compile time,
+// for example with `-C target-cpu=native`, guarantee every CPU the crate runs
+// on supports. Its kernels are then called directly, without detecting CPU
+// features or loading ACTIVE_BACKEND, and it can't be overridden.
+const STATIC_BACKEND: Option<Backend> = if cfg!(all(
+    feature = "avx512vbmi",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx512vbmi",
+    target_feature = "avx512bw",
+    target_feature = "avx2"
+)) {
+    Some(Backend::Avx512Vbmi)
+} else if cfg!(all(
+    feature = "avx2",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+)) {
+    Some(Backend::Avx2)
+} else if cfg!(all(
+    feature = "ssse3",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+)) {
+    Some(Backend::Ssse3)
+} else {
+    None
+};
+
+// The backend selected by set_backend, or the automatically selected backend
+// once it is known, or 0 if neither is. Unused when STATIC_BACKEND is set.
+// Otherwise the features are detected once and cached, and the dispatch on
+// every encode and decode is a single load. Targets without atomics, such as
+// msp430 and avr, have no SIMD backends, so they always use the scalar one.
+#[cfg(target_has_atomic = "8")]
+static ACTIVE_BACKEND: AtomicU8 = AtomicU8::new(0);
+
+/// Returns the backend used to encode and decode the builtin configs on this
+/// host. Unless overridden with [set_backend](fn.set_backend.html) this is
+/// the fastest backend that was compiled in and is supported by the CPU,
+/// which makes it useful to log at startup.
+///
+/// `CustomConfig` always uses the scalar backend.
+///
+/// CPU features are only detected at runtime with the `std` feature. Without
+/// it, a SIMD backend is used only when its target feature is enabled at
+/// compile time.
+///
+/// ```
+/// println!("radix64 is using the {} backend", radix64::active_backend());
+/// ```
+#[inline]
+pub fn active_backend() -> Backend {
+    if let Some(backend) = STATIC_BACKEND {
+        return backend;
+    }
+    #[cfg(target_has_atomic = "8")]
+    match Backend::from_u8(ACTIVE_BACKEND.load(Ordering::Relaxed)) {
+        Some(backend) => backend,
+        None => cache_detected_backend(),
+    }
+    #[cfg(not(target_has_atomic = "8"))]
+    Backend::Scalar
+}
+
+#[cfg(target_has_atomic = "8")]
+#[cold]
+fn cache_detected_backend() -> Backend {
+    let detected = detect_backend();
+    // Don't replace a backend stored by a concurrent call to set_backend.
+    match ACTIVE_BACKEND.compare_exchange(0, detected.to_u8(), Ordering::Relaxed, Ordering::Relaxed)
+    {
+        Ok(_) => detected,
+        Err(current) => Backend::from_u8(current).unwrap_or(detected),
+    }
+}
+
+#[cfg(target_has_atomic = "8")]
+fn detect_backend() -> Backend {
+    if avx512vbmi_detected() {
+        Backend::Avx512Vbmi
+    } else if avx2_detected() {
+        Backend::Avx2
+    } else if ssse3_detected() {
+        Backend::Ssse3
+    } else {
+        Backend::Scalar
+    }
+}
+
+/// Force the builtin configs to use the provided backend for all subsequent
+/// encoding and decoding in this process. This is intended for benchmarking,
+/// debugging, and reproducing results across hosts with different CPUs.
+/// Returns an error, leaving the current backend unchanged, if the backend is
+/// not supported on this host. A backend selected at compile time, for example
+/// with `-C target-cpu=native`, can't be overridden, so every other backend
+/// is rejected.
+///
+/// ```
+/// use radix64::{Backend, STD};
+///
+/// if radix64::set_backend(Backend::Scalar).is_ok() {
+///     assert_eq!(radix64::active_backend(), Backend::Scalar);
+/// }
+/// assert_eq!(STD.encode("hello"), "aGVsbG8=");
+/// radix64::reset_backend();
+/// ```
+pub fn set_backend(backend: Backend) -> Result<(), UnsupportedBackend> {
+    if !backend.is_supported() {
+        return Err(UnsupportedBackend(backend));
+    }
+    #[cfg(target_has_atomic = "8")]
+    ACTIVE_BACKEND.store(backend.to_u8(), Ordering::Relaxed);
+    Ok(())
+}
+
+/// Remove any override set by [set_backend](fn.set_backend.html), returning
+/// to automatically selecting the backend.
+pub fn reset_backend() {
+    #[cfg(target_has_atomic = "8")]
+    ACTIVE_BACKEND.store(0, Ordering::Relaxed);
+}
+
+// Whether the AVX2 kernels should be used. The AVX-512 VBMI backend also uses
+// them for blocks that are too short for its own kernels.
+#[cfg(all(feature = "avx2", any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline]
+pub(crate) fn use_avx2() -> bool {
+    match active_backend() {
+        Backend::Avx2 | Backend::Avx512Vbmi => true,
+        Backend::Scalar | Backend::Ssse3 => false,
+    }
+}
+
+// Whether the SSSE3 backend should be used.
+#[cfg(all(feature = "ssse3", any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline]
+pub(crate) fn use_ssse3() -> bool {
+    active_backend() == Backend::Ssse3
+}
+
+// Whether the AVX-512 VBMI backend should be used.
+#[cfg(all(
+    feature = "avx512vbmi",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[inline]
+pub(crate) fn use_avx512vbmi() -> bool {
+    active_backend() == Backend::Avx512Vbmi
+}
+
+fn avx2_detected() -> bool {
+    #[cfg(all(
+        feature = "std",
+        feature = "avx2",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(all(
+        feature = "std",
+        feature = "avx2",
+        any(target_arch = "x86", target_arch = "x86_64")
+    )))]
+    {
+        false
+    }
+}
+
+fn avx512vbmi_detected() -> bool {
+    #[cfg(all(
+        feature = "std",
+        feature = "avx512vbmi",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
+    {
+        std::is_x86_feature_detected!("avx512vbmi")
+            && std::is_x86_feature_detected!("avx512bw")
+            && avx2_detected()
+    }
+    #[cfg(not(all(
+        feature = "std",
+        feature = "avx512vbmi",
+        any(target_arch = "x86", target_arch = "x86_64")
+    )))]
+    {
+        false
+    }
+}
+
+fn ssse3_detected() -> bool {
+    #[cfg(all(
+        feature = "std",
+        feature = "ssse3",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
+    {
+        std::is_x86_feature_detected!("ssse3")
+    }
+    #[cfg(not(all(
+        feature = "std",
+        feature = "ssse3",
+        any(target_arch = "x86", target_arch = "x86_64")
+    )))]
+    {
+        false
+    }
+}
@@ -0,0 +1,119 @@
+//! Building and parsing HTTP `Authorization` header values for the `Basic`
+//! scheme described in [RFC 7617](https://tools.ietf.org/html/rfc7617).
+//!
+//! The credentials are the user-id and password joined by a colon, encoded
+//! as UTF-8 using the [STD](../constant.STD.html) config.
+//!
+//! ```
+//! use radix64::basic_auth;
+//!
+//! let header = basic_auth::encode("Aladdin", "open sesame").unwrap();
+//! assert_eq!(header, "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==");
+//! let (user, pass) = basic_auth::decode(&header).unwrap();
+//! assert_eq!(user, "Aladdin");
+//! assert_eq!(pass, "open sesame");
+//! ```
+//!
+//! The password may contain colons, since the credentials are split at the
+//! first one. The user-id can't, so `encode` rejects user-ids containing a
+//! colon rather than producing credentials that decode differently.
+use crate::{DecodeError, STD};
+use alloc::{borrow::ToOwned, string::String};
+use core::{error, fmt};
+
+const SCHEME: &str = "Basic";
+
+/// Errors that can occur when building or parsing `Basic` credentials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BasicAuthError {
+    /// The user-id contains a colon, which would be mistaken for the
+    /// separator between the user-id and password.
+    ColonInUserId,
+    /// The header value does not use the `Basic` scheme.
+    UnsupportedScheme,
+    /// The credentials are not valid base64.
+    Decode(DecodeError),
+    /// The decoded credentials are not valid UTF-8.
+    InvalidUtf8,
+    /// The decoded credentials do not contain a colon separating the user-id
+    /// from the password.
+    MissingColon,
+}
+
+impl fmt::Display for BasicAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BasicAuthError::ColonInUserId => write!(f, "user-id contains a colon"),
+            BasicAuthError::UnsupportedScheme => {
+                write!(f, "authorization scheme is not \"Basic\"")
+            }
+            BasicAuthError::Decode(ref err) => write!(f, "invalid credentials: {}", err),
+            BasicAuthError::InvalidUtf8 => write!(f, "credentials are not valid UTF-8"),
+            BasicAuthError::MissingColon => write!(f, "credentials do not contain a colon"),
+        }
+    }
+}
+
+impl error::Error for BasicAuthError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            BasicAuthError::Decode(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Build an `Authorization` header value from a user-id and password.
+/// Returns `BasicAuthError::ColonInUserId` if the user-id contains a colon.
+pub fn encode(user_id: &str, password: &str) -> Result<String, BasicAuthError> {
+    if user_id.contains(':') {
+        return Err(BasicAuthError::ColonInUserId);
+    }
+    let mut credentials = String::with_capacity(user_id.len() + 1 + password.len());
+    credentials.push_str(user_id);
+    credentials.push(':');
+    credentials.push_str(password);
+    let mut header =
+        String::with_capacity(SCHEME.len() + 1 + STD.encoded_output_len(credentials.len()));
+    header.push_str(SCHEME);
+    header.push(' ');
+    STD.encode_append(&credentials, &mut header);
+    Ok(header)
+}
+
+/// Parse an `Authorization` header value into its user-id and password.
+///
+/// The scheme is matched case-insensitively and surrounding whitespace is
+/// ignored, as HTTP permits.
+///
+/// ```
+/// use radix64::basic_auth::{self, BasicAuthError};
+///
+/// let (user, pass) = basic_auth::decode("basic  dXNlcjpwYTpzcw==").unwrap();
+/// assert_eq!((user.as_str(), pass.as_str()), ("user", "pa:ss"));
+/// assert_eq!(
+///     basic_auth::decode("Bearer dXNlcjpwYTpzcw=="),
+///     Err(BasicAuthError::UnsupportedScheme)
+/// );
+/// ```
+pub fn decode(header_value: &str) -> Result<(String, String), BasicAuthError> {
+    let header_value = header_value.trim_matches(is_http_whitespace);
+    let (scheme, credentials) = header_value
+        .split_once(is_http_whitespace)
+        .ok_or(BasicAuthError::UnsupportedScheme)?;
+    if !scheme.eq_ignore_ascii_case(SCHEME) {
+        return Err(BasicAuthError::UnsupportedScheme);
+    }
+    let credentials = credentials.trim_start_matches(is_http_whitespace);
+    let decoded = STD.decode(credentials).map_err(BasicAuthError::Decode)?;
+    let decoded = String::from_utf8(decoded).map_err(|_| BasicAuthError::InvalidUtf8)?;
+    let (user_id, password) = decoded
+        .split_once(':')
+        .ok_or(BasicAuthError::MissingColon)?;
+    Ok((user_id.to_owned(), password.to_owned()))
+}
+
+fn is_http_whitespace(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
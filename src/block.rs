@@ -0,0 +1,45 @@
+//! The block layer used by the encoders and decoders. Only available with the
+//! `unstable-block` feature.
+//!
+//! Encoding and decoding hand the bulk of their input to a block encoder or
+//! decoder, which processes as many whole blocks as it can using the fastest
+//! kernel available, and finish the remainder with scalar code. Every config
+//! provides one through [IntoBlockEncoder](trait.IntoBlockEncoder.html) and
+//! [IntoBlockDecoder](trait.IntoBlockDecoder.html). Exposing them allows the
+//! block layer to be benchmarked directly, and allows architecture specific
+//! kernels written outside this crate to be checked against the builtin ones.
+//!
+//! The output of the block layer may be uninitialized. The kernels only
+//! write to it, and report how many bytes they wrote.
+//!
+//! ```
+//! use radix64::block::{BlockDecoder, BlockEncoder, IntoBlockDecoder, IntoBlockEncoder};
+//! use radix64::STD;
+//! use std::mem::MaybeUninit;
+//!
+//! let input: Vec<u8> = (0..100).collect();
+//! let mut encoded = [MaybeUninit::uninit(); 160];
+//! let (input_idx, output_idx) = STD.into_block_encoder().encode_blocks(&input, &mut encoded);
+//! // The first output_idx bytes were written.
+//! let encoded: Vec<u8> = encoded[..output_idx]
+//!     .iter()
+//!     .map(|b| unsafe { b.assume_init() })
+//!     .collect();
+//! assert_eq!(encoded, STD.encode(&input[..input_idx]).into_bytes());
+//!
+//! let mut decoded = [MaybeUninit::uninit(); 120];
+//! let (encoded_idx, decoded_idx) = STD
+//!     .into_block_decoder()
+//!     .decode_blocks(&encoded, &mut decoded)
+//!     .unwrap();
+//! assert!(decoded[..decoded_idx]
+//!     .iter()
+//!     .zip(&input)
+//!     .all(|(b, &expected)| unsafe { b.assume_init() } == expected));
+//! assert!(encoded_idx <= output_idx);
+//! ```
+//!
+//! The traits are tied to the internal layout of the kernels and are **not
+//! covered by semver**. They may change or be removed in any release.
+pub use crate::decode::block::{BlockDecoder, IntoBlockDecoder, ScalarBlockDecoder};
+pub use crate::encode::block::{BlockEncoder, IntoBlockEncoder, ScalarBlockEncoder};
@@ -0,0 +1,131 @@
+//! Parsing base64 command line arguments with [clap](https://docs.rs/clap/4).
+//!
+//! [Base64ValueParser](struct.Base64ValueParser.html) decodes an argument
+//! into a `Vec<u8>`. Invalid arguments are reported with a message naming the
+//! offending character and its position, which is far more useful than a bare
+//! "invalid base64" when pasting keys and nonces on the command line.
+//!
+//! ```
+//! use clap::{Arg, Command};
+//! use radix64::{cli::Base64ValueParser, STD};
+//!
+//! let cmd = Command::new("encrypt")
+//!     .arg(Arg::new("key").long("key").value_parser(Base64ValueParser::new(STD)));
+//!
+//! let matches = cmd.clone().try_get_matches_from(["encrypt", "--key", "c2VjcmV0"]).unwrap();
+//! assert_eq!(matches.get_one::<Vec<u8>>("key").unwrap(), b"secret");
+//!
+//! let err = cmd.try_get_matches_from(["encrypt", "--key", "c2V!cmV0"]).unwrap_err();
+//! assert!(err.to_string().contains("invalid character '!' at position 3"));
+//! ```
+//!
+//! [Base64](../struct.Base64.html) also implements `ValueParserFactory`, so
+//! `value_parser!(Base64<Vec<u8>, UrlSafeNoPad>)` and fields of that type in
+//! clap's derive API decode with the chosen config.
+use crate::{Base64, Config, DecodeError};
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::{format, vec::Vec};
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+use std::ffi::OsStr;
+
+/// A clap value parser that decodes arguments into a `Vec<u8>` using the
+/// provided config.
+#[derive(Debug, Clone, Copy)]
+pub struct Base64ValueParser<C> {
+    config: C,
+}
+
+impl<C> Base64ValueParser<C>
+where
+    C: Config,
+{
+    /// Create a value parser that decodes using the provided config.
+    pub fn new(config: C) -> Self {
+        Base64ValueParser { config }
+    }
+}
+
+impl<C> TypedValueParser for Base64ValueParser<C>
+where
+    C: Config + Send + Sync + 'static,
+{
+    type Value = Vec<u8>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Vec<u8>, clap::Error> {
+        let arg = arg
+            .map(|arg| arg.to_string())
+            .unwrap_or_else(|| "...".to_owned());
+        let value = value.to_str().ok_or_else(|| {
+            clap::Error::raw(
+                ErrorKind::InvalidUtf8,
+                format!("invalid UTF-8 in the value for '{}'\n", arg),
+            )
+            .format(&mut cmd.clone())
+        })?;
+        self.config.decode(value).map_err(|err| {
+            clap::Error::raw(
+                ErrorKind::ValueValidation,
+                format!(
+                    "invalid base64 value '{}' for '{}': {}\n",
+                    value,
+                    arg,
+                    describe_error(self.config, value, &err)
+                ),
+            )
+            .format(&mut cmd.clone())
+        })
+    }
+}
+
+impl<T, C> ValueParserFactory for Base64<T, C>
+where
+    T: Clone + Send + Sync + 'static,
+    Vec<u8>: Into<T>,
+    C: Config + Default + Send + Sync + 'static,
+{
+    type Parser = clap::builder::MapValueParser<Base64ValueParser<C>, fn(Vec<u8>) -> Self>;
+
+    fn value_parser() -> Self::Parser {
+        Base64ValueParser::new(C::default()).map(|decoded| Base64::new(decoded.into()))
+    }
+}
+
+// Describe a decode error in terms of the characters of the input.
+fn describe_error<C: Config>(config: C, value: &str, err: &DecodeError) -> String {
+    match *err {
+        DecodeError::InvalidByte(byte) => {
+            let pos = value
+                .bytes()
+                .position(|b| b == byte)
+                .expect("invalid byte must be in the input");
+            // Non-ascii characters are reported by their first byte.
+            let c = value[pos..].chars().next().unwrap();
+            format!(
+                "invalid character {:?} at position {}",
+                c,
+                value[..pos].chars().count()
+            )
+        }
+        DecodeError::InvalidTrailingBits => {
+            let last = value
+                .bytes()
+                .rposition(|b| config.padding_byte() != Some(b))
+                .unwrap_or(0);
+            format!(
+                "the character {:?} at position {} has non-zero trailing bits",
+                char::from(value.as_bytes()[last]),
+                last
+            )
+        }
+        ref err @ DecodeError::InvalidLength { .. }
+        | ref err @ DecodeError::UnexpectedDecodedLength { .. } => err.to_string(),
+    }
+}
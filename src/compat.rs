@@ -0,0 +1,154 @@
+//! Free functions mirroring those of the [base64](https://docs.rs/base64/0.13)
+//! crate, to ease migrating from it.
+//!
+//! Code written against the base64 crate's `encode_config` style API can
+//! usually switch by changing its imports. The functions accept any radix64
+//! [Config](../trait.Config.html), and the constants below name the builtin
+//! configs as the base64 crate does.
+//!
+//! ```
+//! use radix64::compat::{decode_config, encode_config, URL_SAFE};
+//!
+//! let encoded = encode_config(b"\xfb\xff", URL_SAFE);
+//! assert_eq!(encoded, "-_8=");
+//! assert_eq!(decode_config(&encoded, URL_SAFE).unwrap(), b"\xfb\xff");
+//! ```
+//!
+//! There are a few differences to be aware of:
+//!
+//! * Decoding is strict. Padded configs require padding, unpadded configs
+//!   reject it, and non-zero trailing bits are an error.
+//! * Errors are radix64's [DecodeError](../enum.DecodeError.html), which has
+//!   different variants than the base64 crate's.
+//! * The base64 crate's `MIME` line wrapping is not provided as a constant.
+//!   Use a [CustomConfig](../struct.CustomConfig.html) built with
+//!   `with_line_wrap` instead.
+//!
+//! Code using the `Engine` API of later base64 releases can use the
+//! [engine](engine/index.html) module and [prelude](prelude/index.html)
+//! instead.
+use crate::configs::{Crypt, Std, StdNoPad, UrlSafe, UrlSafeNoPad};
+use crate::Config;
+use alloc::{string::String, vec::Vec};
+
+pub mod engine;
+
+pub use crate::DecodeError;
+
+/// The engine trait and engines, named as in the base64 crate's prelude.
+pub mod prelude {
+    pub use super::engine::general_purpose::{
+        STANDARD as BASE64_STANDARD, STANDARD_NO_PAD as BASE64_STANDARD_NO_PAD,
+        URL_SAFE as BASE64_URL_SAFE, URL_SAFE_NO_PAD as BASE64_URL_SAFE_NO_PAD,
+    };
+    pub use super::engine::Engine;
+}
+
+/// The standard character set with `=` padding.
+pub const STANDARD: Std = Std;
+/// The standard character set without padding.
+pub const STANDARD_NO_PAD: StdNoPad = StdNoPad;
+/// The URL safe character set with `=` padding.
+pub const URL_SAFE: UrlSafe = UrlSafe;
+/// The URL safe character set without padding.
+pub const URL_SAFE_NO_PAD: UrlSafeNoPad = UrlSafeNoPad;
+/// The `crypt(3)` character set without padding.
+pub const CRYPT: Crypt = Crypt;
+
+/// Encode the input using the [STANDARD](constant.STANDARD.html) config.
+pub fn encode<T>(input: &T) -> String
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    STANDARD.encode(input)
+}
+
+/// Encode the input using the provided config.
+pub fn encode_config<T, C>(input: &T, config: C) -> String
+where
+    T: AsRef<[u8]> + ?Sized,
+    C: Config,
+{
+    config.encode(input)
+}
+
+/// Encode the input using the provided config, appending to `buf`.
+pub fn encode_config_buf<T, C>(input: &T, config: C, buf: &mut String)
+where
+    T: AsRef<[u8]> + ?Sized,
+    C: Config,
+{
+    config.encode_append(input, buf)
+}
+
+/// Encode the input using the provided config into the front of `output`,
+/// returning the number of bytes written. Panics if `output` is too small.
+/// Use `encoded_size` to size it.
+pub fn encode_config_slice<T, C>(input: &T, config: C, output: &mut [u8]) -> usize
+where
+    T: AsRef<[u8]> + ?Sized,
+    C: Config,
+{
+    config.encode_slice(input, output)
+}
+
+/// The length of the output produced by encoding `bytes_len` bytes with the
+/// provided config, or `None` if it overflows a usize.
+pub fn encoded_size<C>(bytes_len: usize, config: C) -> Option<usize>
+where
+    C: Config,
+{
+    let encoded_len =
+        crate::encode::checked_encoded_len(bytes_len, config.padding_byte().is_some())?;
+    match config.line_wrap() {
+        Some(wrap) => {
+            crate::encode::checked_wrapped_len(encoded_len, wrap.interval(), wrap.separator().len())
+        }
+        None => Some(encoded_len),
+    }
+}
+
+/// Decode the input using the [STANDARD](constant.STANDARD.html) config.
+pub fn decode<T>(input: &T) -> Result<Vec<u8>, DecodeError>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    STANDARD.decode(input)
+}
+
+/// Decode the input using the provided config.
+pub fn decode_config<T, C>(input: &T, config: C) -> Result<Vec<u8>, DecodeError>
+where
+    T: AsRef<[u8]> + ?Sized,
+    C: Config,
+{
+    config.decode(input)
+}
+
+/// Decode the input using the provided config, appending to `buffer`. The
+/// buffer is left unchanged if the input is invalid.
+pub fn decode_config_buf<T, C>(
+    input: &T,
+    config: C,
+    buffer: &mut Vec<u8>,
+) -> Result<(), DecodeError>
+where
+    T: AsRef<[u8]> + ?Sized,
+    C: Config,
+{
+    config.decode_append(input, buffer)
+}
+
+/// Decode the input using the provided config into the front of `output`,
+/// returning the number of bytes written. Panics if `output` is too small.
+pub fn decode_config_slice<T, C>(
+    input: &T,
+    config: C,
+    output: &mut [u8],
+) -> Result<usize, DecodeError>
+where
+    T: AsRef<[u8]> + ?Sized,
+    C: Config,
+{
+    config.decode_slice(input, output)
+}
@@ -0,0 +1,191 @@
+//! An `Engine` trait mirroring the one in the base64 crate since 0.21.
+//!
+//! Code that is generic over the base64 crate's `Engine`, or that calls its
+//! methods on the `general_purpose` engines, can use this trait and the
+//! engines below instead. Every radix64 [Config](../../trait.Config.html),
+//! as well as a [CustomConfig](../../struct.CustomConfig.html), is an Engine.
+//!
+//! ```
+//! use radix64::compat::engine::{general_purpose::URL_SAFE_NO_PAD, Engine};
+//!
+//! fn token<E: Engine>(engine: &E, id: &[u8]) -> String {
+//!     engine.encode(id)
+//! }
+//!
+//! assert_eq!(token(&URL_SAFE_NO_PAD, b"\xfb\xff"), "-_8");
+//! assert_eq!(URL_SAFE_NO_PAD.decode("-_8").unwrap(), b"\xfb\xff");
+//! ```
+use crate::{BufferTooSmall, Config, CustomConfig, DecodeError, DecodeSliceError};
+use alloc::{string::String, vec::Vec};
+
+/// The builtin configs, named as the base64 crate's general purpose engines.
+pub mod general_purpose {
+    use crate::configs::{Std, StdNoPad, UrlSafe, UrlSafeNoPad};
+
+    /// The standard character set with `=` padding.
+    pub const STANDARD: Std = Std;
+    /// The standard character set without padding.
+    pub const STANDARD_NO_PAD: StdNoPad = StdNoPad;
+    /// The URL safe character set with `=` padding.
+    pub const URL_SAFE: UrlSafe = UrlSafe;
+    /// The URL safe character set without padding.
+    pub const URL_SAFE_NO_PAD: UrlSafeNoPad = UrlSafeNoPad;
+}
+
+/// The encoding and decoding methods of the base64 crate's `Engine` trait.
+///
+/// The methods behave like the [Config](../../trait.Config.html) methods they
+/// call. Encoding into a slice that is too small returns a `BufferTooSmall`
+/// in place of the base64 crate's `EncodeSliceError`.
+pub trait Engine {
+    /// Encode the input. See [Config::encode](../../trait.Config.html#method.encode).
+    fn encode<T: AsRef<[u8]>>(&self, input: T) -> String;
+
+    /// Encode the input, appending to `output_buf`. See
+    /// [Config::encode_append](../../trait.Config.html#method.encode_append).
+    fn encode_string<T: AsRef<[u8]>>(&self, input: T, output_buf: &mut String);
+
+    /// Encode the input into the front of `output_buf`, returning the number
+    /// of bytes written. See
+    /// [Config::try_encode_slice](../../trait.Config.html#method.try_encode_slice).
+    fn encode_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output_buf: &mut [u8],
+    ) -> Result<usize, BufferTooSmall>;
+
+    /// Decode the input. See [Config::decode](../../trait.Config.html#method.decode).
+    fn decode<T: AsRef<[u8]>>(&self, input: T) -> Result<Vec<u8>, DecodeError>;
+
+    /// Decode the input, appending to `buffer`. See
+    /// [Config::decode_append](../../trait.Config.html#method.decode_append).
+    fn decode_vec<T: AsRef<[u8]>>(&self, input: T, buffer: &mut Vec<u8>)
+        -> Result<(), DecodeError>;
+
+    /// Decode the input into the front of `output`, returning the number of
+    /// bytes written. See
+    /// [Config::try_decode_slice](../../trait.Config.html#method.try_decode_slice).
+    fn decode_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeSliceError>;
+
+    /// Decode the input into the front of `output`, returning the number of
+    /// bytes written. Panics if `output` is too small. See
+    /// [Config::decode_slice](../../trait.Config.html#method.decode_slice).
+    fn decode_slice_unchecked<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeError>;
+}
+
+impl<C> Engine for C
+where
+    C: Config,
+{
+    #[inline]
+    fn encode<T: AsRef<[u8]>>(&self, input: T) -> String {
+        Config::encode(*self, input.as_ref())
+    }
+
+    #[inline]
+    fn encode_string<T: AsRef<[u8]>>(&self, input: T, output_buf: &mut String) {
+        Config::encode_append(*self, input.as_ref(), output_buf)
+    }
+
+    #[inline]
+    fn encode_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output_buf: &mut [u8],
+    ) -> Result<usize, BufferTooSmall> {
+        Config::try_encode_slice(*self, input.as_ref(), output_buf)
+    }
+
+    #[inline]
+    fn decode<T: AsRef<[u8]>>(&self, input: T) -> Result<Vec<u8>, DecodeError> {
+        Config::decode(*self, input.as_ref())
+    }
+
+    #[inline]
+    fn decode_vec<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), DecodeError> {
+        Config::decode_append(*self, input.as_ref(), buffer)
+    }
+
+    #[inline]
+    fn decode_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeSliceError> {
+        Config::try_decode_slice(*self, input.as_ref(), output)
+    }
+
+    #[inline]
+    fn decode_slice_unchecked<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        Config::decode_slice(*self, input.as_ref(), output)
+    }
+}
+
+impl Engine for CustomConfig {
+    #[inline]
+    fn encode<T: AsRef<[u8]>>(&self, input: T) -> String {
+        Engine::encode(&self, input)
+    }
+
+    #[inline]
+    fn encode_string<T: AsRef<[u8]>>(&self, input: T, output_buf: &mut String) {
+        Engine::encode_string(&self, input, output_buf)
+    }
+
+    #[inline]
+    fn encode_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output_buf: &mut [u8],
+    ) -> Result<usize, BufferTooSmall> {
+        Engine::encode_slice(&self, input, output_buf)
+    }
+
+    #[inline]
+    fn decode<T: AsRef<[u8]>>(&self, input: T) -> Result<Vec<u8>, DecodeError> {
+        Engine::decode(&self, input)
+    }
+
+    #[inline]
+    fn decode_vec<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), DecodeError> {
+        Engine::decode_vec(&self, input, buffer)
+    }
+
+    #[inline]
+    fn decode_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeSliceError> {
+        Engine::decode_slice(&self, input, output)
+    }
+
+    #[inline]
+    fn decode_slice_unchecked<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        Engine::decode_slice_unchecked(&self, input, output)
+    }
+}
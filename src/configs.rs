@@ -1,16 +1,32 @@
 //! The different varieties of base64.
+use crate::decode::block::IntoBlockDecoder;
+use crate::encode::block::IntoBlockEncoder;
 use crate::u6::U6;
-use crate::{private::SealedConfig, Config, DecodeError};
-use std::fmt;
+use crate::wrap::{LineWrap, MAX_SEPARATOR_LEN};
+#[cfg(feature = "alloc")]
+use crate::DecodeUtf8Error;
+use crate::{
+    private::SealedConfig, BufferTooSmall, Config, DecodeError, DecodeIntError, DecodeSliceError,
+    EncodeBytes, EncodeChars, OutputBuffer,
+};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use core::mem::MaybeUninit;
 
 macro_rules! impl_config_from_table {
-    ($cfg:ty, $encode_table:ident, $decode_table:ident, $padding:expr) => {
+    ($cfg:ty, $encode_table:ident, $pair_table:ident, $decode_table:ident, $padding:expr) => {
         impl SealedConfig for $cfg {
             #[inline]
             fn encode_u6(self, input: U6) -> u8 {
                 crate::encode::encode_using_table(crate::tables::$encode_table, input)
             }
 
+            #[inline]
+            fn encode_pair_table(self) -> Option<&'static [u16; 4096]> {
+                Some(&crate::encode::$pair_table)
+            }
+
             #[inline]
             fn decode_u8(self, input: u8) -> u8 {
                 crate::decode::decode_using_table(crate::tables::$decode_table, input)
@@ -23,13 +39,47 @@ macro_rules! impl_config_from_table {
         }
 
         impl Config for $cfg {}
+
+        impl $cfg {
+            const PADDING: Option<u8> = $padding;
+            const ENCODE_TABLE: &'static [u8; 64] = crate::tables::$encode_table;
+            const DECODE_TABLE: &'static [u8; 256] = crate::tables::$decode_table;
+        }
     };
 }
 
 macro_rules! define_inherent_impl {
-    ($cfg:ty) => {
-        impl $cfg {
+    (impl[$($generics:tt)*] $cfg:ty) => {
+        define_inherent_impl!(@methods impl[$($generics)*] $cfg);
+        impl<$($generics)*> $cfg {
+            /// See [Config::encoded_output_len](../trait.Config.html#method.encoded_output_len).
+            #[inline]
+            pub fn encoded_output_len(self, input_len: usize) -> usize {
+                <Self as Config>::encoded_output_len(self, input_len)
+            }
+
+            /// See [Config::encoded_output_len_wrapped](../trait.Config.html#method.encoded_output_len_wrapped).
+            #[inline]
+            pub fn encoded_output_len_wrapped(
+                self,
+                input_len: usize,
+                width: usize,
+                line_ending_len: usize,
+            ) -> usize {
+                <Self as Config>::encoded_output_len_wrapped(self, input_len, width, line_ending_len)
+            }
+
+            /// See [Config::maximum_decoded_output_len](../trait.Config.html#method.maximum_decoded_output_len).
+            #[inline]
+            pub fn maximum_decoded_output_len(self, input_len: usize) -> usize {
+                <Self as Config>::maximum_decoded_output_len(self, input_len)
+            }
+        }
+    };
+    (@methods impl[$($generics:tt)*] $cfg:ty) => {
+        impl<$($generics)*> $cfg {
             /// See [Config::encode](../trait.Config.html#method.encode).
+            #[cfg(feature = "alloc")]
             #[inline]
             pub fn encode<I>(self, input: &I) -> String
             where
@@ -38,11 +88,26 @@ macro_rules! define_inherent_impl {
                 <Self as Config>::encode(self, input)
             }
 
+            /// See [Config::encode_capped](../trait.Config.html#method.encode_capped).
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn encode_capped<I>(
+                self,
+                input: &I,
+                max_encoded_len: usize,
+            ) -> Result<String, crate::io::LimitExceeded>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_capped(self, input, max_encoded_len)
+            }
+
             /// See [Config::encode_with_buffer](../trait.Config.html#method.encode_with_buffer).
+            #[cfg(feature = "alloc")]
             #[inline]
-            pub fn encode_with_buffer<'i, 'b, I>(
+            pub fn encode_with_buffer<'b, I>(
                 self,
-                input: &'i I,
+                input: &I,
                 buffer: &'b mut Vec<u8>,
             ) -> &'b str
             where
@@ -51,6 +116,70 @@ macro_rules! define_inherent_impl {
                 <Self as Config>::encode_with_buffer(self, input, buffer)
             }
 
+            /// See [Config::encode_with_str_buffer](../trait.Config.html#method.encode_with_str_buffer).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn encode_with_str_buffer<'b, I>(
+                self,
+                input: &I,
+                buffer: &'b mut String,
+            ) -> &'b str
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_with_str_buffer(self, input, buffer)
+            }
+
+            /// See [Config::encode_append](../trait.Config.html#method.encode_append).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn encode_append<I>(self, input: &I, buffer: &mut String)
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_append(self, input, buffer)
+            }
+
+            /// See [Config::encode_arc](../trait.Config.html#method.encode_arc).
+            #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+            #[inline]
+            pub fn encode_arc<I>(self, input: &I) -> alloc::sync::Arc<str>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_arc(self, input)
+            }
+
+            /// See [Config::encode_rc](../trait.Config.html#method.encode_rc).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn encode_rc<I>(self, input: &I) -> alloc::rc::Rc<str>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_rc(self, input)
+            }
+
+            /// See [Config::encode_to](../trait.Config.html#method.encode_to).
+            #[inline]
+            pub fn encode_to<I, O>(self, input: &I, output: &mut O) -> usize
+            where
+                I: AsRef<[u8]> + ?Sized,
+                O: OutputBuffer + ?Sized,
+            {
+                <Self as Config>::encode_to(self, input, output)
+            }
+
+            /// See [Config::try_encode_to](../trait.Config.html#method.try_encode_to).
+            #[inline]
+            pub fn try_encode_to<I, O>(self, input: &I, output: &mut O) -> Result<usize, BufferTooSmall>
+            where
+                I: AsRef<[u8]> + ?Sized,
+                O: OutputBuffer + ?Sized,
+            {
+                <Self as Config>::try_encode_to(self, input, output)
+            }
+
             /// See [Config::encode_slice](../trait.Config.html#method.encode_slice).
             #[inline]
             pub fn encode_slice<I>(self, input: &I, output: &mut [u8]) -> usize
@@ -60,7 +189,36 @@ macro_rules! define_inherent_impl {
                 <Self as Config>::encode_slice(self, input, output)
             }
 
+            /// See [Config::encode_array](../trait.Config.html#method.encode_array).
+            #[inline]
+            pub fn encode_array<const N: usize, const M: usize>(self, input: &[u8; N]) -> [u8; M] {
+                <Self as Config>::encode_array(self, input)
+            }
+
+            /// See [Config::try_encode_slice](../trait.Config.html#method.try_encode_slice).
+            #[inline]
+            pub fn try_encode_slice<I>(self, input: &I, output: &mut [u8]) -> Result<usize, BufferTooSmall>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::try_encode_slice(self, input, output)
+            }
+
+            /// See [Config::encode_slice_uninit](../trait.Config.html#method.encode_slice_uninit).
+            #[inline]
+            pub fn encode_slice_uninit<'o, I>(
+                self,
+                input: &I,
+                output: &'o mut [MaybeUninit<u8>],
+            ) -> &'o str
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_slice_uninit(self, input, output)
+            }
+
             /// See [Config::decode](../trait.Config.html#method.decode).
+            #[cfg(feature = "alloc")]
             #[inline]
             pub fn decode<I>(self, input: &I) -> Result<Vec<u8>, DecodeError>
             where
@@ -69,11 +227,29 @@ macro_rules! define_inherent_impl {
                 <Self as Config>::decode(self, input)
             }
 
+            /// See [Config::decode_utf8](../trait.Config.html#method.decode_utf8).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_utf8<I>(self, input: &I) -> Result<String, DecodeUtf8Error>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_utf8(self, input)
+            }
+
+            /// See [Config::decode_str](../trait.Config.html#method.decode_str).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_str(self, input: &str) -> Result<Vec<u8>, DecodeError> {
+                <Self as Config>::decode_str(self, input)
+            }
+
             /// See [Config::decode_with_buffer](../trait.Config.html#method.decode_with_buffer).
+            #[cfg(feature = "alloc")]
             #[inline]
-            pub fn decode_with_buffer<'i, 'b, I>(
+            pub fn decode_with_buffer<'b, I>(
                 self,
-                input: &'i I,
+                input: &I,
                 buffer: &'b mut Vec<u8>,
             ) -> Result<&'b [u8], DecodeError>
             where
@@ -82,206 +258,1652 @@ macro_rules! define_inherent_impl {
                 <Self as Config>::decode_with_buffer(self, input, buffer)
             }
 
-            /// See [Config::decode_slice](../trait.Config.html#method.decode_slice).
-            #[inline]
-            pub fn decode_slice<I>(self, input: &I, output: &mut [u8]) -> Result<usize, DecodeError>
-            where
-                I: AsRef<[u8]> + ?Sized,
-            {
-                <Self as Config>::decode_slice(self, input, output)
-            }
-        }
-    };
-}
+            /// See [Config::decode_append](../trait.Config.html#method.decode_append).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_append<I>(self, input: &I, buffer: &mut Vec<u8>) -> Result<(), DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_append(self, input, buffer)
+            }
+
+            /// See [Config::decode_in_place](../trait.Config.html#method.decode_in_place).
+            #[inline]
+            pub fn decode_in_place(self, buffer: &mut [u8]) -> Result<&[u8], DecodeError> {
+                <Self as Config>::decode_in_place(self, buffer)
+            }
+
+            /// See [Config::decode_truncated](../trait.Config.html#method.decode_truncated).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_truncated<I>(self, input: &I) -> Result<(Vec<u8>, usize), DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_truncated(self, input)
+            }
+
+            /// See [Config::decode_prefix](../trait.Config.html#method.decode_prefix).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_prefix<I>(self, input: &I) -> Result<(Vec<u8>, usize), DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_prefix(self, input)
+            }
+
+            /// See [Config::decode_prefix_rest](../trait.Config.html#method.decode_prefix_rest).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_prefix_rest<I>(self, input: &I) -> Result<(Vec<u8>, &[u8]), DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_prefix_rest(self, input)
+            }
+
+            /// See [Config::encode_into](../trait.Config.html#method.encode_into).
+            #[inline]
+            pub fn encode_into<I, T>(self, input: &I, output: &mut T)
+            where
+                I: AsRef<[u8]> + ?Sized,
+                T: Extend<u8>,
+            {
+                <Self as Config>::encode_into(self, input, output)
+            }
+
+            /// See [Config::encode_iter](../trait.Config.html#method.encode_iter).
+            #[inline]
+            pub fn encode_iter<I>(self, input: &I) -> EncodeChars<'_, Self>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_iter(self, input)
+            }
+
+            /// See [Config::encode_bytes_iter](../trait.Config.html#method.encode_bytes_iter).
+            #[inline]
+            pub fn encode_bytes_iter<I>(self, input: &I) -> EncodeBytes<'_, Self>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_bytes_iter(self, input)
+            }
+
+            /// See [Config::encode_to_writer](../trait.Config.html#method.encode_to_writer).
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn encode_to_writer<I, W>(self, input: &I, writer: &mut W) -> std::io::Result<u64>
+            where
+                I: AsRef<[u8]> + ?Sized,
+                W: std::io::Write + ?Sized,
+            {
+                <Self as Config>::encode_to_writer(self, input, writer)
+            }
+
+            /// See [Config::encode_to_fmt](../trait.Config.html#method.encode_to_fmt).
+            #[inline]
+            pub fn encode_to_fmt<I, W>(self, input: &I, writer: &mut W) -> fmt::Result
+            where
+                I: AsRef<[u8]> + ?Sized,
+                W: fmt::Write + ?Sized,
+            {
+                <Self as Config>::encode_to_fmt(self, input, writer)
+            }
+
+            /// See [Config::encode_chunked](../trait.Config.html#method.encode_chunked).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn encode_chunked<I>(self, input: &I, max_chars: usize) -> Vec<String>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_chunked(self, input, max_chars)
+            }
+
+            /// See [Config::decode_chunked](../trait.Config.html#method.decode_chunked).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_chunked<P, I>(self, pieces: P) -> Result<Vec<u8>, DecodeError>
+            where
+                P: IntoIterator<Item = I>,
+                I: AsRef<[u8]>,
+            {
+                <Self as Config>::decode_chunked(self, pieces)
+            }
+
+            /// See [Config::validate](../trait.Config.html#method.validate).
+            #[inline]
+            pub fn validate<I>(self, input: &I) -> Result<(), DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::validate(self, input)
+            }
+
+            /// See [Config::is_canonical](../trait.Config.html#method.is_canonical).
+            #[inline]
+            pub fn is_canonical<I>(self, input: &I) -> bool
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::is_canonical(self, input)
+            }
+
+            /// See [Config::decode_into](../trait.Config.html#method.decode_into).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_into<I, T>(self, input: &I, output: &mut T) -> Result<(), DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+                T: Extend<u8>,
+            {
+                <Self as Config>::decode_into(self, input, output)
+            }
+
+            /// See [Config::decode_to_writer](../trait.Config.html#method.decode_to_writer).
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn decode_to_writer<I, W>(self, input: &I, writer: &mut W) -> std::io::Result<u64>
+            where
+                I: AsRef<[u8]> + ?Sized,
+                W: std::io::Write + ?Sized,
+            {
+                <Self as Config>::decode_to_writer(self, input, writer)
+            }
+
+            /// See [Config::decode_range](../trait.Config.html#method.decode_range).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_range<I>(self, input: &I, range: core::ops::Range<usize>) -> Result<Vec<u8>, DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_range(self, input, range)
+            }
+
+            /// See [Config::decode_utf16](../trait.Config.html#method.decode_utf16).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_utf16(self, input: &[u16]) -> Result<Vec<u8>, DecodeError> {
+                <Self as Config>::decode_utf16(self, input)
+            }
+
+            /// See [Config::encode_slices](../trait.Config.html#method.encode_slices).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn encode_slices<A, B>(self, first: &A, second: &B) -> String
+            where
+                A: AsRef<[u8]> + ?Sized,
+                B: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::encode_slices(self, first, second)
+            }
+
+            /// See [Config::decode_slices](../trait.Config.html#method.decode_slices).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_slices<A, B>(self, first: &A, second: &B) -> Result<Vec<u8>, DecodeError>
+            where
+                A: AsRef<[u8]> + ?Sized,
+                B: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_slices(self, first, second)
+            }
+
+            /// See [Config::decode_split](../trait.Config.html#method.decode_split).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_split<'a, S>(self, segments: S) -> Result<Vec<u8>, DecodeError>
+            where
+                S: IntoIterator<Item = &'a [u8]>,
+                S::IntoIter: Clone,
+            {
+                <Self as Config>::decode_split(self, segments)
+            }
+
+            /// See [Config::encode_buf](../trait.Config.html#method.encode_buf).
+            #[cfg(feature = "bytes")]
+            #[inline]
+            pub fn encode_buf<B>(self, buf: B) -> String
+            where
+                B: bytes::Buf,
+            {
+                <Self as Config>::encode_buf(self, buf)
+            }
+
+            /// See [Config::decode_buf](../trait.Config.html#method.decode_buf).
+            #[cfg(feature = "bytes")]
+            #[inline]
+            pub fn decode_buf<B>(self, buf: B) -> Result<Vec<u8>, DecodeError>
+            where
+                B: bytes::Buf,
+            {
+                <Self as Config>::decode_buf(self, buf)
+            }
+
+            /// See [Config::decode_percent_encoded](../trait.Config.html#method.decode_percent_encoded).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn decode_percent_encoded<I>(self, input: &I) -> Result<Vec<u8>, DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_percent_encoded(self, input)
+            }
+
+            /// See [Config::decode_secret](../trait.Config.html#method.decode_secret).
+            #[cfg(feature = "secrecy")]
+            #[inline]
+            pub fn decode_secret<I>(self, input: &I) -> Result<secrecy::SecretVec<u8>, DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_secret(self, input)
+            }
+
+            /// See [Config::encode_pod_slice](../trait.Config.html#method.encode_pod_slice).
+            #[cfg(feature = "bytemuck")]
+            #[inline]
+            pub fn encode_pod_slice<T>(self, input: &[T]) -> String
+            where
+                T: bytemuck::Pod,
+            {
+                <Self as Config>::encode_pod_slice(self, input)
+            }
+
+            /// See [Config::decode_pod_vec](../trait.Config.html#method.decode_pod_vec).
+            #[cfg(feature = "bytemuck")]
+            #[inline]
+            pub fn decode_pod_vec<T, I>(self, input: &I) -> Result<Vec<T>, crate::PodDecodeError>
+            where
+                T: bytemuck::Pod,
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_pod_vec(self, input)
+            }
+
+            /// See [Config::encode_parallel](../trait.Config.html#method.encode_parallel).
+            #[cfg(feature = "rayon")]
+            #[inline]
+            pub fn encode_parallel<I>(self, input: &I) -> String
+            where
+                I: AsRef<[u8]> + ?Sized,
+                Self: Sync,
+            {
+                <Self as Config>::encode_parallel(self, input)
+            }
+
+            /// See [Config::decode_parallel](../trait.Config.html#method.decode_parallel).
+            #[cfg(feature = "rayon")]
+            #[inline]
+            pub fn decode_parallel<I>(self, input: &I) -> Result<Vec<u8>, DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+                Self: Sync,
+            {
+                <Self as Config>::decode_parallel(self, input)
+            }
+
+            /// See [Config::decode_to](../trait.Config.html#method.decode_to).
+            #[inline]
+            pub fn decode_to<I, O>(self, input: &I, output: &mut O) -> Result<usize, O::DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+                O: OutputBuffer + ?Sized,
+            {
+                <Self as Config>::decode_to(self, input, output)
+            }
+
+            /// See [Config::try_decode_to](../trait.Config.html#method.try_decode_to).
+            #[inline]
+            pub fn try_decode_to<I, O>(self, input: &I, output: &mut O) -> Result<usize, O::DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+                O: OutputBuffer + ?Sized,
+                O::DecodeError: From<BufferTooSmall>,
+            {
+                <Self as Config>::try_decode_to(self, input, output)
+            }
+
+            /// See [Config::decode_slice](../trait.Config.html#method.decode_slice).
+            #[inline]
+            pub fn decode_slice<I>(self, input: &I, output: &mut [u8]) -> Result<usize, DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_slice(self, input, output)
+            }
+
+            /// See [Config::decode_exact](../trait.Config.html#method.decode_exact).
+            #[inline]
+            pub fn decode_exact<const N: usize, I>(self, input: &I) -> Result<[u8; N], DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_exact(self, input)
+            }
+
+            /// See [Config::try_decode_slice](../trait.Config.html#method.try_decode_slice).
+            #[inline]
+            pub fn try_decode_slice<I>(self, input: &I, output: &mut [u8]) -> Result<usize, DecodeSliceError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::try_decode_slice(self, input, output)
+            }
+
+            /// See [Config::decode_slice_uninit](../trait.Config.html#method.decode_slice_uninit).
+            #[inline]
+            pub fn decode_slice_uninit<'o, I>(
+                self,
+                input: &I,
+                output: &'o mut [MaybeUninit<u8>],
+            ) -> Result<&'o mut [u8], DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_slice_uninit(self, input, output)
+            }
+
+            /// See [Config::encode_u64](../trait.Config.html#method.encode_u64).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn encode_u64(self, value: u64) -> String {
+                <Self as Config>::encode_u64(self, value)
+            }
+
+            /// See [Config::encode_u128](../trait.Config.html#method.encode_u128).
+            #[cfg(feature = "alloc")]
+            #[inline]
+            pub fn encode_u128(self, value: u128) -> String {
+                <Self as Config>::encode_u128(self, value)
+            }
+
+            /// See [Config::decode_u64](../trait.Config.html#method.decode_u64).
+            #[inline]
+            pub fn decode_u64<I>(self, input: &I) -> Result<u64, DecodeIntError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_u64(self, input)
+            }
+
+            /// See [Config::decode_u128](../trait.Config.html#method.decode_u128).
+            #[inline]
+            pub fn decode_u128<I>(self, input: &I) -> Result<u128, DecodeIntError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decode_u128(self, input)
+            }
+
+            /// See [Config::decoded_len](../trait.Config.html#method.decoded_len).
+            #[inline]
+            pub fn decoded_len<I>(self, input: &I) -> Result<usize, DecodeError>
+            where
+                I: AsRef<[u8]> + ?Sized,
+            {
+                <Self as Config>::decoded_len(self, input)
+            }
+        }
+    };
+    ($cfg:ty) => {
+        define_inherent_impl!(@methods impl[] $cfg);
+        impl $cfg {
+            /// See [Config::encoded_output_len](../trait.Config.html#method.encoded_output_len).
+            #[inline]
+            pub const fn encoded_output_len(self, input_len: usize) -> usize {
+                crate::encode::encoded_len(input_len, Self::PADDING.is_some())
+            }
+
+            /// See [Config::encoded_output_len_wrapped](../trait.Config.html#method.encoded_output_len_wrapped).
+            #[inline]
+            pub const fn encoded_output_len_wrapped(
+                self,
+                input_len: usize,
+                width: usize,
+                line_ending_len: usize,
+            ) -> usize {
+                crate::encode::wrapped_len(
+                    crate::encode::encoded_len(input_len, Self::PADDING.is_some()),
+                    width,
+                    line_ending_len,
+                )
+            }
+
+            /// See [Config::maximum_decoded_output_len](../trait.Config.html#method.maximum_decoded_output_len).
+            #[inline]
+            pub const fn maximum_decoded_output_len(self, input_len: usize) -> usize {
+                crate::decode::max_decoded_len(input_len)
+            }
+
+            /// Encode the provided input in a const context, such as the
+            /// initializer of a constant, so the encoding of embedded data is
+            /// computed at compile time. The length of the output array must
+            /// equal `encoded_output_len(input.len())`, or evaluation panics
+            /// (causing a compile error in a constant). Use `std::str::from_utf8`,
+            /// which is also a `const fn`, to view the output as a str.
+            ///
+            /// ```
+            /// use radix64::STD;
+            ///
+            /// const INPUT: &[u8] = b"hello";
+            /// const ENCODED: [u8; STD.encoded_output_len(INPUT.len())] = STD.encode_const(INPUT);
+            /// const TOKEN: &str = match std::str::from_utf8(&ENCODED) {
+            ///     Ok(token) => token,
+            ///     Err(_) => panic!("encoded output is ascii"),
+            /// };
+            /// assert_eq!(TOKEN, "aGVsbG8=");
+            /// ```
+            pub const fn encode_const<const N: usize>(self, input: &[u8]) -> [u8; N] {
+                crate::encode::encode_const(Self::ENCODE_TABLE, Self::PADDING, input)
+            }
+
+            /// The exact number of bytes produced by decoding the provided
+            /// input, in a const context. This returns the same result as
+            /// [Config::decoded_len](../trait.Config.html#method.decoded_len),
+            /// and sizes the output of `decode_const`.
+            pub const fn decoded_len_const(self, input: &[u8]) -> Result<usize, DecodeError> {
+                crate::decode::decoded_len_const(Self::PADDING, input)
+            }
+
+            /// Decode the provided input in a const context, such as the
+            /// initializer of a constant, so embedded base64 data is decoded at
+            /// compile time. The length of the output array must equal
+            /// `decoded_len_const(input)`. Evaluation panics (causing a compile
+            /// error in a constant) if the input is invalid or the array has the
+            /// wrong length. The [b64](../macro.b64.html) macro sizes the array
+            /// automatically.
+            ///
+            /// ```
+            /// use radix64::STD;
+            ///
+            /// const INPUT: &[u8] = b"aGVsbG8=";
+            /// const LEN: usize = match STD.decoded_len_const(INPUT) {
+            ///     Ok(len) => len,
+            ///     Err(_) => panic!("invalid input"),
+            /// };
+            /// const DECODED: [u8; LEN] = STD.decode_const(INPUT);
+            /// assert_eq!(&DECODED, b"hello");
+            /// ```
+            pub const fn decode_const<const N: usize>(self, input: &[u8]) -> [u8; N] {
+                crate::decode::decode_const(Self::DECODE_TABLE, Self::PADDING, input)
+            }
+        }
+    };
+}
+
+/// The standard character set (uses `+` and `/`) with `=` padding.
+///
+/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-4).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Std;
+impl_config_from_table!(Std, STD_ENCODE, STD_ENCODE_PAIRS, STD_DECODE, Some(b'='));
+define_inherent_impl!(Std);
+
+/// The standard character set (uses `+` and `/`) *without* padding.
+///
+/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-4).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdNoPad;
+impl_config_from_table!(StdNoPad, STD_ENCODE, STD_ENCODE_PAIRS, STD_DECODE, None);
+define_inherent_impl!(StdNoPad);
+
+/// The URL safe character set (uses `-` and `_`) with `=` padding.
+///
+/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-5).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UrlSafe;
+impl_config_from_table!(
+    UrlSafe,
+    URL_SAFE_ENCODE,
+    URL_SAFE_ENCODE_PAIRS,
+    URL_SAFE_DECODE,
+    Some(b'=')
+);
+define_inherent_impl!(UrlSafe);
+
+/// The URL safe character set (uses `-` and `_`) *without* padding.
+///
+/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-5).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UrlSafeNoPad;
+impl_config_from_table!(
+    UrlSafeNoPad,
+    URL_SAFE_ENCODE,
+    URL_SAFE_ENCODE_PAIRS,
+    URL_SAFE_DECODE,
+    None
+);
+define_inherent_impl!(UrlSafeNoPad);
+
+/// The crypt(3) character set
+///
+/// (uses `./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz`)
+/// *without* padding.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Crypt;
+impl_config_from_table!(Crypt, CRYPT_ENCODE, CRYPT_ENCODE_PAIRS, CRYPT_DECODE, None);
+define_inherent_impl!(Crypt);
+
+/// The Fast character set
+///
+/// (uses `:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\]^_`abcdefghijklmnopqrstuvwxyz`)
+/// *without* padding.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Fast;
+impl_config_from_table!(Fast, FAST_ENCODE, FAST_ENCODE_PAIRS, FAST_DECODE, None);
+define_inherent_impl!(Fast);
+
+/// A config that is statically known to pad its encoded output.
+///
+/// This allows APIs to require a padded config at compile time.
+/// `CustomConfig` chooses its padding at runtime and does not implement this
+/// trait.
+///
+/// # Examples
+/// ```
+/// use radix64::configs::PaddedConfig;
+///
+/// fn encode_for_mime<C: PaddedConfig>(config: C, data: &[u8]) -> String {
+///     config.encode(data)
+/// }
+///
+/// assert_eq!(encode_for_mime(radix64::STD, b"a"), "YQ==");
+/// ```
+///
+/// Unpadded configs are rejected.
+/// ```compile_fail
+/// # use radix64::configs::PaddedConfig;
+/// # fn encode_for_mime<C: PaddedConfig>(config: C, data: &[u8]) -> String {
+/// #     config.encode(data)
+/// # }
+/// encode_for_mime(radix64::STD_NO_PAD, b"a");
+/// ```
+pub trait PaddedConfig: Config {}
+
+/// A config that is statically known to not pad its encoded output.
+///
+/// This allows APIs to require an unpadded config at compile time, for
+/// example when producing JSON Web Tokens. `CustomConfig` chooses its padding
+/// at runtime and does not implement this trait.
+///
+/// # Examples
+/// ```
+/// use radix64::configs::UnpaddedConfig;
+///
+/// fn encode_jwt_segment<C: UnpaddedConfig>(config: C, data: &[u8]) -> String {
+///     config.encode(data)
+/// }
+///
+/// assert_eq!(encode_jwt_segment(radix64::URL_SAFE_NO_PAD, b"a"), "YQ");
+/// ```
+///
+/// Padded configs are rejected.
+/// ```compile_fail
+/// # use radix64::configs::UnpaddedConfig;
+/// # fn encode_jwt_segment<C: UnpaddedConfig>(config: C, data: &[u8]) -> String {
+/// #     config.encode(data)
+/// # }
+/// encode_jwt_segment(radix64::URL_SAFE, b"a");
+/// ```
+pub trait UnpaddedConfig: Config {}
+
+impl PaddedConfig for Std {}
+impl PaddedConfig for UrlSafe {}
+impl UnpaddedConfig for StdNoPad {}
+impl UnpaddedConfig for UrlSafeNoPad {}
+impl UnpaddedConfig for Crypt {}
+impl UnpaddedConfig for Fast {}
+impl<C> PaddedConfig for &C where C: PaddedConfig {}
+impl<C> UnpaddedConfig for &C where C: UnpaddedConfig {}
+
+/// Wraps a config to make padding optional when decoding.
+///
+/// Encoding is unchanged, so the wrapped config still decides whether padding
+/// is emitted. Decoding accepts input with or without padding. This allows
+/// producing RFC compliant padded output while accepting unpadded input, or
+/// producing unpadded output while accepting padded input. The wrapped config's
+/// optimized encoder and decoder are retained.
+///
+/// # Examples
+/// ```
+/// use radix64::{configs::OptionalPadding, STD, URL_SAFE_NO_PAD};
+///
+/// let lenient = OptionalPadding(STD);
+/// assert_eq!(lenient.encode("a"), "YQ==");
+/// assert_eq!(lenient.decode("YQ==").unwrap(), b"a");
+/// assert_eq!(lenient.decode("YQ").unwrap(), b"a");
+///
+/// let lenient = OptionalPadding(URL_SAFE_NO_PAD);
+/// assert_eq!(lenient.encode("a"), "YQ");
+/// assert_eq!(lenient.decode("YQ==").unwrap(), b"a");
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OptionalPadding<C>(pub C);
+
+impl<C> SealedConfig for OptionalPadding<C>
+where
+    C: Config,
+{
+    #[inline]
+    fn encode_u6(self, input: U6) -> u8 {
+        self.0.encode_u6(input)
+    }
+
+    #[inline]
+    fn encode_pair_table(self) -> Option<&'static [u16; 4096]> {
+        self.0.encode_pair_table()
+    }
+
+    #[inline]
+    fn decode_u8(self, input: u8) -> u8 {
+        self.0.decode_u8(input)
+    }
+
+    #[inline]
+    fn padding_byte(self) -> Option<u8> {
+        self.0.padding_byte()
+    }
+
+    #[inline]
+    fn decode_padding(self) -> DecodePadding {
+        match self.0.decode_padding() {
+            DecodePadding::Required(padding) | DecodePadding::Optional(padding) => {
+                DecodePadding::Optional(padding)
+            }
+            DecodePadding::Forbidden => DecodePadding::Optional(b'='),
+        }
+    }
+
+    #[inline]
+    fn line_wrap(self) -> Option<LineWrap> {
+        self.0.line_wrap()
+    }
+}
+
+impl<C> IntoBlockEncoder for OptionalPadding<C>
+where
+    C: Config,
+{
+    type BlockEncoder = C::BlockEncoder;
+
+    #[inline]
+    fn into_block_encoder(self) -> Self::BlockEncoder {
+        self.0.into_block_encoder()
+    }
+}
+
+impl<C> IntoBlockDecoder for OptionalPadding<C>
+where
+    C: Config,
+{
+    type BlockDecoder = C::BlockDecoder;
+
+    #[inline]
+    fn into_block_decoder(self) -> Self::BlockDecoder {
+        self.0.into_block_decoder()
+    }
+}
+
+impl<C> Config for OptionalPadding<C> where C: Config {}
+define_inherent_impl!(impl[C: Config] OptionalPadding<C>);
+
+// OptionalPadding only changes decoding, so the wrapped config determines
+// whether output is padded.
+impl<C> PaddedConfig for OptionalPadding<C> where C: PaddedConfig {}
+impl<C> UnpaddedConfig for OptionalPadding<C> where C: UnpaddedConfig {}
+
+// Any reference to a config is also a config. This allows generic code
+// accepting `C: Config` to work uniformly with `STD`, `&STD`, and
+// `&CustomConfig` (as well as `&&CustomConfig`).
+impl<C> SealedConfig for &C
+where
+    C: Config,
+{
+    #[inline]
+    fn encode_u6(self, input: U6) -> u8 {
+        (*self).encode_u6(input)
+    }
+
+    #[inline]
+    fn encode_pair_table(self) -> Option<&'static [u16; 4096]> {
+        (*self).encode_pair_table()
+    }
+
+    #[inline]
+    fn decode_u8(self, input: u8) -> u8 {
+        (*self).decode_u8(input)
+    }
+
+    #[inline]
+    fn padding_byte(self) -> Option<u8> {
+        (*self).padding_byte()
+    }
+
+    #[inline]
+    fn decode_padding(self) -> DecodePadding {
+        (*self).decode_padding()
+    }
+
+    #[inline]
+    fn line_wrap(self) -> Option<LineWrap> {
+        (*self).line_wrap()
+    }
+}
+
+impl<C> IntoBlockEncoder for &C
+where
+    C: Config,
+{
+    type BlockEncoder = C::BlockEncoder;
+
+    #[inline]
+    fn into_block_encoder(self) -> Self::BlockEncoder {
+        (*self).into_block_encoder()
+    }
+}
+
+impl<C> IntoBlockDecoder for &C
+where
+    C: Config,
+{
+    type BlockDecoder = C::BlockDecoder;
+
+    #[inline]
+    fn into_block_decoder(self) -> Self::BlockDecoder {
+        (*self).into_block_decoder()
+    }
+}
+
+impl<C> Config for &C where C: Config {}
+
+/// Wraps a config to encode and decode without padding.
+///
+/// The alphabet and optimized encoder and decoder of the wrapped config are
+/// retained. This allows removing padding from any config without a dedicated
+/// constant for each combination.
+///
+/// # Examples
+/// ```
+/// use radix64::{configs::NoPad, STD, STD_NO_PAD};
+///
+/// assert_eq!(NoPad(STD).encode("a"), STD_NO_PAD.encode("a"));
+/// assert!(NoPad(STD).decode("YQ==").is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoPad<C>(pub C);
+
+impl<C> SealedConfig for NoPad<C>
+where
+    C: Config,
+{
+    #[inline]
+    fn encode_u6(self, input: U6) -> u8 {
+        self.0.encode_u6(input)
+    }
+
+    #[inline]
+    fn encode_pair_table(self) -> Option<&'static [u16; 4096]> {
+        self.0.encode_pair_table()
+    }
+
+    #[inline]
+    fn decode_u8(self, input: u8) -> u8 {
+        self.0.decode_u8(input)
+    }
+
+    #[inline]
+    fn padding_byte(self) -> Option<u8> {
+        None
+    }
+
+    #[inline]
+    fn line_wrap(self) -> Option<LineWrap> {
+        self.0.line_wrap()
+    }
+}
+
+impl<C> IntoBlockEncoder for NoPad<C>
+where
+    C: Config,
+{
+    type BlockEncoder = C::BlockEncoder;
+
+    #[inline]
+    fn into_block_encoder(self) -> Self::BlockEncoder {
+        self.0.into_block_encoder()
+    }
+}
+
+impl<C> IntoBlockDecoder for NoPad<C>
+where
+    C: Config,
+{
+    type BlockDecoder = C::BlockDecoder;
+
+    #[inline]
+    fn into_block_decoder(self) -> Self::BlockDecoder {
+        self.0.into_block_decoder()
+    }
+}
+
+impl<C> Config for NoPad<C> where C: Config {}
+impl<C> UnpaddedConfig for NoPad<C> where C: Config {}
+define_inherent_impl!(impl[C: Config] NoPad<C>);
+
+/// Wraps a config to encode and decode with `PAD` as the padding byte.
+///
+/// The alphabet and optimized encoder and decoder of the wrapped config are
+/// retained. This allows adding padding to any config without a dedicated
+/// constant for each combination.
+///
+/// # Examples
+/// ```
+/// use radix64::{configs::WithPad, CRYPT};
+///
+/// let padded_crypt = WithPad::<_, b'='>::new(CRYPT);
+/// assert_eq!(padded_crypt.encode("a"), "ME==");
+/// assert_eq!(padded_crypt.decode("ME==").unwrap(), b"a");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WithPad<C, const PAD: u8>(C);
+
+impl<C, const PAD: u8> WithPad<C, PAD>
+where
+    C: Config,
+{
+    /// Wrap the provided config.
+    ///
+    /// # Panics
+    /// Panics if `PAD` is not ascii, is part of the config's alphabet, or is
+    /// part of the config's line separator.
+    pub fn new(config: C) -> Self {
+        assert!(PAD.is_ascii(), "padding byte {} is not ascii", PAD);
+        assert!(
+            config.decode_u8(PAD) == crate::decode::INVALID_VALUE,
+            "padding byte {:?} is part of the alphabet",
+            PAD as char
+        );
+        if let Some(wrap) = config.line_wrap() {
+            assert!(
+                !wrap.separator().contains(&PAD),
+                "padding byte {:?} is part of the line separator",
+                PAD as char
+            );
+        }
+        WithPad(config)
+    }
+
+    /// Return the wrapped config.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C, const PAD: u8> SealedConfig for WithPad<C, PAD>
+where
+    C: Config,
+{
+    #[inline]
+    fn encode_u6(self, input: U6) -> u8 {
+        self.0.encode_u6(input)
+    }
+
+    #[inline]
+    fn encode_pair_table(self) -> Option<&'static [u16; 4096]> {
+        self.0.encode_pair_table()
+    }
+
+    #[inline]
+    fn decode_u8(self, input: u8) -> u8 {
+        self.0.decode_u8(input)
+    }
+
+    #[inline]
+    fn padding_byte(self) -> Option<u8> {
+        Some(PAD)
+    }
+
+    #[inline]
+    fn line_wrap(self) -> Option<LineWrap> {
+        self.0.line_wrap()
+    }
+}
+
+impl<C, const PAD: u8> IntoBlockEncoder for WithPad<C, PAD>
+where
+    C: Config,
+{
+    type BlockEncoder = C::BlockEncoder;
+
+    #[inline]
+    fn into_block_encoder(self) -> Self::BlockEncoder {
+        self.0.into_block_encoder()
+    }
+}
+
+impl<C, const PAD: u8> IntoBlockDecoder for WithPad<C, PAD>
+where
+    C: Config,
+{
+    type BlockDecoder = C::BlockDecoder;
+
+    #[inline]
+    fn into_block_decoder(self) -> Self::BlockDecoder {
+        self.0.into_block_decoder()
+    }
+}
+
+impl<C, const PAD: u8> Config for WithPad<C, PAD> where C: Config {}
+impl<C, const PAD: u8> PaddedConfig for WithPad<C, PAD> where C: Config {}
+define_inherent_impl!(impl[C: Config, const PAD: u8] WithPad<C, PAD>);
+
+/// How padding is treated when decoding.
+///
+/// By default a config requires padding on decode if it emits padding on
+/// encode, and forbids it otherwise. A `CustomConfig` can override this with
+/// [CustomConfigBuilder::decode_padding](struct.CustomConfigBuilder.html#method.decode_padding).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodePadding {
+    /// Input must be padded to a multiple of 4 bytes using the provided byte.
+    Required(u8),
+    /// Input may either be padded using the provided byte or unpadded.
+    Optional(u8),
+    /// Input must not contain any padding.
+    Forbidden,
+}
+
+/// A custom defined alphabet and padding.
+///
+/// All characters of the alphabet, as well as the padding character (if any),
+/// must be ascii characters.
+///
+/// # Examples
+/// ```
+/// // Create a custom base64 configuration that matches what `crypt(3)`
+/// // produces. This is equivalent to using radix64::CRYPT except the builtin
+/// // constant provides SIMD optimized encoding/decoding when available while a
+/// // custom config cannot.
+/// use radix64::CustomConfig;
+///
+/// let my_config = CustomConfig::with_alphabet(
+///     "./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+/// )
+/// .no_padding()
+/// .build()
+/// .unwrap();
+///
+/// let my_encoded_msg = my_config.encode("my message");
+/// assert_eq!("PLYUPKJnQq3bNE", my_encoded_msg.as_str());
+/// assert_eq!("my message".as_bytes(), my_config.decode(&my_encoded_msg).unwrap().as_slice());
+/// ```
+///
+/// Note that building a custom configuration is somewhat expensive. It needs to
+/// iterate over the provided alphabet, sanity check it's contents, create an
+/// inverted alphabet for decoding, and store the results. For this reason it's
+/// encouraged to create a custom config early in program execution and share a
+/// single instance throughout the code. A simple way to do this is by utilizing
+/// lazy_static.
+/// ```
+/// use lazy_static::lazy_static;
+/// use radix64::CustomConfig;
+///
+/// lazy_static::lazy_static! {
+///     pub static ref MY_CONFIG: CustomConfig = CustomConfig::with_alphabet(
+///         "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+///     )
+///     .with_padding(b'=')
+///     .build()
+///     .expect("failed to build custom base64 config");
+/// }
+///
+/// let my_encoded_msg = MY_CONFIG.encode("my message");
+/// assert_eq!("bXkgbWVzc2FnZQ==", my_encoded_msg.as_str());
+/// assert_eq!("my message".as_bytes(), MY_CONFIG.decode(&my_encoded_msg).unwrap().as_slice());
+/// ```
+#[derive(Clone)]
+pub struct CustomConfig {
+    encode_table: [u8; 64],
+    decode_table: [u8; 256],
+    padding_byte: Option<u8>,
+    decode_padding: DecodePadding,
+    line_wrap: Option<LineWrap>,
+}
+
+impl SealedConfig for &CustomConfig {
+    fn encode_u6(self, input: U6) -> u8 {
+        crate::encode::encode_using_table(&self.encode_table, input)
+    }
+
+    fn decode_u8(self, input: u8) -> u8 {
+        crate::decode::decode_using_table(&self.decode_table, input)
+    }
+
+    fn padding_byte(self) -> Option<u8> {
+        self.padding_byte
+    }
+
+    fn decode_padding(self) -> DecodePadding {
+        self.decode_padding
+    }
+
+    fn line_wrap(self) -> Option<LineWrap> {
+        self.line_wrap
+    }
+}
+
+impl Config for &CustomConfig {}
+
+impl CustomConfig {
+    /// Start creating a new CustomConfig with the provided alphabet.
+    /// The provided alphabet needs to be 64 non-repeating ascii bytes.
+    pub fn with_alphabet<A: AsRef<[u8]> + ?Sized>(alphabet: &A) -> CustomConfigBuilder<'_> {
+        CustomConfigBuilder::with_alphabet(alphabet)
+    }
+
+    /// Start creating a new CustomConfig based on an existing config. See
+    /// [CustomConfigBuilder::based_on](struct.CustomConfigBuilder.html#method.based_on).
+    pub fn based_on<C: Config>(config: C) -> CustomConfigBuilder<'static> {
+        CustomConfigBuilder::based_on(config)
+    }
+
+    /// See [Config::encode](../trait.Config.html#method.encode).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode<I>(&self, input: &I) -> String
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode(self, input)
+    }
+
+    /// See [Config::encode_capped](../trait.Config.html#method.encode_capped).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn encode_capped<I>(
+        &self,
+        input: &I,
+        max_encoded_len: usize,
+    ) -> Result<String, crate::io::LimitExceeded>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_capped(self, input, max_encoded_len)
+    }
+
+    /// See [Config::encode_with_buffer](../trait.Config.html#method.encode_with_buffer).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_with_buffer<'b, I>(&self, input: &I, buffer: &'b mut Vec<u8>) -> &'b str
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_with_buffer(self, input, buffer)
+    }
+
+    /// See [Config::encode_with_str_buffer](../trait.Config.html#method.encode_with_str_buffer).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_with_str_buffer<'b, I>(&self, input: &I, buffer: &'b mut String) -> &'b str
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_with_str_buffer(self, input, buffer)
+    }
+
+    /// See [Config::encode_append](../trait.Config.html#method.encode_append).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_append<I>(&self, input: &I, buffer: &mut String)
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_append(self, input, buffer)
+    }
+
+    /// See [Config::encode_arc](../trait.Config.html#method.encode_arc).
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[inline]
+    pub fn encode_arc<I>(&self, input: &I) -> alloc::sync::Arc<str>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_arc(self, input)
+    }
+
+    /// See [Config::encode_rc](../trait.Config.html#method.encode_rc).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_rc<I>(&self, input: &I) -> alloc::rc::Rc<str>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_rc(self, input)
+    }
+
+    /// See [Config::encode_to](../trait.Config.html#method.encode_to).
+    #[inline]
+    pub fn encode_to<I, O>(&self, input: &I, output: &mut O) -> usize
+    where
+        I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+    {
+        <&Self as Config>::encode_to(self, input, output)
+    }
+
+    /// See [Config::try_encode_to](../trait.Config.html#method.try_encode_to).
+    #[inline]
+    pub fn try_encode_to<I, O>(&self, input: &I, output: &mut O) -> Result<usize, BufferTooSmall>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+    {
+        <&Self as Config>::try_encode_to(self, input, output)
+    }
+
+    /// See [Config::encode_slice](../trait.Config.html#method.encode_slice).
+    #[inline]
+    pub fn encode_slice<I>(&self, input: &I, output: &mut [u8]) -> usize
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_slice(self, input, output)
+    }
+
+    /// See [Config::encode_array](../trait.Config.html#method.encode_array).
+    #[inline]
+    pub fn encode_array<const N: usize, const M: usize>(&self, input: &[u8; N]) -> [u8; M] {
+        <&Self as Config>::encode_array(self, input)
+    }
+
+    /// See [Config::try_encode_slice](../trait.Config.html#method.try_encode_slice).
+    #[inline]
+    pub fn try_encode_slice<I>(&self, input: &I, output: &mut [u8]) -> Result<usize, BufferTooSmall>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::try_encode_slice(self, input, output)
+    }
+
+    /// See [Config::encode_slice_uninit](../trait.Config.html#method.encode_slice_uninit).
+    #[inline]
+    pub fn encode_slice_uninit<'o, I>(
+        &self,
+        input: &I,
+        output: &'o mut [MaybeUninit<u8>],
+    ) -> &'o str
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_slice_uninit(self, input, output)
+    }
+
+    /// See [Config::decode](../trait.Config.html#method.decode).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode<I>(&self, input: &I) -> Result<Vec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode(self, input)
+    }
+
+    /// See [Config::decode_utf8](../trait.Config.html#method.decode_utf8).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_utf8<I>(&self, input: &I) -> Result<String, DecodeUtf8Error>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_utf8(self, input)
+    }
+
+    /// See [Config::decode_str](../trait.Config.html#method.decode_str).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_str(&self, input: &str) -> Result<Vec<u8>, DecodeError> {
+        <&Self as Config>::decode_str(self, input)
+    }
+
+    /// See [Config::decode_with_buffer](../trait.Config.html#method.decode_with_buffer).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_with_buffer<'b, I>(
+        &self,
+        input: &I,
+        buffer: &'b mut Vec<u8>,
+    ) -> Result<&'b [u8], DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_with_buffer(self, input, buffer)
+    }
+
+    /// See [Config::decode_append](../trait.Config.html#method.decode_append).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_append<I>(&self, input: &I, buffer: &mut Vec<u8>) -> Result<(), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_append(self, input, buffer)
+    }
+
+    /// See [Config::decode_in_place](../trait.Config.html#method.decode_in_place).
+    #[inline]
+    pub fn decode_in_place<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b [u8], DecodeError> {
+        <&Self as Config>::decode_in_place(self, buffer)
+    }
+
+    /// See [Config::decode_truncated](../trait.Config.html#method.decode_truncated).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_truncated<I>(&self, input: &I) -> Result<(Vec<u8>, usize), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_truncated(self, input)
+    }
+
+    /// See [Config::decode_prefix](../trait.Config.html#method.decode_prefix).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_prefix<I>(&self, input: &I) -> Result<(Vec<u8>, usize), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_prefix(self, input)
+    }
+
+    /// See [Config::decode_prefix_rest](../trait.Config.html#method.decode_prefix_rest).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_prefix_rest<'i, I>(
+        &self,
+        input: &'i I,
+    ) -> Result<(Vec<u8>, &'i [u8]), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_prefix_rest(self, input)
+    }
+
+    /// See [Config::encode_into](../trait.Config.html#method.encode_into).
+    #[inline]
+    pub fn encode_into<I, T>(&self, input: &I, output: &mut T)
+    where
+        I: AsRef<[u8]> + ?Sized,
+        T: Extend<u8>,
+    {
+        <&Self as Config>::encode_into(self, input, output)
+    }
+
+    /// See [Config::encode_iter](../trait.Config.html#method.encode_iter).
+    #[inline]
+    pub fn encode_iter<'a, I>(&'a self, input: &'a I) -> EncodeChars<'a, &'a Self>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_iter(self, input)
+    }
+
+    /// See [Config::encode_bytes_iter](../trait.Config.html#method.encode_bytes_iter).
+    #[inline]
+    pub fn encode_bytes_iter<'a, I>(&'a self, input: &'a I) -> EncodeBytes<'a, &'a Self>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_bytes_iter(self, input)
+    }
+
+    /// See [Config::encode_to_writer](../trait.Config.html#method.encode_to_writer).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn encode_to_writer<I, W>(&self, input: &I, writer: &mut W) -> std::io::Result<u64>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        W: std::io::Write + ?Sized,
+    {
+        <&Self as Config>::encode_to_writer(self, input, writer)
+    }
+
+    /// See [Config::encode_to_fmt](../trait.Config.html#method.encode_to_fmt).
+    #[inline]
+    pub fn encode_to_fmt<I, W>(&self, input: &I, writer: &mut W) -> fmt::Result
+    where
+        I: AsRef<[u8]> + ?Sized,
+        W: fmt::Write + ?Sized,
+    {
+        <&Self as Config>::encode_to_fmt(self, input, writer)
+    }
+
+    /// See [Config::encode_chunked](../trait.Config.html#method.encode_chunked).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_chunked<I>(&self, input: &I, max_chars: usize) -> Vec<String>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_chunked(self, input, max_chars)
+    }
+
+    /// See [Config::decode_chunked](../trait.Config.html#method.decode_chunked).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_chunked<P, I>(&self, pieces: P) -> Result<Vec<u8>, DecodeError>
+    where
+        P: IntoIterator<Item = I>,
+        I: AsRef<[u8]>,
+    {
+        <&Self as Config>::decode_chunked(self, pieces)
+    }
+
+    /// See [Config::validate](../trait.Config.html#method.validate).
+    #[inline]
+    pub fn validate<I>(&self, input: &I) -> Result<(), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::validate(self, input)
+    }
+
+    /// See [Config::is_canonical](../trait.Config.html#method.is_canonical).
+    #[inline]
+    pub fn is_canonical<I>(&self, input: &I) -> bool
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::is_canonical(self, input)
+    }
+
+    /// See [Config::decode_into](../trait.Config.html#method.decode_into).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_into<I, T>(&self, input: &I, output: &mut T) -> Result<(), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        T: Extend<u8>,
+    {
+        <&Self as Config>::decode_into(self, input, output)
+    }
+
+    /// See [Config::decode_to_writer](../trait.Config.html#method.decode_to_writer).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn decode_to_writer<I, W>(&self, input: &I, writer: &mut W) -> std::io::Result<u64>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        W: std::io::Write + ?Sized,
+    {
+        <&Self as Config>::decode_to_writer(self, input, writer)
+    }
+
+    /// See [Config::decode_range](../trait.Config.html#method.decode_range).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_range<I>(
+        &self,
+        input: &I,
+        range: core::ops::Range<usize>,
+    ) -> Result<Vec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_range(self, input, range)
+    }
+
+    /// See [Config::decode_utf16](../trait.Config.html#method.decode_utf16).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_utf16(&self, input: &[u16]) -> Result<Vec<u8>, DecodeError> {
+        <&Self as Config>::decode_utf16(self, input)
+    }
 
-/// The standard character set (uses `+` and `/`) with `=` padding.
-///
-/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-4).
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Std;
-impl_config_from_table!(Std, STD_ENCODE, STD_DECODE, Some(b'='));
-define_inherent_impl!(Std);
+    /// See [Config::encode_slices](../trait.Config.html#method.encode_slices).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_slices<A, B>(&self, first: &A, second: &B) -> String
+    where
+        A: AsRef<[u8]> + ?Sized,
+        B: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_slices(self, first, second)
+    }
 
-/// The standard character set (uses `+` and `/`) *without* padding.
-///
-/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-4).
-#[derive(Debug, Default, Clone, Copy)]
-pub struct StdNoPad;
-impl_config_from_table!(StdNoPad, STD_ENCODE, STD_DECODE, None);
-define_inherent_impl!(StdNoPad);
+    /// See [Config::decode_slices](../trait.Config.html#method.decode_slices).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_slices<A, B>(&self, first: &A, second: &B) -> Result<Vec<u8>, DecodeError>
+    where
+        A: AsRef<[u8]> + ?Sized,
+        B: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_slices(self, first, second)
+    }
 
-/// The URL safe character set (uses `-` and `_`) with `=` padding.
-///
-/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-5).
-#[derive(Debug, Default, Clone, Copy)]
-pub struct UrlSafe;
-impl_config_from_table!(UrlSafe, URL_SAFE_ENCODE, URL_SAFE_DECODE, Some(b'='));
-define_inherent_impl!(UrlSafe);
+    /// See [Config::decode_split](../trait.Config.html#method.decode_split).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_split<'a, S>(&self, segments: S) -> Result<Vec<u8>, DecodeError>
+    where
+        S: IntoIterator<Item = &'a [u8]>,
+        S::IntoIter: Clone,
+    {
+        <&Self as Config>::decode_split(self, segments)
+    }
 
-/// The URL safe character set (uses `-` and `_`) *without* padding.
-///
-/// See [RFC 4648](https://tools.ietf.org/html/rfc4648#section-5).
-#[derive(Debug, Default, Clone, Copy)]
-pub struct UrlSafeNoPad;
-impl_config_from_table!(UrlSafeNoPad, URL_SAFE_ENCODE, URL_SAFE_DECODE, None);
-define_inherent_impl!(UrlSafeNoPad);
+    /// See [Config::encode_buf](../trait.Config.html#method.encode_buf).
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub fn encode_buf<B>(&self, buf: B) -> String
+    where
+        B: bytes::Buf,
+    {
+        <&Self as Config>::encode_buf(self, buf)
+    }
 
-/// The crypt(3) character set
-///
-/// (uses `./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz`)
-/// *without* padding.
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Crypt;
-impl_config_from_table!(Crypt, CRYPT_ENCODE, CRYPT_DECODE, None);
-define_inherent_impl!(Crypt);
+    /// See [Config::decode_buf](../trait.Config.html#method.decode_buf).
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub fn decode_buf<B>(&self, buf: B) -> Result<Vec<u8>, DecodeError>
+    where
+        B: bytes::Buf,
+    {
+        <&Self as Config>::decode_buf(self, buf)
+    }
 
-/// The Fast character set
-///
-/// (uses `:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\]^_`abcdefghijklmnopqrstuvwxyz`)
-/// *without* padding.
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Fast;
-impl_config_from_table!(Fast, FAST_ENCODE, FAST_DECODE, None);
-define_inherent_impl!(Fast);
+    /// See [Config::decode_percent_encoded](../trait.Config.html#method.decode_percent_encoded).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decode_percent_encoded<I>(&self, input: &I) -> Result<Vec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_percent_encoded(self, input)
+    }
 
-/// A custom defined alphabet and padding.
-///
-/// All characters of the alphabet, as well as the padding character (if any),
-/// must be ascii characters.
-///
-/// # Examples
-/// ```
-/// // Create a custom base64 configuration that matches what `crypt(3)`
-/// // produces. This is equivalent to using radix64::CRYPT except the builtin
-/// // constant provides SIMD optimized encoding/decoding when available while a
-/// // custom config cannot.
-/// use radix64::CustomConfig;
-///
-/// let my_config = CustomConfig::with_alphabet(
-///     "./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
-/// )
-/// .no_padding()
-/// .build()
-/// .unwrap();
-///
-/// let my_encoded_msg = my_config.encode("my message");
-/// assert_eq!("PLYUPKJnQq3bNE", my_encoded_msg.as_str());
-/// assert_eq!("my message".as_bytes(), my_config.decode(&my_encoded_msg).unwrap().as_slice());
-/// ```
-///
-/// Note that building a custom configuration is somewhat expensive. It needs to
-/// iterate over the provided alphabet, sanity check it's contents, create an
-/// inverted alphabet for decoding, and store the results. For this reason it's
-/// encouraged to create a custom config early in program execution and share a
-/// single instance throughout the code. A simple way to do this is by utilizing
-/// lazy_static.
-/// ```
-/// use lazy_static::lazy_static;
-/// use radix64::CustomConfig;
-///
-/// lazy_static::lazy_static! {
-///     pub static ref MY_CONFIG: CustomConfig = CustomConfig::with_alphabet(
-///         "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
-///     )
-///     .with_padding(b'=')
-///     .build()
-///     .expect("failed to build custom base64 config");
-/// }
-///
-/// let my_encoded_msg = MY_CONFIG.encode("my message");
-/// assert_eq!("bXkgbWVzc2FnZQ==", my_encoded_msg.as_str());
-/// assert_eq!("my message".as_bytes(), MY_CONFIG.decode(&my_encoded_msg).unwrap().as_slice());
-/// ```
-#[derive(Clone)]
-pub struct CustomConfig {
-    encode_table: [u8; 64],
-    decode_table: [u8; 256],
-    padding_byte: Option<u8>,
-}
+    /// See [Config::decode_secret](../trait.Config.html#method.decode_secret).
+    #[cfg(feature = "secrecy")]
+    #[inline]
+    pub fn decode_secret<I>(&self, input: &I) -> Result<secrecy::SecretVec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_secret(self, input)
+    }
 
-impl SealedConfig for &CustomConfig {
-    fn encode_u6(self, input: U6) -> u8 {
-        crate::encode::encode_using_table(&self.encode_table, input)
+    /// See [Config::encode_pod_slice](../trait.Config.html#method.encode_pod_slice).
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn encode_pod_slice<T>(&self, input: &[T]) -> String
+    where
+        T: bytemuck::Pod,
+    {
+        <&Self as Config>::encode_pod_slice(self, input)
     }
 
-    fn decode_u8(self, input: u8) -> u8 {
-        crate::decode::decode_using_table(&self.decode_table, input)
+    /// See [Config::decode_pod_vec](../trait.Config.html#method.decode_pod_vec).
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn decode_pod_vec<T, I>(&self, input: &I) -> Result<Vec<T>, crate::PodDecodeError>
+    where
+        T: bytemuck::Pod,
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_pod_vec(self, input)
     }
 
-    fn padding_byte(self) -> Option<u8> {
-        self.padding_byte
+    /// See [Config::encode_parallel](../trait.Config.html#method.encode_parallel).
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn encode_parallel<I>(&self, input: &I) -> String
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::encode_parallel(self, input)
     }
-}
 
-impl Config for &CustomConfig {}
+    /// See [Config::decode_parallel](../trait.Config.html#method.decode_parallel).
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn decode_parallel<I>(&self, input: &I) -> Result<Vec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_parallel(self, input)
+    }
 
-impl CustomConfig {
-    /// Start creating a new CustomConfig with the provided alphabet.
-    /// The provided alphabet needs to be 64 non-repeating ascii bytes.
-    pub fn with_alphabet<A: AsRef<[u8]> + ?Sized>(alphabet: &A) -> CustomConfigBuilder {
-        CustomConfigBuilder::with_alphabet(alphabet)
+    /// See [Config::decode_to](../trait.Config.html#method.decode_to).
+    #[inline]
+    pub fn decode_to<I, O>(&self, input: &I, output: &mut O) -> Result<usize, O::DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+    {
+        <&Self as Config>::decode_to(self, input, output)
     }
 
-    /// See [Config::encode](../trait.Config.html#method.encode).
+    /// See [Config::try_decode_to](../trait.Config.html#method.try_decode_to).
     #[inline]
-    pub fn encode<I>(&self, input: &I) -> String
+    pub fn try_decode_to<I, O>(&self, input: &I, output: &mut O) -> Result<usize, O::DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+        O::DecodeError: From<BufferTooSmall>,
     {
-        <&Self as Config>::encode(self, input)
+        <&Self as Config>::try_decode_to(self, input, output)
     }
 
-    /// See [Config::encode_with_buffer](../trait.Config.html#method.encode_with_buffer).
+    /// See [Config::decode_slice](../trait.Config.html#method.decode_slice).
     #[inline]
-    pub fn encode_with_buffer<'i, 'b, I>(&self, input: &'i I, buffer: &'b mut Vec<u8>) -> &'b str
+    pub fn decode_slice<I>(&self, input: &I, output: &mut [u8]) -> Result<usize, DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        <&Self as Config>::encode_with_buffer(self, input, buffer)
+        <&Self as Config>::decode_slice(self, input, output)
     }
 
-    /// See [Config::encode_slice](../trait.Config.html#method.encode_slice).
+    /// See [Config::decode_exact](../trait.Config.html#method.decode_exact).
     #[inline]
-    pub fn encode_slice<I>(&self, input: &I, output: &mut [u8]) -> usize
+    pub fn decode_exact<const N: usize, I>(&self, input: &I) -> Result<[u8; N], DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        <&Self as Config>::encode_slice(self, input, output)
+        <&Self as Config>::decode_exact(self, input)
     }
 
-    /// See [Config::decode](../trait.Config.html#method.decode).
+    /// See [Config::try_decode_slice](../trait.Config.html#method.try_decode_slice).
     #[inline]
-    pub fn decode<I>(&self, input: &I) -> Result<Vec<u8>, DecodeError>
+    pub fn try_decode_slice<I>(
+        &self,
+        input: &I,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeSliceError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        <&Self as Config>::decode(self, input)
+        <&Self as Config>::try_decode_slice(self, input, output)
     }
 
-    /// See [Config::decode_with_buffer](../trait.Config.html#method.decode_with_buffer).
+    /// See [Config::decode_slice_uninit](../trait.Config.html#method.decode_slice_uninit).
     #[inline]
-    pub fn decode_with_buffer<'i, 'b, I>(
+    pub fn decode_slice_uninit<'o, I>(
         &self,
-        input: &'i I,
-        buffer: &'b mut Vec<u8>,
-    ) -> Result<&'b [u8], DecodeError>
+        input: &I,
+        output: &'o mut [MaybeUninit<u8>],
+    ) -> Result<&'o mut [u8], DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        <&Self as Config>::decode_with_buffer(self, input, buffer)
+        <&Self as Config>::decode_slice_uninit(self, input, output)
     }
 
-    /// See [Config::decode_slice](../trait.Config.html#method.decode_slice).
+    /// See [Config::encode_u64](../trait.Config.html#method.encode_u64).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_u64(&self, value: u64) -> String {
+        <&Self as Config>::encode_u64(self, value)
+    }
+
+    /// See [Config::encode_u128](../trait.Config.html#method.encode_u128).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encode_u128(&self, value: u128) -> String {
+        <&Self as Config>::encode_u128(self, value)
+    }
+
+    /// See [Config::decode_u64](../trait.Config.html#method.decode_u64).
+    #[inline]
+    pub fn decode_u64<I>(&self, input: &I) -> Result<u64, DecodeIntError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_u64(self, input)
+    }
+
+    /// See [Config::decode_u128](../trait.Config.html#method.decode_u128).
+    #[inline]
+    pub fn decode_u128<I>(&self, input: &I) -> Result<u128, DecodeIntError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        <&Self as Config>::decode_u128(self, input)
+    }
+
+    /// See [Config::encoded_output_len](../trait.Config.html#method.encoded_output_len).
     #[inline]
-    pub fn decode_slice<'a, 'b, I>(
+    pub fn encoded_output_len(&self, input_len: usize) -> usize {
+        <&Self as Config>::encoded_output_len(self, input_len)
+    }
+
+    /// See [Config::encoded_output_len_wrapped](../trait.Config.html#method.encoded_output_len_wrapped).
+    #[inline]
+    pub fn encoded_output_len_wrapped(
         &self,
-        input: &'a I,
-        output: &'b mut [u8],
-    ) -> Result<usize, DecodeError>
+        input_len: usize,
+        width: usize,
+        line_ending_len: usize,
+    ) -> usize {
+        <&Self as Config>::encoded_output_len_wrapped(self, input_len, width, line_ending_len)
+    }
+
+    /// See [Config::maximum_decoded_output_len](../trait.Config.html#method.maximum_decoded_output_len).
+    #[inline]
+    pub fn maximum_decoded_output_len(&self, input_len: usize) -> usize {
+        <&Self as Config>::maximum_decoded_output_len(self, input_len)
+    }
+
+    /// See [Config::decoded_len](../trait.Config.html#method.decoded_len).
+    #[inline]
+    pub fn decoded_len<I>(&self, input: &I) -> Result<usize, DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        <&Self as Config>::decode_slice(self, input, output)
+        <&Self as Config>::decoded_len(self, input)
     }
 }
 
@@ -291,6 +1913,8 @@ impl fmt::Debug for CustomConfig {
             .field("encode_table", &&self.encode_table[..])
             .field("decode_table", &&self.decode_table[..])
             .field("padding_byte", &self.padding_byte)
+            .field("decode_padding", &self.decode_padding)
+            .field("line_wrap", &self.line_wrap)
             .finish()
     }
 }
@@ -300,8 +1924,55 @@ impl fmt::Debug for CustomConfig {
 /// See [CustomConfig](struct.CustomConfig.html)
 #[derive(Debug, Clone)]
 pub struct CustomConfigBuilder<'a> {
-    alphabet: &'a [u8],
+    alphabet: BuilderBytes<'a, 64>,
     padding_byte: Option<u8>,
+    decode_padding: Option<DecodePadding>,
+    line_wrap: Option<(usize, BuilderBytes<'a, MAX_SEPARATOR_LEN>)>,
+    // The first byte passed to `replace` that was not found in the alphabet.
+    missing_replacement: Option<u8>,
+}
+
+// Bytes passed to the builder, or copied from an existing config. Copies are
+// held inline, so building a config never allocates.
+#[derive(Debug, Clone)]
+enum BuilderBytes<'a, const N: usize> {
+    Borrowed(&'a [u8]),
+    Owned([u8; N], usize),
+}
+
+impl<const N: usize> BuilderBytes<'_, N> {
+    // Panics if `bytes` is longer than N.
+    fn copied(bytes: &[u8]) -> Self {
+        let mut owned = [0; N];
+        owned[..bytes.len()].copy_from_slice(bytes);
+        BuilderBytes::Owned(owned, bytes.len())
+    }
+
+    // Copy borrowed bytes so they can be modified. Returns None if they are
+    // longer than N, which `build` rejects anyway.
+    fn to_mut(&mut self) -> Option<&mut [u8]> {
+        if let BuilderBytes::Borrowed(bytes) = *self {
+            if bytes.len() > N {
+                return None;
+            }
+            *self = BuilderBytes::copied(bytes);
+        }
+        match self {
+            BuilderBytes::Owned(owned, len) => Some(&mut owned[..*len]),
+            BuilderBytes::Borrowed(_) => None,
+        }
+    }
+}
+
+impl<const N: usize> core::ops::Deref for BuilderBytes<'_, N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            BuilderBytes::Borrowed(bytes) => bytes,
+            BuilderBytes::Owned(owned, len) => &owned[..*len],
+        }
+    }
 }
 
 /// Errors that can occur when building a `CustomConfig`.
@@ -313,6 +1984,12 @@ pub enum CustomConfigError {
     NonAscii(u8),
     /// The alphabet contains duplicate values.
     DuplicateValue(u8),
+    /// The line wrap interval is zero, or the separator is empty or longer
+    /// than 8 bytes.
+    InvalidLineWrap,
+    /// A character passed to `CustomConfigBuilder::replace` is not part of
+    /// the alphabet.
+    NotInAlphabet(u8),
 }
 
 impl<'a> CustomConfigBuilder<'a> {
@@ -320,9 +1997,59 @@ impl<'a> CustomConfigBuilder<'a> {
     /// The provided alphabet needs to be 64 non-repeating ascii bytes.
     pub fn with_alphabet<A: AsRef<[u8]> + ?Sized>(alphabet: &'a A) -> Self {
         CustomConfigBuilder {
-            alphabet: alphabet.as_ref(),
+            alphabet: BuilderBytes::Borrowed(alphabet.as_ref()),
             padding_byte: Some(b'='),
+            decode_padding: None,
+            line_wrap: None,
+            missing_replacement: None,
+        }
+    }
+
+    /// Start from the alphabet, padding, and line wrapping of an existing
+    /// config. Individual characters can then be changed using `replace`.
+    ///
+    /// ```
+    /// use radix64::{configs::CustomConfigBuilder, STD};
+    ///
+    /// // The standard alphabet with `+/` replaced by `.-`.
+    /// let cfg = CustomConfigBuilder::based_on(STD)
+    ///     .replace(b'+', b'.')
+    ///     .replace(b'/', b'-')
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(cfg.encode(&[0xfb, 0xff]), ".-8=");
+    /// ```
+    pub fn based_on<C: Config>(config: C) -> CustomConfigBuilder<'static> {
+        let mut alphabet = [0; 64];
+        for (i, b) in alphabet.iter_mut().enumerate() {
+            *b = config.encode_u6(U6::from_low_six_bits(i as u8));
+        }
+        CustomConfigBuilder {
+            alphabet: BuilderBytes::Owned(alphabet, 64),
+            padding_byte: config.padding_byte(),
+            decode_padding: Some(config.decode_padding()),
+            line_wrap: config
+                .line_wrap()
+                .map(|wrap| (wrap.interval(), BuilderBytes::copied(wrap.separator()))),
+            missing_replacement: None,
+        }
+    }
+
+    /// Replace the character `from` in the alphabet with `to`. If `from` is not
+    /// part of the alphabet `build` will return
+    /// `CustomConfigError::NotInAlphabet`.
+    pub fn replace(mut self, from: u8, to: u8) -> Self {
+        match self.alphabet.iter().position(|&b| b == from) {
+            Some(idx) => {
+                if let Some(alphabet) = self.alphabet.to_mut() {
+                    alphabet[idx] = to;
+                }
+            }
+            None => {
+                self.missing_replacement = self.missing_replacement.or(Some(from));
+            }
         }
+        self
     }
 
     /// Set which character to use for padding.
@@ -337,16 +2064,85 @@ impl<'a> CustomConfigBuilder<'a> {
         self
     }
 
+    /// Set how padding is treated when decoding. If not specified, padding is
+    /// required when the config pads on encode and forbidden otherwise.
+    ///
+    /// ```
+    /// use radix64::{configs::DecodePadding, CustomConfig};
+    ///
+    /// // Emit padding, but accept unpadded input.
+    /// let cfg = CustomConfig::with_alphabet(
+    ///     "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    /// )
+    /// .with_padding(b'=')
+    /// .decode_padding(DecodePadding::Optional(b'='))
+    /// .build()
+    /// .unwrap();
+    /// assert_eq!(cfg.encode("a"), "YQ==");
+    /// assert_eq!(cfg.decode("YQ").unwrap(), b"a");
+    /// ```
+    pub fn decode_padding(mut self, decode_padding: DecodePadding) -> Self {
+        self.decode_padding = Some(decode_padding);
+        self
+    }
+
+    /// Insert the separator after every `interval` bytes of encoded output.
+    /// No separator is emitted after the final line. Decoding skips any
+    /// occurrences of the separator, so wrapped output can be decoded with
+    /// the same config. The separator must be 1 to 8 ascii bytes that are not
+    /// part of the alphabet or padding.
+    ///
+    /// ```
+    /// use radix64::CustomConfig;
+    ///
+    /// // MIME style output with lines of at most 76 characters.
+    /// let mime = CustomConfig::with_alphabet(
+    ///     "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    /// )
+    /// .with_line_wrap(76, "\r\n")
+    /// .build()
+    /// .unwrap();
+    ///
+    /// let encoded = mime.encode(&[0; 60][..]);
+    /// assert_eq!(encoded, format!("{}\r\n{}", "A".repeat(76), "A".repeat(4)));
+    /// assert_eq!(mime.decode(&encoded).unwrap(), &[0; 60][..]);
+    /// ```
+    pub fn with_line_wrap<S: AsRef<[u8]> + ?Sized>(
+        mut self,
+        interval: usize,
+        separator: &'a S,
+    ) -> Self {
+        self.line_wrap = Some((interval, BuilderBytes::Borrowed(separator.as_ref())));
+        self
+    }
+
+    /// Do not insert any line separators.
+    pub fn no_line_wrap(mut self) -> Self {
+        self.line_wrap = None;
+        self
+    }
+
     /// Validate and build the `CustomConfig`.
     pub fn build(self) -> Result<CustomConfig, CustomConfigError> {
         use crate::decode::INVALID_VALUE;
+        if let Some(b) = self.missing_replacement {
+            return Err(CustomConfigError::NotInAlphabet(b));
+        }
         if self.alphabet.len() != 64 {
             return Err(CustomConfigError::AlphabetNot64Bytes);
         }
         if let Some(&b) = self.alphabet.iter().find(|b| !b.is_ascii()) {
             return Err(CustomConfigError::NonAscii(b));
         }
-        if let Some(b) = self.padding_byte {
+        let decode_padding = self.decode_padding.unwrap_or(match self.padding_byte {
+            Some(b) => DecodePadding::Required(b),
+            None => DecodePadding::Forbidden,
+        });
+        let decode_padding_byte = match decode_padding {
+            DecodePadding::Required(b) | DecodePadding::Optional(b) => Some(b),
+            DecodePadding::Forbidden => None,
+        };
+        for b in self.padding_byte.into_iter().chain(decode_padding_byte) {
             if !b.is_ascii() {
                 return Err(CustomConfigError::NonAscii(b));
             }
@@ -355,7 +2151,28 @@ impl<'a> CustomConfigBuilder<'a> {
                 return Err(CustomConfigError::DuplicateValue(b));
             }
         }
-        let mut decode_scratch: Vec<u8> = vec![INVALID_VALUE; 256];
+        let line_wrap = match &self.line_wrap {
+            Some((interval, separator)) => {
+                let interval = *interval;
+                if interval == 0 || separator.is_empty() || separator.len() > MAX_SEPARATOR_LEN {
+                    return Err(CustomConfigError::InvalidLineWrap);
+                }
+                if let Some(&b) = separator.iter().find(|b| !b.is_ascii()) {
+                    return Err(CustomConfigError::NonAscii(b));
+                }
+                // Verify the separator cannot be confused with encoded data.
+                if let Some(&b) = separator.iter().find(|&&b| {
+                    self.alphabet.contains(&b)
+                        || self.padding_byte == Some(b)
+                        || decode_padding_byte == Some(b)
+                }) {
+                    return Err(CustomConfigError::DuplicateValue(b));
+                }
+                Some(LineWrap::new(interval, separator))
+            }
+            None => None,
+        };
+        let mut decode_scratch = [INVALID_VALUE; 256];
         for (i, b) in self.alphabet.iter().cloned().enumerate() {
             if decode_scratch[b as usize] != INVALID_VALUE {
                 return Err(CustomConfigError::DuplicateValue(b));
@@ -364,12 +2181,14 @@ impl<'a> CustomConfigBuilder<'a> {
         }
         let mut encode_table = [0; 64];
         let mut decode_table = [0; 256];
-        encode_table.copy_from_slice(self.alphabet);
+        encode_table.copy_from_slice(&self.alphabet);
         decode_table.copy_from_slice(&decode_scratch);
         Ok(CustomConfig {
             encode_table,
             decode_table,
             padding_byte: self.padding_byte,
+            decode_padding,
+            line_wrap,
         })
     }
 }
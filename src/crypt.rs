@@ -0,0 +1,75 @@
+//! Generating salts for `crypt(3)` password hashes.
+//!
+//! Salts are drawn from the same `./0-9A-Za-z` alphabet used by
+//! [CRYPT](../constant.CRYPT.html), so a salt generated here can always be
+//! combined with hashes encoded using CRYPT.
+//!
+//! Randomness is supplied by a function that fills a buffer with random bytes,
+//! allowing any random number generator to be used. It must be
+//! cryptographically secure for the salts to serve their purpose.
+//!
+//! ```
+//! use radix64::crypt::{generate_crypt_salt, CryptScheme};
+//! use rand::RngCore;
+//!
+//! let mut rng = rand::thread_rng();
+//! let salt = generate_crypt_salt(CryptScheme::Sha512, |buf| rng.fill_bytes(buf));
+//! assert_eq!(salt.len(), 16);
+//! let setting = format!("{}{}$", CryptScheme::Sha512.prefix(), salt);
+//! assert!(setting.starts_with("$6$"));
+//! ```
+use crate::CRYPT;
+use alloc::string::String;
+
+/// A `crypt(3)` hashing scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CryptScheme {
+    /// The traditional DES based scheme.
+    Des,
+    /// The MD5 based scheme, identified by `$1$`.
+    Md5,
+    /// The SHA-256 based scheme, identified by `$5$`.
+    Sha256,
+    /// The SHA-512 based scheme, identified by `$6$`.
+    Sha512,
+}
+
+impl CryptScheme {
+    /// The number of salt characters the scheme uses.
+    pub fn salt_len(self) -> usize {
+        match self {
+            CryptScheme::Des => 2,
+            CryptScheme::Md5 => 8,
+            CryptScheme::Sha256 | CryptScheme::Sha512 => 16,
+        }
+    }
+
+    /// The prefix that identifies the scheme in a hash or setting string. The
+    /// DES scheme has no prefix.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            CryptScheme::Des => "",
+            CryptScheme::Md5 => "$1$",
+            CryptScheme::Sha256 => "$5$",
+            CryptScheme::Sha512 => "$6$",
+        }
+    }
+}
+
+/// Generate a salt of the length used by the scheme, excluding the scheme's
+/// prefix. `fill_random` is called to fill a buffer with random bytes and
+/// every character of the salt is drawn uniformly from the CRYPT alphabet.
+pub fn generate_crypt_salt<F>(scheme: CryptScheme, mut fill_random: F) -> String
+where
+    F: FnMut(&mut [u8]),
+{
+    // Every character encodes 6 bits. Any bits of a final partial character
+    // are discarded by truncating.
+    let mut random = [0; 12];
+    let random = &mut random[..(scheme.salt_len() * 6).div_ceil(8)];
+    fill_random(random);
+    let mut salt = CRYPT.encode(random);
+    salt.truncate(scheme.salt_len());
+    salt
+}
@@ -1,10 +1,20 @@
-use crate::Config;
-use std::{error, fmt};
+use crate::configs::DecodePadding;
+use crate::uninit::{as_uninit_mut, write_slice};
+use crate::{BufferTooSmall, Config};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+use core::{error, fmt};
 
+#[cfg(feature = "std")]
+pub(crate) mod armor;
 pub(crate) mod block;
+#[cfg(feature = "std")]
+pub(crate) mod indexed;
+#[cfg(feature = "std")]
 pub(crate) mod io;
 
-pub(crate) const INVALID_VALUE: u8 = 255;
+pub(crate) use crate::tables::INVALID_VALUE;
 
 /// Errors that can occur during decoding.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,21 +22,57 @@ pub enum DecodeError {
     /// An invalid byte was found in the input. The offending byte is provided.
     InvalidByte(u8),
     /// The length of the input is invalid.
-    InvalidLength,
+    InvalidLength {
+        /// The length of the encoded input, excluding any line separators.
+        length: usize,
+        /// The length of the encoded input modulo 4.
+        remainder: usize,
+        /// Whether the config expected the input to be padded to a multiple
+        /// of 4 bytes.
+        padding_expected: bool,
+    },
     /// The last non-padding byte of input has discarded bits and those bits are
     /// not zero. While this could be decoded it likely represents a corrupted or
     /// invalid encoding.
     InvalidTrailingBits,
+    /// The input is valid but doesn't decode to the number of bytes required,
+    /// such as by [Config::decode_exact](trait.Config.html#method.decode_exact).
+    UnexpectedDecodedLength {
+        /// The number of bytes required.
+        expected: usize,
+        /// The number of bytes the input decodes to.
+        actual: usize,
+    },
 }
 
 impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DecodeError::InvalidByte(byte) => write!(f, "invalid byte {}", byte),
-            DecodeError::InvalidLength => write!(f, "encoded text cannot have a 6-bit remainder"),
+            DecodeError::InvalidLength {
+                length,
+                remainder,
+                padding_expected: true,
+            } => write!(
+                f,
+                "invalid length {} ({} \u{2261} {} mod 4): padded input must be a multiple of 4 bytes",
+                length, length, remainder
+            ),
+            DecodeError::InvalidLength {
+                length, remainder, ..
+            } => write!(
+                f,
+                "invalid length {} ({} \u{2261} {} mod 4): encoded text cannot have a 6-bit remainder",
+                length, length, remainder
+            ),
             DecodeError::InvalidTrailingBits => {
                 write!(f, "last byte has unnecessary trailing bits")
             }
+            DecodeError::UnexpectedDecodedLength { expected, actual } => write!(
+                f,
+                "decoded length {} does not match the expected length {}",
+                actual, expected
+            ),
         }
     }
 }
@@ -35,8 +81,9 @@ impl error::Error for DecodeError {
     fn description(&self) -> &str {
         match *self {
             DecodeError::InvalidByte(_) => "invalid byte",
-            DecodeError::InvalidLength => "invalid length",
+            DecodeError::InvalidLength { .. } => "invalid length",
             DecodeError::InvalidTrailingBits => "invalid trailing bits",
+            DecodeError::UnexpectedDecodedLength { .. } => "unexpected decoded length",
         }
     }
 
@@ -45,56 +92,1021 @@ impl error::Error for DecodeError {
     }
 }
 
+impl DecodeError {
+    // Replace the length reported by an InvalidLength error with the length of
+    // the entire input. Errors are often detected when only looking at the
+    // final chunk of input.
+    pub(crate) fn with_input_length(self, length: usize) -> Self {
+        match self {
+            DecodeError::InvalidLength {
+                padding_expected, ..
+            } => DecodeError::InvalidLength {
+                length,
+                remainder: length % 4,
+                padding_expected,
+            },
+            err => err,
+        }
+    }
+}
+
+pub(crate) fn invalid_length<C>(config: C, length: usize) -> DecodeError
+where
+    C: Config,
+{
+    DecodeError::InvalidLength {
+        length,
+        remainder: length % 4,
+        padding_expected: matches!(config.decode_padding(), DecodePadding::Required(_)),
+    }
+}
+
 // decode_slice on success will return the number of decoded bytes written.
 pub(crate) fn decode_slice<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    // The decoders only write initialized bytes.
+    decode_uninit(config, input, unsafe { as_uninit_mut(output) })
+}
+
+// Decode into memory that may be uninitialized, returning the number of bytes
+// written. Those bytes are initialized when this returns.
+pub(crate) fn decode_uninit<C>(
     config: C,
     mut input: &[u8],
-    mut output: &mut [u8],
+    mut output: &mut [MaybeUninit<u8>],
 ) -> Result<usize, DecodeError>
 where
     C: Config,
 {
+    if let Some(wrap) = config.line_wrap() {
+        return decode_wrapped_slice(config, wrap.separator(), input, output);
+    }
+    let input_length = input.len();
     input = remove_padding(config, input)?;
-    let (input_idx, output_idx) = decode_full_chunks_without_padding(config, input, output)?;
+    let required = max_decoded_len(input.len());
+    if output.len() < required {
+        output_too_small(input_length, required, output.len());
+    }
+    let (input_idx, output_idx) = decode_full_chunks_uninit(config, input, output)?;
     input = &input[input_idx..];
     output = &mut output[output_idx..];
 
     // Deal with the remaining partial chunk. The padding characters have already been removed.
-    Ok(output_idx + decode_partial_chunk(config, input, output)?)
+    let partial_chunk_len = decode_partial_chunk_uninit(config, input, output)
+        .map_err(|err| err.with_input_length(input_length))?;
+    Ok(output_idx + partial_chunk_len)
+}
+
+/// Errors that can occur when decoding text with `decode_utf8`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeUtf8Error {
+    /// The input is not valid base64.
+    Decode(DecodeError),
+    /// The decoded data is not valid UTF-8. The decoded bytes can be
+    /// recovered with `FromUtf8Error::into_bytes`.
+    Utf8(alloc::string::FromUtf8Error),
 }
 
+#[cfg(feature = "alloc")]
+impl fmt::Display for DecodeUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeUtf8Error::Decode(ref err) => err.fmt(f),
+            DecodeUtf8Error::Utf8(ref err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl error::Error for DecodeUtf8Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DecodeUtf8Error::Decode(ref err) => Some(err),
+            DecodeUtf8Error::Utf8(ref err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<DecodeError> for DecodeUtf8Error {
+    fn from(err: DecodeError) -> Self {
+        DecodeUtf8Error::Decode(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<alloc::string::FromUtf8Error> for DecodeUtf8Error {
+    fn from(err: alloc::string::FromUtf8Error) -> Self {
+        DecodeUtf8Error::Utf8(err)
+    }
+}
+
+// Decode into the output slice, returning an error rather than panicking when
+// it's too small. The required length is computed the same way as
+// decode_slice, so invalid padding is reported before a short output slice.
+pub(crate) fn try_decode_slice<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, DecodeSliceError>
+where
+    C: Config,
+{
+    let required = match config.line_wrap() {
+        Some(wrap) => {
+            segments_output_len(
+                config,
+                crate::wrap::split_separators(input, wrap.separator()),
+            )
+            .1
+        }
+        None => max_decoded_len(remove_padding(config, input)?.len()),
+    };
+    if output.len() < required {
+        return Err(BufferTooSmall::new(required, output.len()).into());
+    }
+    Ok(decode_slice(config, input, output)?)
+}
+
+/// Errors that can occur when decoding into a slice with `try_decode_slice`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeSliceError {
+    /// The input is not valid base64.
+    Decode(DecodeError),
+    /// The output slice is too small to hold the decoded output.
+    BufferTooSmall(BufferTooSmall),
+}
+
+impl fmt::Display for DecodeSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeSliceError::Decode(ref err) => err.fmt(f),
+            DecodeSliceError::BufferTooSmall(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for DecodeSliceError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DecodeSliceError::Decode(ref err) => Some(err),
+            DecodeSliceError::BufferTooSmall(ref err) => Some(err),
+        }
+    }
+}
+
+impl From<DecodeError> for DecodeSliceError {
+    fn from(err: DecodeError) -> Self {
+        DecodeSliceError::Decode(err)
+    }
+}
+
+impl From<BufferTooSmall> for DecodeSliceError {
+    fn from(err: BufferTooSmall) -> Self {
+        DecodeSliceError::BufferTooSmall(err)
+    }
+}
+
+#[cold]
+fn output_too_small(input_len: usize, required: usize, provided: usize) -> ! {
+    panic!(
+        "output slice too small to decode {} bytes: {} bytes required, {} provided",
+        input_len, required, provided
+    )
+}
+
+/// The maximum number of bytes produced by decoding input_len bytes of input.
 #[inline]
-fn remove_padding<C>(config: C, input: &[u8]) -> Result<&[u8], DecodeError>
+pub(crate) const fn max_decoded_len(input_len: usize) -> usize {
+    input_len / 4 * 3 + input_len % 4 * 3 / 4
+}
+
+// The number of symbols in input once the padding of a builtin config is
+// removed, in a const context. Reports the same errors as decoded_len.
+const fn unpadded_len_const(padding: Option<u8>, input: &[u8]) -> Result<usize, DecodeError> {
+    let length = input.len();
+    let mut unpadded_len = length;
+    if let Some(padding) = padding {
+        let remainder = length % 4;
+        if remainder != 0 {
+            return Err(DecodeError::InvalidLength {
+                length,
+                remainder,
+                padding_expected: true,
+            });
+        }
+        while unpadded_len > 0 && length - unpadded_len < 2 && input[unpadded_len - 1] == padding {
+            unpadded_len -= 1;
+        }
+    }
+    if unpadded_len % 4 == 1 {
+        return Err(DecodeError::InvalidLength {
+            length,
+            remainder: length % 4,
+            padding_expected: padding.is_some(),
+        });
+    }
+    Ok(unpadded_len)
+}
+
+/// The exact number of bytes produced by decoding input with a builtin config,
+/// in a const context.
+pub(crate) const fn decoded_len_const(
+    padding: Option<u8>,
+    input: &[u8],
+) -> Result<usize, DecodeError> {
+    match unpadded_len_const(padding, input) {
+        Ok(unpadded_len) => Ok(max_decoded_len(unpadded_len)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Decode the input using the table and padding in a const context. Invalid
+/// input, or an output array that is not exactly the decoded length, panics,
+/// which is a compile error when evaluated as a constant.
+pub(crate) const fn decode_const<const N: usize>(
+    table: &[u8; 256],
+    padding: Option<u8>,
+    input: &[u8],
+) -> [u8; N] {
+    let unpadded_len = match unpadded_len_const(padding, input) {
+        Ok(unpadded_len) => unpadded_len,
+        Err(_) => panic!("invalid base64 length or padding"),
+    };
+    assert!(
+        N == max_decoded_len(unpadded_len),
+        "output array length does not match the decoded length"
+    );
+    let mut output = [0; N];
+    let mut input_idx = 0;
+    let mut output_idx = 0;
+    let mut chunk = [0; 4];
+    while input_idx < unpadded_len {
+        let chunk_len = if unpadded_len - input_idx < 4 {
+            unpadded_len - input_idx
+        } else {
+            4
+        };
+        let mut i = 0;
+        while i < 4 {
+            chunk[i] = if i < chunk_len {
+                let value = table[input[input_idx + i] as usize];
+                assert!(value != INVALID_VALUE, "invalid byte in base64 input");
+                value
+            } else {
+                0
+            };
+            i += 1;
+        }
+        let n = (chunk[0] as u32) << 18
+            | (chunk[1] as u32) << 12
+            | (chunk[2] as u32) << 6
+            | chunk[3] as u32;
+        let bytes = n.to_be_bytes();
+        let decoded_len = chunk_len * 3 / 4;
+        let mut i = 0;
+        while i < decoded_len {
+            output[output_idx + i] = bytes[i + 1];
+            i += 1;
+        }
+        assert!(
+            decoded_len == 3 || bytes[decoded_len + 1] == 0,
+            "invalid trailing bits in base64 input"
+        );
+        input_idx += chunk_len;
+        output_idx += decoded_len;
+    }
+    output
+}
+
+// The exact number of bytes produced by decoding input, determined from the
+// length of its data and any padding at its end. Only the tail of the data
+// is examined, so the symbols themselves are not validated.
+pub(crate) fn decode_exact<C, const N: usize>(
+    config: C,
+    input: &[u8],
+) -> Result<[u8; N], DecodeError>
 where
     C: Config,
 {
-    Ok(if let Some(padding) = config.padding_byte() {
-        if input.len() % 4 != 0 {
-            return Err(DecodeError::InvalidLength);
+    let actual = decoded_len(config, input)?;
+    if actual != N {
+        return Err(DecodeError::UnexpectedDecodedLength {
+            expected: N,
+            actual,
+        });
+    }
+    let mut output = [0; N];
+    decode_slice(config, input, &mut output)?;
+    Ok(output)
+}
+
+// The same as decoded_len, but reports the same error as decode, which may
+// find an invalid byte before an invalid length.
+pub(crate) fn checked_decoded_len<C>(config: C, input: &[u8]) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    decoded_len(config, input).map_err(|err| validate(config, input).err().unwrap_or(err))
+}
+
+pub(crate) fn decoded_len<C>(config: C, input: &[u8]) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    let mut tail = [0; 7];
+    let (data_len, tail_len) = match config.line_wrap() {
+        None => {
+            let tail_len = core::cmp::min(input.len(), 4 + input.len() % 4);
+            tail[..tail_len].copy_from_slice(&input[input.len() - tail_len..]);
+            (input.len(), tail_len)
+        }
+        Some(wrap) => {
+            let segments = crate::wrap::split_separators(input, wrap.separator());
+            let data_len: usize = segments.clone().map(<[u8]>::len).sum();
+            let tail_len = core::cmp::min(data_len, 4 + data_len % 4);
+            copy_tail(segments, data_len, &mut tail[..tail_len]);
+            (data_len, tail_len)
         }
-        let num_padding_bytes = input
+    };
+    let unpadded_tail_len = remove_padding(config, &tail[..tail_len])
+        .map_err(|err| err.with_input_length(data_len))?
+        .len();
+    let unpadded_len = data_len - tail_len + unpadded_tail_len;
+    if unpadded_len % 4 == 1 {
+        return Err(invalid_length(config, data_len));
+    }
+    Ok(max_decoded_len(unpadded_len))
+}
+
+// Decode a str, rejecting input that contains non-ascii characters without
+// decoding any of it. str::is_ascii checks a word at a time, which is much
+// cheaper than running the decoder up to the offending character.
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_str<C>(config: C, input: &str) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config,
+{
+    if input.is_ascii() || config.line_wrap().is_some() {
+        return config.decode(input);
+    }
+    // Report the same error decoding would: a length error, otherwise the
+    // first byte that is not part of the alphabet.
+    let input = remove_padding(config, input.as_bytes())?;
+    let invalid_byte = input
+        .iter()
+        .cloned()
+        .find(|&b| config.decode_u8(b) == INVALID_VALUE)
+        .expect("non-ascii input contains an invalid byte");
+    Err(DecodeError::InvalidByte(invalid_byte))
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_range<C>(
+    config: C,
+    input: &[u8],
+    range: core::ops::Range<usize>,
+) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config,
+{
+    let span = encoded_span(
+        config,
+        input.len() as u64,
+        range.start as u64,
+        range.end as u64,
+    );
+    let (start_pos, end_pos) = match span {
+        Some(span) => span,
+        None => return Ok(Vec::new()),
+    };
+    let mut decoded = config.decode(&input[start_pos as usize..end_pos as usize])?;
+    let skip = core::cmp::min(range.start % 3, decoded.len());
+    decoded.drain(..skip);
+    decoded.truncate(range.end - range.start);
+    Ok(decoded)
+}
+
+// The range of bytes within encoded_len bytes of encoded input that holds the
+// chunks covering decoded bytes start..end. Returns None if start is beyond
+// the last chunk. The range may decode to fewer bytes than requested when it
+// includes the final chunk.
+#[cfg(feature = "alloc")]
+pub(crate) fn encoded_span<C: Config>(
+    config: C,
+    encoded_len: u64,
+    start: u64,
+    end: u64,
+) -> Option<(u64, u64)> {
+    let total_symbols = symbols_in(config, encoded_len);
+    let start_symbol = start / 3 * 4;
+    if start_symbol >= total_symbols || start >= end {
+        return None;
+    }
+    let end_symbol = core::cmp::min(end.div_ceil(3) * 4, total_symbols);
+    let start_pos = position_of(config, start_symbol);
+    let end_pos = if end_symbol == total_symbols {
+        encoded_len
+    } else {
+        position_of(config, end_symbol)
+    };
+    Some((start_pos, end_pos))
+}
+
+// The number of encoded symbols (including padding, excluding line
+// separators) in encoded_len bytes of input.
+#[cfg(feature = "alloc")]
+fn symbols_in<C: Config>(config: C, encoded_len: u64) -> u64 {
+    match config.line_wrap() {
+        None => encoded_len,
+        Some(wrap) => {
+            let interval = wrap.interval() as u64;
+            let line_len = interval + wrap.separator().len() as u64;
+            let full_lines = encoded_len / line_len;
+            full_lines * interval + core::cmp::min(encoded_len % line_len, interval)
+        }
+    }
+}
+
+// The byte offset of the symbol within the encoded input.
+#[cfg(feature = "alloc")]
+fn position_of<C: Config>(config: C, symbol: u64) -> u64 {
+    match config.line_wrap() {
+        None => symbol,
+        Some(wrap) => symbol + symbol / wrap.interval() as u64 * wrap.separator().len() as u64,
+    }
+}
+
+// Decode the input appending the output to the provided collection. Unwrapped
+// input is decoded a block at a time into a fixed size stack buffer, leaving
+// the final (possibly padded) chunks to decode_slice.
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_into<C, T>(config: C, input: &[u8], output: &mut T) -> Result<(), DecodeError>
+where
+    C: Config,
+    T: Extend<u8>,
+{
+    // Number of input bytes decoded at a time. Must be a multiple of 4.
+    const BLOCK_SIZE: usize = 1024;
+    if config.line_wrap().is_some() {
+        // Separators may straddle block boundaries, so wrapped input is
+        // decoded all at once.
+        output.extend(config.decode(input)?);
+        return Ok(());
+    }
+    // Check the length of padded input before decoding anything, the same as
+    // decode_slice.
+    remove_padding(config, input)?;
+    let mut buffer = [0; (BLOCK_SIZE + 4) / 4 * 3 + 1];
+    let mut remaining = input;
+    while remaining.len() > BLOCK_SIZE + 4 {
+        let (block, rest) = remaining.split_at(BLOCK_SIZE);
+        let (input_idx, output_idx) =
+            decode_full_chunks_without_padding(config, block, &mut buffer)?;
+        debug_assert_eq!(input_idx, BLOCK_SIZE);
+        output.extend(buffer[..output_idx].iter().cloned());
+        remaining = rest;
+    }
+    let decoded_len = decode_slice(config, remaining, &mut buffer)
+        .map_err(|err| err.with_input_length(input.len()))?;
+    output.extend(buffer[..decoded_len].iter().cloned());
+    Ok(())
+}
+
+// Copy the final tail.len() bytes of the data in the segments into tail, where
+// data_len is the length of the data in all the segments.
+fn copy_tail<'a, S>(segments: S, data_len: usize, tail: &mut [u8])
+where
+    S: Iterator<Item = &'a [u8]>,
+{
+    let mut skip = data_len - tail.len();
+    let mut filled = 0;
+    for segment in segments {
+        let start = core::cmp::min(skip, segment.len());
+        skip -= start;
+        let segment = &segment[start..];
+        tail[filled..filled + segment.len()].copy_from_slice(segment);
+        filled += segment.len();
+    }
+    debug_assert_eq!(filled, tail.len());
+}
+
+// Check that the input decodes successfully without producing any output.
+// The input is decoded a block at a time into a fixed size stack buffer that
+// is discarded. Wrapped input has its separators removed as it's copied into
+// a block, and its final (possibly padded) chunks are handled exactly like
+// decode_segments_uninit so that the same error is reported.
+pub(crate) fn validate<C>(config: C, input: &[u8]) -> Result<(), DecodeError>
+where
+    C: Config,
+{
+    // Number of input bytes decoded at a time. Must be a multiple of 4.
+    const BLOCK_SIZE: usize = 1024;
+    let mut buffer = [0; (BLOCK_SIZE + 4) / 4 * 3 + 1];
+    let wrap = match config.line_wrap() {
+        Some(wrap) => wrap,
+        None => {
+            // Check the length of padded input before decoding anything, the
+            // same as decode_slice.
+            remove_padding(config, input)?;
+            let mut remaining = input;
+            while remaining.len() > BLOCK_SIZE + 4 {
+                let (block, rest) = remaining.split_at(BLOCK_SIZE);
+                decode_full_chunks_without_padding(config, block, &mut buffer)?;
+                remaining = rest;
+            }
+            decode_slice(config, remaining, &mut buffer)
+                .map_err(|err| err.with_input_length(input.len()))?;
+            return Ok(());
+        }
+    };
+    let segments = crate::wrap::split_separators(input, wrap.separator());
+    let (data_len, _) = segments_output_len(config, segments.clone());
+    let tail_start = match data_len % 4 {
+        0 => data_len.saturating_sub(4),
+        rem => data_len - rem,
+    };
+    let mut block = [0; BLOCK_SIZE];
+    let mut block_len = 0;
+    let mut data_idx = 0;
+    let mut tail = [0; 4];
+    let mut tail_len = 0;
+    for segment in segments {
+        let body_len = core::cmp::min(segment.len(), tail_start.saturating_sub(data_idx));
+        let (mut body, segment_tail) = segment.split_at(body_len);
+        data_idx += segment.len();
+        while !body.is_empty() {
+            let n = core::cmp::min(BLOCK_SIZE - block_len, body.len());
+            block[block_len..block_len + n].copy_from_slice(&body[..n]);
+            block_len += n;
+            body = &body[n..];
+            if block_len == BLOCK_SIZE {
+                decode_full_chunks_without_padding(config, &block, &mut buffer)?;
+                block_len = 0;
+            }
+        }
+        tail[tail_len..tail_len + segment_tail.len()].copy_from_slice(segment_tail);
+        tail_len += segment_tail.len();
+    }
+    decode_full_chunks_without_padding(config, &block[..block_len], &mut buffer)?;
+    // The decoders only write initialized bytes.
+    decode_tail(config, &tail[..tail_len], data_len, unsafe {
+        as_uninit_mut(&mut buffer)
+    })?;
+    Ok(())
+}
+
+// Decode the input in place. Unwrapped input is decoded a block at a time into
+// a fixed size stack buffer and copied back to the front of buf, which is
+// always behind the input still to be decoded. Wrapped input has its
+// separators removed first.
+pub(crate) fn decode_in_place<C>(config: C, buf: &mut [u8]) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    // Number of input bytes decoded at a time. Must be a multiple of 4.
+    const BLOCK_SIZE: usize = 1024;
+    let input_len = match config.line_wrap() {
+        Some(wrap) => crate::wrap::remove_separators(buf, wrap.separator()),
+        None => buf.len(),
+    };
+    // Check the length of padded input before decoding anything, the same as
+    // decode_slice.
+    remove_padding(config, &buf[..input_len])?;
+    let mut buffer = [0; (BLOCK_SIZE + 4) / 4 * 3 + 1];
+    let mut input_idx = 0;
+    let mut output_idx = 0;
+    while input_len - input_idx > BLOCK_SIZE + 4 {
+        let block = &buf[input_idx..input_idx + BLOCK_SIZE];
+        let (block_idx, decoded_len) =
+            decode_full_chunks_without_padding(config, block, &mut buffer)?;
+        debug_assert_eq!(block_idx, BLOCK_SIZE);
+        buf[output_idx..output_idx + decoded_len].copy_from_slice(&buffer[..decoded_len]);
+        input_idx += BLOCK_SIZE;
+        output_idx += decoded_len;
+    }
+    let decoded_len = decode_slice(config, &buf[input_idx..input_len], &mut buffer)
+        .map_err(|err| err.with_input_length(input_len))?;
+    buf[output_idx..output_idx + decoded_len].copy_from_slice(&buffer[..decoded_len]);
+    Ok(output_idx + decoded_len)
+}
+
+// Decode input split across any number of segments. Unwrapped input is decoded
+// in place, carrying the chunks that straddle segments over from one segment to
+// the next. Separators may also straddle segments, so wrapped input is split on
+// separators that span segments before decoding it the same way.
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_split<'a, C, S>(config: C, segments: S) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config,
+    S: Iterator<Item = &'a [u8]> + Clone,
+{
+    use crate::OutputBuffer;
+    let input_len: usize = segments.clone().map(<[u8]>::len).sum();
+    let mut output = Vec::new();
+    match config.line_wrap() {
+        Some(wrap) => {
+            let segments = crate::wrap::split_segment_separators(segments, wrap.separator());
+            output.append_decoded(max_decoded_len(input_len) + 1, |buffer| {
+                decode_segments_uninit(config, segments, buffer)
+            })?;
+        }
+        None => {
+            // Check the length of padded input before decoding anything, the
+            // same as decode_slice. Only the tail of the input is needed to do
+            // so.
+            let tail_len = core::cmp::min(input_len, 4 + input_len % 4);
+            let mut tail = [0; 7];
+            copy_tail(segments.clone(), input_len, &mut tail[..tail_len]);
+            remove_padding(config, &tail[..tail_len])
+                .map_err(|err| err.with_input_length(input_len))?;
+            output.append_decoded(max_decoded_len(input_len) + 1, |buffer| {
+                decode_segments_uninit(config, segments, buffer)
+            })?;
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(feature = "bytes")]
+pub(crate) fn decode_buf<C, B>(config: C, buf: B) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config,
+    B: bytes::Buf,
+{
+    let input_len = buf.remaining();
+    read_to_end_infallible(io::DecodeReader::new(config, buf.reader()), input_len)
+}
+
+// Read everything from a DecodeReader whose underlying reader cannot fail, so
+// that any error is a DecodeError.
+#[cfg(feature = "bytes")]
+fn read_to_end_infallible<C, R>(
+    mut reader: io::DecodeReader<C, R>,
+    input_len: usize,
+) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config,
+    R: std::io::Read,
+{
+    use std::io::Read;
+    let mut output = Vec::with_capacity(max_decoded_len(input_len) + 1);
+    match reader.read_to_end(&mut output) {
+        Ok(_) => Ok(output),
+        Err(err) => Err(*err
+            .into_inner()
+            .and_then(|err| err.downcast().ok())
+            .expect("reading the input cannot fail")),
+    }
+}
+
+// Decode UTF-16 input by narrowing it into a vector that is then decoded in
+// place. Non-ascii units become 0xFF, which is never part of an alphabet,
+// padding, or line separator.
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_utf16<C>(config: C, input: &[u16]) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config,
+{
+    let mut buf: Vec<u8> = input
+        .iter()
+        .map(|&unit| if unit < 0x80 { unit as u8 } else { 0xFF })
+        .collect();
+    let decoded_len = decode_in_place(config, &mut buf)?;
+    buf.truncate(decoded_len);
+    Ok(buf)
+}
+
+// Return the length of the longest prefix of input that consists of complete
+// 4 byte chunks, along with any line separators that follow them.
+#[cfg(feature = "alloc")]
+pub(crate) fn complete_chunks_len<C>(config: C, input: &[u8]) -> usize
+where
+    C: Config,
+{
+    let wrap = match config.line_wrap() {
+        Some(wrap) => wrap,
+        None => return input.len() - input.len() % 4,
+    };
+    let separator = wrap.separator();
+    let mut idx = 0;
+    let mut data_len = 0;
+    let mut complete_len = 0;
+    loop {
+        while input[idx..].starts_with(separator) {
+            idx += separator.len();
+        }
+        if data_len % 4 == 0 {
+            complete_len = idx;
+        }
+        if idx == input.len() {
+            return complete_len;
+        }
+        idx += 1;
+        data_len += 1;
+    }
+}
+
+// Return the length of the document at the start of input: the symbols of
+// the alphabet along with any line separators between them, followed by any
+// padding that completes the final chunk. Input that follows is not examined.
+#[cfg(feature = "alloc")]
+pub(crate) fn prefix_len<C>(config: C, input: &[u8]) -> usize
+where
+    C: Config,
+{
+    let wrap = config.line_wrap();
+    let separator = wrap.as_ref().map_or(&[][..], |wrap| wrap.separator());
+    let skip_separators = |mut idx: usize| {
+        while !separator.is_empty() && input[idx..].starts_with(separator) {
+            idx += separator.len();
+        }
+        idx
+    };
+    let mut idx = 0;
+    let mut num_symbols = 0;
+    let mut end = 0;
+    loop {
+        idx = skip_separators(idx);
+        match input.get(idx) {
+            Some(&b) if config.decode_u8(b) != INVALID_VALUE => {
+                idx += 1;
+                num_symbols += 1;
+                end = idx;
+            }
+            _ => break,
+        }
+    }
+    let padding = match config.decode_padding() {
+        DecodePadding::Required(padding) | DecodePadding::Optional(padding) => padding,
+        DecodePadding::Forbidden => return end,
+    };
+    if num_symbols % 4 != 0 {
+        let mut idx = end;
+        for _ in num_symbols % 4..4 {
+            idx = skip_separators(idx);
+            if input.get(idx) != Some(&padding) {
+                break;
+            }
+            idx += 1;
+            end = idx;
+        }
+    }
+    end
+}
+
+// Check that the input is exactly what encoding its decoded data would
+// produce, without writing any output.
+pub(crate) fn is_canonical<C>(config: C, input: &[u8]) -> bool
+where
+    C: Config,
+{
+    match config.line_wrap() {
+        None => canonical_symbols(config, input.iter().cloned(), input.len()),
+        Some(wrap) => {
+            // Every line but the last must be full and followed by exactly one
+            // separator. The last line must not be empty.
+            let line_len = wrap.interval() + wrap.separator().len();
+            let mut lines = input.chunks(line_len).peekable();
+            while let Some(line) = lines.next() {
+                let (data, separator) = line.split_at(core::cmp::min(wrap.interval(), line.len()));
+                let is_last = lines.peek().is_none();
+                if is_last && !separator.is_empty() || !is_last && separator != wrap.separator() {
+                    return false;
+                }
+                if data.is_empty() {
+                    return false;
+                }
+            }
+            // The final line is never full length, so each full length chunk
+            // ends with a separator.
+            let data_len = input.len() - (input.len() / line_len) * wrap.separator().len();
+            let data = input
+                .chunks(line_len)
+                .flat_map(|line| &line[..core::cmp::min(wrap.interval(), line.len())])
+                .cloned();
+            canonical_symbols(config, data, data_len)
+        }
+    }
+}
+
+// Check that the separator-free symbols are valid, correctly padded, and have
+// no discarded bits set.
+fn canonical_symbols<C, I>(config: C, symbols: I, len: usize) -> bool
+where
+    C: Config,
+    I: Iterator<Item = u8>,
+{
+    let mut num_padding = 0;
+    let mut last_value = 0;
+    for b in symbols {
+        if Some(b) == config.padding_byte() {
+            num_padding += 1;
+            continue;
+        }
+        let value = config.decode_u8(b);
+        if value == INVALID_VALUE || num_padding > 0 {
+            return false;
+        }
+        last_value = value;
+    }
+    let num_data = len - num_padding;
+    let expected_padding = match config.padding_byte() {
+        Some(_) => (4 - num_data % 4) % 4,
+        None => 0,
+    };
+    let discarded_bits_mask = match num_data % 4 {
+        0 => 0,
+        1 => return false,
+        2 => 0b0000_1111,
+        _ => 0b0000_0011,
+    };
+    num_padding == expected_padding && last_value & discarded_bits_mask == 0
+}
+
+// Decode input that may contain separators at arbitrary positions.
+fn decode_wrapped_slice<C>(
+    config: C,
+    separator: &[u8],
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    use crate::wrap::split_separators;
+    decode_segments_uninit(config, split_separators(input, separator), output)
+}
+
+// The length of the data in the segments and the size of the output needed to
+// decode it. The final two bytes of the data determine how much padding it ends
+// with.
+fn segments_output_len<'a, C, S>(config: C, segments: S) -> (usize, usize)
+where
+    C: Config,
+    S: Iterator<Item = &'a [u8]>,
+{
+    let mut data_len = 0;
+    let mut last_two = [0; 2];
+    for segment in segments {
+        data_len += segment.len();
+        for &b in segment.iter().rev().take(2).rev() {
+            last_two = [last_two[1], b];
+        }
+    }
+    let padding_len = match config.padding_byte() {
+        Some(pad) => last_two
             .iter()
             .rev()
-            .cloned()
-            .take_while(|&b| b == padding)
-            .take(2)
-            .count();
-        match num_padding_bytes {
-            0 => input,
-            1 => &input[..input.len() - 1],
-            2 => &input[..input.len() - 2],
-            _ => unreachable!("impossible number of padding bytes"),
+            .take(core::cmp::min(data_len, 2))
+            .take_while(|&&b| b == pad)
+            .count(),
+        None => 0,
+    };
+    (data_len, max_decoded_len(data_len - padding_len))
+}
+
+// Decode input that is provided as a series of segments that when concatenated
+// form the encoded data. The input is processed one segment at a time,
+// carrying incomplete chunks over to the next segment. The final (possibly
+// padded) chunk is collected separately so that padding is handled exactly like
+// contiguous input.
+pub(crate) fn decode_segments_uninit<'a, C, S>(
+    config: C,
+    segments: S,
+    output: &mut [MaybeUninit<u8>],
+) -> Result<usize, DecodeError>
+where
+    C: Config,
+    S: Iterator<Item = &'a [u8]> + Clone,
+{
+    use arrayref::array_mut_ref;
+    let (data_len, required) = segments_output_len(config, segments.clone());
+    if output.len() < required {
+        output_too_small(data_len, required, output.len());
+    }
+    let tail_start = match data_len % 4 {
+        0 => data_len.saturating_sub(4),
+        rem => data_len - rem,
+    };
+
+    let mut output_idx = 0;
+    let mut data_idx = 0;
+    let mut carry = [0; 4];
+    let mut carry_len = 0;
+    let mut tail = [0; 4];
+    let mut tail_len = 0;
+    for segment in segments {
+        let body_len = core::cmp::min(segment.len(), tail_start.saturating_sub(data_idx));
+        let (mut body, segment_tail) = segment.split_at(body_len);
+        data_idx += segment.len();
+
+        if carry_len > 0 {
+            let bytes_to_copy = core::cmp::min(4 - carry_len, body.len());
+            carry[carry_len..carry_len + bytes_to_copy].copy_from_slice(&body[..bytes_to_copy]);
+            carry_len += bytes_to_copy;
+            body = &body[bytes_to_copy..];
+            if carry_len == 4 {
+                let decoded = decoded_chunk(config, carry).map_err(DecodeError::InvalidByte)?;
+                write_slice(array_mut_ref!(output, output_idx, 3), &decoded);
+                output_idx += 3;
+                carry_len = 0;
+            }
         }
-    } else {
-        input
-    })
+
+        let (body_idx, body_output_idx) =
+            decode_full_chunks_uninit(config, body, &mut output[output_idx..])?;
+        output_idx += body_output_idx;
+        body = &body[body_idx..];
+        assert!(body.len() < 4, "output buffer too small");
+        carry[carry_len..carry_len + body.len()].copy_from_slice(body);
+        carry_len += body.len();
+
+        tail[tail_len..tail_len + segment_tail.len()].copy_from_slice(segment_tail);
+        tail_len += segment_tail.len();
+    }
+    debug_assert_eq!(carry_len, 0);
+
+    let tail_output_len = decode_tail(
+        config,
+        &tail[..tail_len],
+        data_len,
+        &mut output[output_idx..],
+    )?;
+    Ok(output_idx + tail_output_len)
+}
+
+// Decode the final (possibly padded) chunk of input split into segments, where
+// data_len is the length of the data in all the segments.
+fn decode_tail<C>(
+    config: C,
+    tail: &[u8],
+    data_len: usize,
+    output: &mut [MaybeUninit<u8>],
+) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    let tail = remove_padding(config, tail).map_err(|err| err.with_input_length(data_len))?;
+    let (tail_idx, output_idx) = decode_full_chunks_uninit(config, tail, output)?;
+    let partial_chunk_len =
+        decode_partial_chunk_uninit(config, &tail[tail_idx..], &mut output[output_idx..])
+            .map_err(|err| err.with_input_length(data_len))?;
+    Ok(output_idx + partial_chunk_len)
+}
+
+#[inline]
+fn remove_padding<C>(config: C, input: &[u8]) -> Result<&[u8], DecodeError>
+where
+    C: Config,
+{
+    let (padding, required) = match config.decode_padding() {
+        DecodePadding::Required(padding) => (padding, true),
+        DecodePadding::Optional(padding) => (padding, false),
+        DecodePadding::Forbidden => return Ok(input),
+    };
+    let num_padding_bytes = input
+        .iter()
+        .rev()
+        .cloned()
+        .take_while(|&b| b == padding)
+        .take(2)
+        .count();
+    // Padded input always consists of whole chunks. When padding is optional
+    // unpadded input is validated as a partial chunk instead.
+    if (required || num_padding_bytes > 0) && !input.len().is_multiple_of(4) {
+        return Err(invalid_length(config, input.len()));
+    }
+    Ok(&input[..input.len() - num_padding_bytes])
 }
 
 #[inline]
 fn decode_full_chunks_without_padding<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<(usize, usize), DecodeError>
+where
+    C: Config,
+{
+    // The decoders only write initialized bytes.
+    decode_full_chunks_uninit(config, input, unsafe { as_uninit_mut(output) })
+}
+
+#[inline]
+fn decode_full_chunks_uninit<C>(
     config: C,
     mut input: &[u8],
-    mut output: &mut [u8],
+    mut output: &mut [MaybeUninit<u8>],
 ) -> Result<(usize, usize), DecodeError>
 where
     C: Config,
@@ -117,7 +1129,10 @@ where
 
     let mut iter = DecodeIter::new(input, output);
     while let Some((input, output)) = iter.next_chunk() {
-        decode_chunk(config, *input, output).map_err(DecodeError::InvalidByte)?;
+        write_slice(
+            output,
+            &decoded_chunk(config, *input).map_err(DecodeError::InvalidByte)?,
+        );
     }
 
     let (input_idx2, output_idx2) = iter.remaining();
@@ -125,14 +1140,31 @@ where
 }
 
 #[inline]
-fn decode_partial_chunk<C>(config: C, input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError>
+pub(crate) fn decode_partial_chunk<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    // The decoders only write initialized bytes.
+    decode_partial_chunk_uninit(config, input, unsafe { as_uninit_mut(output) })
+}
+
+#[inline]
+fn decode_partial_chunk_uninit<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+) -> Result<usize, DecodeError>
 where
     C: Config,
 {
     // Deal with the remaining partial chunk. The padding characters have already been removed.
     match input.len() {
         0 => Ok(0),
-        1 => Err(DecodeError::InvalidLength),
+        1 => Err(invalid_length(config, input.len())),
         2 => {
             let first = config.decode_u8(input[0]);
             if first == INVALID_VALUE {
@@ -142,7 +1174,7 @@ where
             if second == INVALID_VALUE {
                 return Err(DecodeError::InvalidByte(input[1]));
             }
-            output[0] = (first << 2) | (second >> 4);
+            write_slice(output, &[(first << 2) | (second >> 4)]);
             if second & 0b0000_1111 != 0 {
                 return Err(DecodeError::InvalidTrailingBits);
             }
@@ -161,8 +1193,10 @@ where
             if third == INVALID_VALUE {
                 return Err(DecodeError::InvalidByte(input[2]));
             }
-            output[0] = (first << 2) | (second >> 4);
-            output[1] = (second << 4) | (third >> 2);
+            write_slice(
+                output,
+                &[(first << 2) | (second >> 4), (second << 4) | (third >> 2)],
+            );
             if third & 0b0000_0011 != 0 {
                 return Err(DecodeError::InvalidTrailingBits);
             }
@@ -172,9 +1206,20 @@ where
     }
 }
 
-/// Decode a chunk. The chunk cannot contain any padding.
+/// Decode a chunk. The chunk cannot contain any padding. The output is left
+/// unchanged if the chunk contains an invalid byte, which is returned.
+#[inline]
+pub(crate) fn decode_chunk<C: Config>(
+    config: C,
+    input: [u8; 4],
+    output: &mut [u8; 3],
+) -> Result<(), u8> {
+    *output = decoded_chunk(config, input)?;
+    Ok(())
+}
+
 #[inline]
-fn decode_chunk<C: Config>(config: C, input: [u8; 4], output: &mut [u8; 3]) -> Result<(), u8> {
+fn decoded_chunk<C: Config>(config: C, input: [u8; 4]) -> Result<[u8; 3], u8> {
     let mut chunk_output: u32 = 0;
     for (idx, input) in input.iter().cloned().enumerate() {
         let decoded = config.decode_u8(input);
@@ -185,17 +1230,15 @@ fn decode_chunk<C: Config>(config: C, input: [u8; 4], output: &mut [u8; 3]) -> R
         chunk_output |= u32::from(decoded) << shift_amount;
     }
     debug_assert!(chunk_output.trailing_zeros() >= 8);
-    write_be_u24(chunk_output, output);
-    Ok(())
+    let mut output = [0; 3];
+    write_be_u24(chunk_output, &mut output);
+    Ok(output)
 }
 
 /// Copy the 24 most significant bits into the provided buffer.
 #[inline]
 fn write_be_u24(n: u32, buf: &mut [u8; 3]) {
-    unsafe {
-        let n: [u8; 4] = *(&n.to_be() as *const _ as *const [u8; 4]);
-        std::ptr::copy_nonoverlapping(n.as_ptr(), buf.as_mut_ptr(), 3);
-    }
+    buf.copy_from_slice(&n.to_be_bytes()[..3]);
 }
 
 #[inline]
@@ -214,8 +1257,43 @@ define_block_iter!(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::string::String;
+
+    #[test]
+    fn decode_chunk_is_big_endian() {
+        let mut buf = [0; 3];
+        write_be_u24(0x0102_0304, &mut buf);
+        assert_eq!(buf, [1, 2, 3]);
+        assert_eq!(decode_chunk(crate::STD, *b"AQID", &mut buf), Ok(()));
+        assert_eq!(buf, [1, 2, 3]);
+    }
+
     #[test]
+    #[should_panic(expected = "to decode 8 bytes: 5 bytes required, 4 provided")]
+    fn decode_slice_panic_states_sizes() {
+        let _ = decode_slice(crate::STD, b"aGVsbG8=", &mut [0; 4]);
+    }
+
+    #[test]
+    fn decode_wrapped_slice_into_exact_output() {
+        let wrapped = crate::configs::CustomConfig::with_alphabet(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        )
+        .with_line_wrap(4, "\r\n")
+        .build()
+        .unwrap();
+        let mut output = [0; 5];
+        assert_eq!(decode_slice(&wrapped, b"aGVs\r\nbG8=", &mut output), Ok(5));
+        assert_eq!(&output, b"hello");
+        let result = std::panic::catch_unwind(|| {
+            let _ = decode_slice(&wrapped, b"aGVs\r\nbG8=", &mut [0; 4]);
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("to decode 8 bytes: 5 bytes required, 4 provided"));
+    }
 
+    #[test]
+    #[cfg(feature = "alloc")]
     fn detect_trailing_bits() {
         use crate::STD;
         assert!(STD.decode("iYU=").is_ok());
@@ -228,4 +1306,87 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn optional_padding() {
+        use crate::configs::OptionalPadding;
+        use crate::{STD, STD_NO_PAD};
+        fn check<C: Config>(cfg: C) {
+            assert_eq!(Ok(b"ab".to_vec()), cfg.decode("YWI="));
+            assert_eq!(Ok(b"ab".to_vec()), cfg.decode("YWI"));
+            assert_eq!(Ok(b"a".to_vec()), cfg.decode("YQ=="));
+            assert_eq!(Ok(b"a".to_vec()), cfg.decode("YQ"));
+            assert_eq!(
+                Err(DecodeError::InvalidLength {
+                    length: 3,
+                    remainder: 3,
+                    padding_expected: false
+                }),
+                cfg.decode("YQ=")
+            );
+            assert_eq!(
+                Err(DecodeError::InvalidLength {
+                    length: 5,
+                    remainder: 1,
+                    padding_expected: false
+                }),
+                cfg.decode("YWJjY")
+            );
+        }
+        check(OptionalPadding(STD));
+        check(OptionalPadding(STD_NO_PAD));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn invalid_length_details() {
+        use crate::{STD, STD_NO_PAD};
+        use std::string::ToString;
+        let err = STD.decode("YWJjZGVmZ2hp=").unwrap_err();
+        assert_eq!(
+            DecodeError::InvalidLength {
+                length: 13,
+                remainder: 1,
+                padding_expected: true
+            },
+            err
+        );
+        assert_eq!(
+            "invalid length 13 (13 \u{2261} 1 mod 4): padded input must be a multiple of 4 bytes",
+            err.to_string()
+        );
+        let err = STD_NO_PAD.decode("YWJjZGVmZ2hpa").unwrap_err();
+        assert_eq!(
+            DecodeError::InvalidLength {
+                length: 13,
+                remainder: 1,
+                padding_expected: false
+            },
+            err
+        );
+        assert_eq!(
+            "invalid length 13 (13 \u{2261} 1 mod 4): encoded text cannot have a 6-bit remainder",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decode_reader_invalid_length_reports_input_length() {
+        use crate::io::DecodeReader;
+        use crate::STD_NO_PAD;
+        use std::io::Read;
+        let input = "A".repeat(2049);
+        let mut reader = DecodeReader::new(STD_NO_PAD, input.as_bytes());
+        let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        let err = err.get_ref().unwrap().downcast_ref::<DecodeError>();
+        assert_eq!(
+            Some(&DecodeError::InvalidLength {
+                length: 2049,
+                remainder: 1,
+                padding_expected: false
+            }),
+            err
+        );
+    }
 }
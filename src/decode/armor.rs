@@ -0,0 +1,197 @@
+use crate::decode::io::DecodeReader;
+use crate::Config;
+use alloc::{string::String, vec::Vec};
+use std::io::{self, BufRead, Read};
+
+/// Decode the body of an armored document, such as a PEM file, from a
+/// std::io::BufRead.
+///
+/// A `-----BEGIN label-----` line preceding the body is skipped and its label
+/// is captured, blank lines and line breaks are ignored, and reading stops at
+/// the matching `-----END label-----` line. Input without a BEGIN line is
+/// decoded as an unarmored body. This allows `std::io::copy` to decode a PEM
+/// file directly, without a separate parsing stage.
+///
+/// The underlying reader is not read beyond the END line, so further
+/// documents can be decoded from the reader returned by
+/// [into_inner](#method.into_inner).
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::ArmorReader};
+/// use std::io::Read;
+///
+/// let pem = "-----BEGIN MESSAGE-----\nbXkgbWVz\nc2FnZQ==\n-----END MESSAGE-----\n";
+/// let mut reader = ArmorReader::new(STD, pem.as_bytes());
+/// let mut decoded = Vec::new();
+/// reader.read_to_end(&mut decoded)?;
+/// assert_eq!(decoded, b"my message");
+/// assert_eq!(reader.label(), Some("MESSAGE"));
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub struct ArmorReader<C, R> {
+    inner: DecodeReader<C, Dearmor<R>>,
+}
+
+impl<C, R> ArmorReader<C, R>
+where
+    C: Config,
+    R: BufRead,
+{
+    /// Create a new ArmorReader that wraps the provided reader. The config
+    /// should not wrap lines, since the line breaks of the body are removed
+    /// before decoding.
+    pub fn new(config: C, rdr: R) -> Self {
+        ArmorReader {
+            inner: DecodeReader::new(config, Dearmor::new(rdr)),
+        }
+    }
+
+    /// The label of the BEGIN line, once it has been read. None if no BEGIN
+    /// line has been read yet or the input is not armored.
+    pub fn label(&self) -> Option<&str> {
+        self.inner.get_ref().label.as_deref()
+    }
+
+    /// Consume the ArmorReader, returning the underlying reader. Once reading
+    /// has returned EOF, the underlying reader is positioned after the END
+    /// line.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner().rdr
+    }
+}
+
+impl<C, R> Read for ArmorReader<C, R>
+where
+    C: Config,
+    R: BufRead,
+{
+    fn read(&mut self, output: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(output)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    // No body data or BEGIN line has been read.
+    Start,
+    Body { armored: bool },
+    Done,
+}
+
+// A reader that yields the body of an armored document with the armor lines
+// and line breaks removed.
+struct Dearmor<R> {
+    rdr: R,
+    state: State,
+    label: Option<String>,
+    line: Vec<u8>,
+    // line[pos..] is body data that has not yet been returned.
+    pos: usize,
+}
+
+impl<R> Dearmor<R>
+where
+    R: BufRead,
+{
+    fn new(rdr: R) -> Self {
+        Dearmor {
+            rdr,
+            state: State::Start,
+            label: None,
+            line: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    // Read lines until one containing body data is found, handling any armor
+    // lines along the way. Leaves line empty once the body has ended.
+    fn next_body_line(&mut self) -> io::Result<()> {
+        loop {
+            self.line.clear();
+            self.pos = 0;
+            if self.state == State::Done {
+                return Ok(());
+            }
+            if self.rdr.read_until(b'\n', &mut self.line)? == 0 {
+                if self.state == (State::Body { armored: true }) {
+                    return Err(invalid_armor("missing END line"));
+                }
+                self.state = State::Done;
+                return Ok(());
+            }
+            let len = trimmed_len(&self.line);
+            self.line.truncate(len);
+            if self.line.is_empty() {
+                continue;
+            }
+            if let Some(label) = armor_label(&self.line, b"-----BEGIN ") {
+                if self.state != State::Start {
+                    return Err(invalid_armor("unexpected BEGIN line"));
+                }
+                let label = String::from_utf8(label.to_vec())
+                    .map_err(|_| invalid_armor("BEGIN line label is not valid utf-8"))?;
+                self.label = Some(label);
+                self.state = State::Body { armored: true };
+                continue;
+            }
+            if let Some(label) = armor_label(&self.line, b"-----END ") {
+                if self.state != (State::Body { armored: true }) {
+                    return Err(invalid_armor("END line without a BEGIN line"));
+                }
+                if self.label.as_ref().map(String::as_bytes) != Some(label) {
+                    return Err(invalid_armor("END line label does not match BEGIN line"));
+                }
+                self.state = State::Done;
+                continue;
+            }
+            if self.state == State::Start {
+                self.state = State::Body { armored: false };
+            }
+            return Ok(());
+        }
+    }
+}
+
+impl<R> Read for Dearmor<R>
+where
+    R: BufRead,
+{
+    fn read(&mut self, output: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.line.len() {
+            self.next_body_line()?;
+        }
+        let remaining = &self.line[self.pos..];
+        let n = core::cmp::min(remaining.len(), output.len());
+        output[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+// The length of the line without trailing whitespace, including the line
+// break.
+fn trimmed_len(line: &[u8]) -> usize {
+    line.iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |idx| idx + 1)
+}
+
+// Return the label of a line of the form `{prefix}label-----`.
+fn armor_label<'a>(line: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    const SUFFIX: &[u8] = b"-----";
+    if line.len() >= prefix.len() + SUFFIX.len()
+        && line.starts_with(prefix)
+        && line.ends_with(SUFFIX)
+    {
+        Some(&line[prefix.len()..line.len() - SUFFIX.len()])
+    } else {
+        None
+    }
+}
+
+fn invalid_armor(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
@@ -1,19 +1,37 @@
 use crate::decode::INVALID_VALUE;
+use crate::uninit::write_slice;
 use crate::DecodeError;
 use crate::{Config, CustomConfig};
+use core::mem::MaybeUninit;
 
-mod arch;
+pub(crate) mod arch;
 
+/// Provides the block decoder used when decoding with a config.
 pub trait IntoBlockDecoder: Copy {
+    /// The block decoder for this config.
     type BlockDecoder: BlockDecoder;
 
+    /// Returns the block decoder, selecting the fastest kernel available.
     fn into_block_decoder(self) -> Self::BlockDecoder;
 }
 
+/// Decodes input a block at a time.
 pub trait BlockDecoder: Copy {
-    fn decode_blocks(self, input: &[u8], output: &mut [u8]) -> Result<(usize, usize), DecodeError>;
+    /// Decode as many whole blocks of input as possible, returning the number
+    /// of input bytes consumed and output bytes written. Input is always
+    /// consumed a multiple of 4 bytes at a time and must not contain padding.
+    /// The remaining input is left for the caller, which may be all of it.
+    /// Returns `DecodeError::InvalidByte` if a block contains a byte outside
+    /// the alphabet. The output may be uninitialized. Kernels only write
+    /// initialized bytes to it and never read from it.
+    fn decode_blocks(
+        self,
+        input: &[u8],
+        output: &mut [MaybeUninit<u8>],
+    ) -> Result<(usize, usize), DecodeError>;
 }
 
+/// The portable block decoder, used when no SIMD kernel is available.
 #[derive(Debug, Clone, Copy)]
 pub struct ScalarBlockDecoder<C>(C);
 
@@ -21,11 +39,12 @@ impl<C> ScalarBlockDecoder<C>
 where
     C: Config,
 {
+    /// Create a scalar block decoder for the provided config.
     #[inline]
-    pub(crate) fn new(config: C) -> Self {
+    pub fn new(config: C) -> Self {
         ScalarBlockDecoder(config)
     }
-    fn decode_block(self, input: &[u8; 32], output: &mut [u8; 24]) -> Result<(), u8> {
+    fn decode_block(self, input: &[u8; 32], output: &mut [MaybeUninit<u8>; 24]) -> Result<(), u8> {
         use arrayref::{array_mut_ref, array_ref};
         for i in 0..4 {
             self.decode_chunk(
@@ -39,7 +58,7 @@ where
     // Padding input as a reference rather than by value improves performance
     // according to the benchmarks on my machine. Ignore the clippy warning.
     #[allow(clippy::trivially_copy_pass_by_ref)]
-    fn decode_chunk(self, input: &[u8; 8], output: &mut [u8; 6]) -> Result<(), u8> {
+    fn decode_chunk(self, input: &[u8; 8], output: &mut [MaybeUninit<u8>; 6]) -> Result<(), u8> {
         let mut chunk_output: u64 = 0;
         for (idx, input) in input.iter().cloned().enumerate() {
             let decoded = self.0.decode_u8(input);
@@ -59,7 +78,11 @@ impl<C> BlockDecoder for ScalarBlockDecoder<C>
 where
     C: Config,
 {
-    fn decode_blocks(self, input: &[u8], output: &mut [u8]) -> Result<(usize, usize), DecodeError> {
+    fn decode_blocks(
+        self,
+        input: &[u8],
+        output: &mut [MaybeUninit<u8>],
+    ) -> Result<(usize, usize), DecodeError> {
         let mut iter = BlockIter::new(input, output);
         while let Some((input_block, output_block)) = iter.next_chunk() {
             self.decode_block(input_block, output_block)
@@ -88,9 +111,36 @@ impl IntoBlockDecoder for &CustomConfig {
 
 /// Copy the 48 most significant bits into the provided buffer.
 #[inline]
-fn write_be_u48(n: u64, buf: &mut [u8; 6]) {
-    unsafe {
-        let n: [u8; 8] = *(&n.to_be() as *const u64 as *const [u8; 8]);
-        std::ptr::copy_nonoverlapping(n.as_ptr(), buf.as_mut_ptr(), 6);
+fn write_be_u48(n: u64, buf: &mut [MaybeUninit<u8>; 6]) {
+    write_slice(buf, &n.to_be_bytes()[..6]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::STD;
+    use std::vec::Vec;
+
+    #[test]
+    fn write_be_u48_is_big_endian() {
+        let mut buf = [MaybeUninit::uninit(); 6];
+        write_be_u48(0x0102_0304_0506_0708, &mut buf);
+        assert_eq!(
+            unsafe { crate::uninit::assume_init_mut(&mut buf) },
+            [1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn scalar_decode_blocks() {
+        let input = b"AAECAwQFBgcICQoLDA0ODxAREhMUFRYX";
+        let mut output = [0; 24];
+        assert_eq!(
+            ScalarBlockDecoder::new(STD)
+                .decode_blocks(input, unsafe { crate::uninit::as_uninit_mut(&mut output) }),
+            Ok((32, 24))
+        );
+        let expected: Vec<u8> = (0..24).collect();
+        assert_eq!(&output[..], &expected[..]);
     }
 }
@@ -3,14 +3,8 @@
 use cfg_if::cfg_if;
 
 cfg_if! {
-    if #[cfg(feature = "simd")] {
-        cfg_if! {
-            if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
-                pub(crate) mod x86;
-            } else {
-                pub(crate) mod other;
-            }
-        }
+    if #[cfg(all(any(feature = "avx2", feature = "ssse3"), any(target_arch = "x86", target_arch = "x86_64")))] {
+        pub(crate) mod x86;
     } else {
         pub(crate) mod other;
     }
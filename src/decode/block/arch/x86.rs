@@ -1,26 +1,13 @@
 //! This module is only included on x86 and x86_64.
-use crate::Config;
 use crate::decode::block::{BlockDecoder, IntoBlockDecoder, ScalarBlockDecoder};
 use crate::decode::DecodeError;
 use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+use core::mem::MaybeUninit;
 #[derive(Debug, Clone, Copy)]
 pub struct Decoder<C>(C);
 
-impl<C> BlockDecoder for Decoder<C> where C: Config + avx2::Translate256i {
-    #[inline]
-    fn decode_blocks(
-        self,
-        input: &[u8],
-        output: &mut [u8],
-    ) -> Result<(usize, usize), DecodeError> {
-        if let Ok(decoder) = avx2::Decoder::new(self.0) {
-            Ok(decoder.decode_blocks(input, output))
-        } else {
-            ScalarBlockDecoder::new(self.0).decode_blocks(input, output)
-        }
-    }
-}
-
+// Each builtin config tries the widest kernel that is compiled in and selected
+// by the active backend, falling back to the scalar implementation.
 macro_rules! define_into_block_decoder {
     ($( $cfg:ident ),+) => {$(
         impl IntoBlockDecoder for $cfg {
@@ -31,28 +18,64 @@ macro_rules! define_into_block_decoder {
                 Decoder(self)
             }
         }
+
+        impl BlockDecoder for Decoder<$cfg> {
+            #[inline]
+            fn decode_blocks(
+                self,
+                input: &[u8],
+                output: &mut [MaybeUninit<u8>],
+            ) -> Result<(usize, usize), DecodeError> {
+                #[cfg(feature = "avx512vbmi")]
+                {
+                    if let Ok(decoder) = avx512vbmi::Decoder::new(self.0) {
+                        return decoder.decode_blocks(input, output);
+                    }
+                }
+                #[cfg(feature = "avx2")]
+                {
+                    if let Ok(decoder) = avx2::Decoder::new(self.0) {
+                        return decoder.decode_blocks(input, output);
+                    }
+                }
+                #[cfg(feature = "ssse3")]
+                {
+                    if let Ok(decoder) = ssse3::Decoder::new(self.0) {
+                        return decoder.decode_blocks(input, output);
+                    }
+                }
+                ScalarBlockDecoder::new(self.0).decode_blocks(input, output)
+            }
+        }
     )+}
 }
 define_into_block_decoder!(Std,StdNoPad,UrlSafe,UrlSafeNoPad,Crypt,Fast);
 
-mod avx2 {
+#[cfg(feature = "avx2")]
+pub(crate) mod avx2 {
      #[cfg(target_arch = "x86")]
-    use std::arch::x86::*;
+    use core::arch::x86::*;
     #[cfg(target_arch = "x86_64")]
-    use std::arch::x86_64::*;
+    use core::arch::x86_64::*;
     use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+    use core::mem::MaybeUninit;
+    use crate::decode::DecodeError;
 
     pub trait Translate256i: Copy {
-        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, ()>;
+        /// Translate the input to 6-bit values. On failure returns a mask with
+        /// a bit set for each byte of input that is not part of the alphabet.
+        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, u32>;
     }
 
+    // The AVX-512 VBMI backend constructs this directly, since creating it
+    // already ensured the CPU supports AVX2.
     #[derive(Debug,Clone,Copy)]
-    pub(crate) struct Decoder<C>(C);
+    pub(crate) struct Decoder<C>(pub(super) C);
 
     impl<C> Decoder<C> where C: Translate256i {
         #[inline]
         pub(crate) fn new(config: C) -> Result<Self, ()> {
-            if is_x86_feature_detected!("avx2") {
+            if crate::backend::use_avx2() {
                 Ok(Decoder(config))
             } else {
                 Err(())
@@ -62,8 +85,8 @@ mod avx2 {
         pub(crate) fn decode_blocks(
             self,
             input: &[u8],
-            output: &mut [u8],
-        ) -> (usize, usize) {
+            output: &mut [MaybeUninit<u8>],
+        ) -> Result<(usize, usize), DecodeError> {
             // The unsafe block is required because _encode_blocks relies on AVX2
             // intrinsics. This is safe because Encoder::new() ensures that an
             // encoder is only successfully created when the CPU supports AVX2.
@@ -74,30 +97,29 @@ mod avx2 {
         unsafe fn _decode_blocks(
             self,
             input: &[u8],
-            output: &mut [u8],
-        ) -> (usize, usize) {
+            output: &mut [MaybeUninit<u8>],
+        ) -> Result<(usize, usize), DecodeError> {
             let mut iter = BlockIter::new(input, output);
             while let Some((input_block, output_block)) = iter.next_chunk() {
                 #[allow(clippy::cast_ptr_alignment)]
                 let mut data = _mm256_loadu_si256(input_block.as_ptr() as *const __m256i);
                 data = match self.decode_block(data) {
                     Ok(data) => data,
-                    Err(_) => {
-                        // Move back to the beginning of the chunk that failed
-                        // and return the remaining slice to the non-optimized
-                        // decoder for better error reporting.
-                        iter.step_back();
-                        return iter.remaining();
+                    Err(invalid_mask) => {
+                        // The lowest set bit of the mask is the first invalid
+                        // byte of the block.
+                        let idx = invalid_mask.trailing_zeros() as usize;
+                        return Err(DecodeError::InvalidByte(input_block[idx]));
                     }
                 };
                 #[allow(clippy::cast_ptr_alignment)]
                 _mm256_storeu_si256(output_block.as_mut_ptr() as *mut __m256i, data);
             }
-            iter.remaining()
+            Ok(iter.remaining())
         }
 
         #[target_feature(enable = "avx2")]
-        unsafe fn decode_block(self, input: __m256i) -> Result<__m256i, ()> {
+        unsafe fn decode_block(self, input: __m256i) -> Result<__m256i, u32> {
             let input = C::translate_m256i(input)?;
             let input = _mm256_maddubs_epi16(input, _mm256_set1_epi32(0x0140_0140));
             let input = _mm256_madd_epi16(input, _mm256_set1_epi32(0x0001_1000));
@@ -122,15 +144,24 @@ mod avx2 {
         }
     }
 
+    /// Decode a single block without checking for AVX2 support. Used by
+    /// crate::arch.
+    #[target_feature(enable = "avx2")]
+    #[inline]
+    pub(crate) unsafe fn decode_block<C: Translate256i>(config: C, input: __m256i) -> Result<__m256i, u32> {
+        Decoder(config).decode_block(input)
+    }
+
     define_block_iter!(name=BlockIter, input_chunk_size=32, input_stride=32, output_chunk_size=32, output_stride=24);
 
     #[target_feature(enable = "avx2")]
     #[inline]
-    unsafe fn translate_std(input: __m256i) -> Result<__m256i, ()> {
+    unsafe fn translate_std(input: __m256i) -> Result<__m256i, u32> {
         let hi_nibbles = _mm256_and_si256(_mm256_srli_epi32(input, 4), _mm256_set1_epi8(0x0f));
         let low_nibbles = _mm256_and_si256(input, _mm256_set1_epi8(0x0f));
-        if !is_valid_std(hi_nibbles, low_nibbles) {
-            return Err(());
+        let invalid_mask = invalid_mask_std(hi_nibbles, low_nibbles);
+        if invalid_mask != 0 {
+            return Err(invalid_mask);
         }
 
         #[rustfmt::skip]
@@ -150,7 +181,7 @@ mod avx2 {
     #[target_feature(enable = "avx2")]
     #[inline]
     #[allow(overflowing_literals)]
-    unsafe fn is_valid_std(hi_nibbles: __m256i, low_nibbles: __m256i) -> bool {
+    unsafe fn invalid_mask_std(hi_nibbles: __m256i, low_nibbles: __m256i) -> u32 {
         #[rustfmt::skip]
         let mask_lut = _mm256_setr_epi8(
             0b1010_1000,                            // 0
@@ -183,16 +214,17 @@ mod avx2 {
         let m = _mm256_shuffle_epi8(mask_lut, low_nibbles);
         let bit = _mm256_shuffle_epi8(bit_pos_lut, hi_nibbles);
         let non_match = _mm256_cmpeq_epi8(_mm256_and_si256(m, bit), _mm256_setzero_si256());
-        _mm256_movemask_epi8(non_match) == 0
+        _mm256_movemask_epi8(non_match) as u32
     }
 
     #[target_feature(enable = "avx2")]
     #[inline]
-    unsafe fn translate_url_safe(input: __m256i) -> Result<__m256i, ()> {
+    unsafe fn translate_url_safe(input: __m256i) -> Result<__m256i, u32> {
         let hi_nibbles = _mm256_and_si256(_mm256_srli_epi32(input, 4), _mm256_set1_epi8(0x0f));
         let low_nibbles = _mm256_and_si256(input, _mm256_set1_epi8(0x0f));
-        if !is_valid_url_safe(hi_nibbles, low_nibbles) {
-            return Err(());
+        let invalid_mask = invalid_mask_url_safe(hi_nibbles, low_nibbles);
+        if invalid_mask != 0 {
+            return Err(invalid_mask);
         }
 
         #[rustfmt::skip]
@@ -212,7 +244,7 @@ mod avx2 {
     #[target_feature(enable = "avx2")]
     #[inline]
     #[allow(overflowing_literals)]
-    unsafe fn is_valid_url_safe(hi_nibbles: __m256i, low_nibbles: __m256i) -> bool {
+    unsafe fn invalid_mask_url_safe(hi_nibbles: __m256i, low_nibbles: __m256i) -> u32 {
         #[rustfmt::skip]
         let mask_lut = _mm256_setr_epi8(
             0b1010_1000,                            // 0
@@ -247,16 +279,17 @@ mod avx2 {
         let m = _mm256_shuffle_epi8(mask_lut, low_nibbles);
         let bit = _mm256_shuffle_epi8(bit_pos_lut, hi_nibbles);
         let non_match = _mm256_cmpeq_epi8(_mm256_and_si256(m, bit), _mm256_setzero_si256());
-        _mm256_movemask_epi8(non_match) == 0
+        _mm256_movemask_epi8(non_match) as u32
     }
 
     #[target_feature(enable = "avx2")]
     #[inline]
-    unsafe fn translate_crypt(input: __m256i) -> Result<__m256i, ()> {
+    unsafe fn translate_crypt(input: __m256i) -> Result<__m256i, u32> {
         let hi_nibbles = _mm256_and_si256(_mm256_srli_epi32(input, 4), _mm256_set1_epi8(0x0f));
         let low_nibbles = _mm256_and_si256(input, _mm256_set1_epi8(0x0f));
-        if !is_valid_crypt(hi_nibbles, low_nibbles) {
-            return Err(());
+        let invalid_mask = invalid_mask_crypt(hi_nibbles, low_nibbles);
+        if invalid_mask != 0 {
+            return Err(invalid_mask);
         }
 
         #[rustfmt::skip]
@@ -273,7 +306,7 @@ mod avx2 {
     #[target_feature(enable = "avx2")]
     #[inline]
     #[allow(overflowing_literals)]
-    unsafe fn is_valid_crypt(hi_nibbles: __m256i, low_nibbles: __m256i) -> bool {
+    unsafe fn invalid_mask_crypt(hi_nibbles: __m256i, low_nibbles: __m256i) -> u32 {
         #[rustfmt::skip]
         let mask_lut = _mm256_setr_epi8(
             0b1010_1000,                            // 0
@@ -304,14 +337,15 @@ mod avx2 {
         let m = _mm256_shuffle_epi8(mask_lut, low_nibbles);
         let bit = _mm256_shuffle_epi8(bit_pos_lut, hi_nibbles);
         let non_match = _mm256_cmpeq_epi8(_mm256_and_si256(m, bit), _mm256_setzero_si256());
-        _mm256_movemask_epi8(non_match) == 0
+        _mm256_movemask_epi8(non_match) as u32
     }
 
     #[target_feature(enable = "avx2")]
     #[inline]
-    unsafe fn translate_fast(input: __m256i) -> Result<__m256i, ()> {
-        if !is_valid_fast(input) {
-            return Err(());
+    unsafe fn translate_fast(input: __m256i) -> Result<__m256i, u32> {
+        let invalid_mask = invalid_mask_fast(input);
+        if invalid_mask != 0 {
+            return Err(invalid_mask);
         }
         Ok(_mm256_sub_epi8(input, _mm256_set1_epi8(62)))
     }
@@ -319,51 +353,380 @@ mod avx2 {
     #[target_feature(enable = "avx2")]
     #[inline]
     #[allow(overflowing_literals)]
-    unsafe fn is_valid_fast(input: __m256i) -> bool {
+    unsafe fn invalid_mask_fast(input: __m256i) -> u32 {
         let gt_125 = _mm256_cmpgt_epi8(input, _mm256_set1_epi8(125));
         let lt_61 = _mm256_cmpgt_epi8(_mm256_set1_epi8(62), input);
-        _mm256_movemask_epi8(_mm256_or_si256(gt_125, lt_61)) == 0
+        _mm256_movemask_epi8(_mm256_or_si256(gt_125, lt_61)) as u32
     }
 
     impl Translate256i for Std {
         #[inline]
-        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, ()> {
+        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, u32> {
             translate_std(input)
         }
     }
 
     impl Translate256i for StdNoPad {
         #[inline]
-        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, ()> {
+        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, u32> {
             translate_std(input)
         }
     }
 
     impl Translate256i for UrlSafe {
         #[inline]
-        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, ()> {
+        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, u32> {
             translate_url_safe(input)
         }
     }
 
     impl Translate256i for UrlSafeNoPad {
         #[inline]
-        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, ()> {
+        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, u32> {
             translate_url_safe(input)
         }
     }
 
     impl Translate256i for Crypt {
         #[inline]
-        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, ()> {
+        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, u32> {
             translate_crypt(input)
         }
     }
 
     impl Translate256i for Fast {
         #[inline]
-        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, ()> {
+        unsafe fn translate_m256i(input: __m256i) -> Result<__m256i, u32> {
             translate_fast(input)
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "avx512vbmi")]
+pub(crate) mod avx512vbmi {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+    use core::mem::MaybeUninit;
+    use crate::decode::DecodeError;
+
+    /// The decode table of a config. Only the first 128 entries are used by
+    /// vpermi2b, every byte with the high bit set is invalid.
+    pub trait Tables: Copy {
+        const DECODE: &'static [u8; 256];
+    }
+
+    #[derive(Debug,Clone,Copy)]
+    pub(crate) struct Decoder<C>(C);
+
+    impl<C> Decoder<C> where C: Tables + super::avx2::Translate256i {
+        #[inline]
+        pub(crate) fn new(config: C) -> Result<Self, ()> {
+            if crate::backend::use_avx512vbmi() {
+                Ok(Decoder(config))
+            } else {
+                Err(())
+            }
+        }
+
+        pub(crate) fn decode_blocks(
+            self,
+            input: &[u8],
+            output: &mut [MaybeUninit<u8>],
+        ) -> Result<(usize, usize), DecodeError> {
+            // The unsafe block is required because _decode_blocks relies on
+            // AVX-512 intrinsics. This is safe because Decoder::new() ensures
+            // that a decoder is only successfully created when the CPU
+            // supports AVX-512 VBMI.
+            let (input_idx, output_idx) = unsafe { self._decode_blocks(input, output)? };
+            // Finish any input too short for a 512-bit block with AVX2, which
+            // every CPU supporting AVX-512 VBMI also supports. The AVX2 decoder is
+            // constructed directly rather than with new(), which checks the
+            // active backend again and fails if set_backend has changed it
+            // since.
+            let avx2 = super::avx2::Decoder(self.0);
+            let (input_rem, output_rem) = avx2.decode_blocks(&input[input_idx..], &mut output[output_idx..])?;
+            Ok((input_idx + input_rem, output_idx + output_rem))
+        }
+
+        #[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+        unsafe fn _decode_blocks(
+            self,
+            input: &[u8],
+            output: &mut [MaybeUninit<u8>],
+        ) -> Result<(usize, usize), DecodeError> {
+            let lut_lo = _mm512_loadu_si512(C::DECODE.as_ptr() as *const _);
+            let lut_hi = _mm512_loadu_si512(C::DECODE[64..].as_ptr() as *const _);
+            let mut iter = BlockIter::new(input, output);
+            while let Some((input_block, output_block)) = iter.next_chunk() {
+                let data = _mm512_loadu_si512(input_block.as_ptr() as *const _);
+                let data = match decode_block(lut_lo, lut_hi, data) {
+                    Ok(data) => data,
+                    Err(invalid_mask) => {
+                        // The lowest set bit of the mask is the first invalid
+                        // byte of the block.
+                        let idx = invalid_mask.trailing_zeros() as usize;
+                        return Err(DecodeError::InvalidByte(input_block[idx]));
+                    }
+                };
+                _mm512_storeu_si512(output_block.as_mut_ptr() as *mut _, data);
+            }
+            Ok(iter.remaining())
+        }
+    }
+
+    // Decode 64 bytes of input into the first 48 bytes of the result. vpermi2b
+    // translates the low 7 bits of each byte using the first half of the
+    // decode table, where invalid entries have the high bit set. On failure
+    // returns a mask with a bit set for each byte of input that is not part of
+    // the alphabet.
+    #[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+    #[inline]
+    unsafe fn decode_block(lut_lo: __m512i, lut_hi: __m512i, input: __m512i) -> Result<__m512i, u64> {
+        let translated = _mm512_permutex2var_epi8(lut_lo, input, lut_hi);
+        let invalid_mask = _mm512_movepi8_mask(_mm512_or_si512(translated, input));
+        if invalid_mask != 0 {
+            return Err(invalid_mask);
+        }
+        let merged = _mm512_maddubs_epi16(translated, _mm512_set1_epi32(0x0140_0140));
+        let merged = _mm512_madd_epi16(merged, _mm512_set1_epi32(0x0001_1000));
+        #[rustfmt::skip]
+        let packed = _mm512_permutexvar_epi8(
+            _mm512_setr_epi32(
+                0x0600_0102, 0x090a_0405, 0x0c0d_0e08,
+                0x1610_1112, 0x191a_1415, 0x1c1d_1e18,
+                0x2620_2122, 0x292a_2425, 0x2c2d_2e28,
+                0x3630_3132, 0x393a_3435, 0x3c3d_3e38,
+                0, 0, 0, 0,
+            ),
+            merged,
+        );
+        Ok(packed)
+    }
+
+    define_block_iter!(name=BlockIter, input_chunk_size=64, input_stride=64, output_chunk_size=64, output_stride=48);
+
+    macro_rules! impl_tables {
+        ($( $cfg:ident => $decode_table:ident ),+) => {$(
+            impl Tables for $cfg {
+                const DECODE: &'static [u8; 256] = crate::tables::$decode_table;
+            }
+        )+}
+    }
+    impl_tables!(
+        Std => STD_DECODE,
+        StdNoPad => STD_DECODE,
+        UrlSafe => URL_SAFE_DECODE,
+        UrlSafeNoPad => URL_SAFE_DECODE,
+        Crypt => CRYPT_DECODE,
+        Fast => FAST_DECODE
+    );
+}
+
+#[cfg(feature = "ssse3")]
+pub(crate) mod ssse3 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+    use core::mem::MaybeUninit;
+    use crate::decode::DecodeError;
+
+    pub trait Translate128i: Copy {
+        /// Translate the input to 6-bit values. On failure returns a mask with
+        /// a bit set for each byte of input that is not part of the alphabet.
+        unsafe fn translate_m128i(input: __m128i) -> Result<__m128i, u32>;
+    }
+
+    #[derive(Debug,Clone,Copy)]
+    pub(crate) struct Decoder<C>(C);
+
+    impl<C> Decoder<C> where C: Translate128i {
+        #[inline]
+        pub(crate) fn new(config: C) -> Result<Self, ()> {
+            if crate::backend::use_ssse3() {
+                Ok(Decoder(config))
+            } else {
+                Err(())
+            }
+        }
+
+        pub(crate) fn decode_blocks(
+            self,
+            input: &[u8],
+            output: &mut [MaybeUninit<u8>],
+        ) -> Result<(usize, usize), DecodeError> {
+            // The unsafe block is required because _decode_blocks relies on
+            // SSSE3 intrinsics. This is safe because Decoder::new() ensures
+            // that a decoder is only successfully created when the CPU
+            // supports SSSE3.
+            unsafe { self._decode_blocks(input, output) }
+        }
+
+        #[target_feature(enable = "ssse3")]
+        unsafe fn _decode_blocks(
+            self,
+            input: &[u8],
+            output: &mut [MaybeUninit<u8>],
+        ) -> Result<(usize, usize), DecodeError> {
+            let mut iter = BlockIter::new(input, output);
+            while let Some((input_block, output_block)) = iter.next_chunk() {
+                #[allow(clippy::cast_ptr_alignment)]
+                let data = _mm_loadu_si128(input_block.as_ptr() as *const __m128i);
+                let data = match self.decode_block(data) {
+                    Ok(data) => data,
+                    Err(invalid_mask) => {
+                        // The lowest set bit of the mask is the first invalid
+                        // byte of the block.
+                        let idx = invalid_mask.trailing_zeros() as usize;
+                        return Err(DecodeError::InvalidByte(input_block[idx]));
+                    }
+                };
+                #[allow(clippy::cast_ptr_alignment)]
+                _mm_storeu_si128(output_block.as_mut_ptr() as *mut __m128i, data);
+            }
+            Ok(iter.remaining())
+        }
+
+        // The 128-bit equivalent of the AVX2 kernel, decoding 16 bytes of
+        // input into the first 12 bytes of the result.
+        #[target_feature(enable = "ssse3")]
+        unsafe fn decode_block(self, input: __m128i) -> Result<__m128i, u32> {
+            let input = C::translate_m128i(input)?;
+            let input = _mm_maddubs_epi16(input, _mm_set1_epi32(0x0140_0140));
+            let input = _mm_madd_epi16(input, _mm_set1_epi32(0x0001_1000));
+            Ok(_mm_shuffle_epi8(
+                input,
+                _mm_setr_epi8(2, 1, 0, 6, 5, 4, 10, 9, 8, 14, 13, 12, -1, -1, -1, -1),
+            ))
+        }
+    }
+
+    define_block_iter!(name=BlockIter, input_chunk_size=16, input_stride=16, output_chunk_size=16, output_stride=12);
+
+    // Translate using the same nibble lookup tables as the AVX2 kernels. Each
+    // byte of mask_lut, indexed by the low nibble, has a bit set for each high
+    // nibble that forms a valid character. shift_lut, indexed by the high
+    // nibble, is added to the character to produce its value, except for the
+    // character matching `special`, which has `special_shift` added instead.
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    unsafe fn translate(
+        input: __m128i,
+        mask_lut: __m128i,
+        shift_lut: __m128i,
+        special: Option<(u8, i8)>,
+    ) -> Result<__m128i, u32> {
+        let hi_nibbles = _mm_and_si128(_mm_srli_epi32(input, 4), _mm_set1_epi8(0x0f));
+        let low_nibbles = _mm_and_si128(input, _mm_set1_epi8(0x0f));
+        let bit_pos_lut = _mm_setr_epi8(1, 2, 4, 8, 16, 32, 64, -128, 0, 0, 0, 0, 0, 0, 0, 0);
+        let m = _mm_shuffle_epi8(mask_lut, low_nibbles);
+        let bit = _mm_shuffle_epi8(bit_pos_lut, hi_nibbles);
+        let non_match = _mm_cmpeq_epi8(_mm_and_si128(m, bit), _mm_setzero_si128());
+        let invalid_mask = _mm_movemask_epi8(non_match) as u32;
+        if invalid_mask != 0 {
+            return Err(invalid_mask);
+        }
+
+        let mut shift = _mm_shuffle_epi8(shift_lut, hi_nibbles);
+        if let Some((special, special_shift)) = special {
+            let eq_special = _mm_cmpeq_epi8(input, _mm_set1_epi8(special as i8));
+            shift = _mm_or_si128(
+                _mm_andnot_si128(eq_special, shift),
+                _mm_and_si128(eq_special, _mm_set1_epi8(special_shift)),
+            );
+        }
+        Ok(_mm_add_epi8(input, shift))
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    #[allow(overflowing_literals)]
+    unsafe fn translate_std(input: __m128i) -> Result<__m128i, u32> {
+        #[rustfmt::skip]
+        let mask_lut = _mm_setr_epi8(
+            0b1010_1000,                            // 0
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  // 1 .. 9
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  //
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  //
+            0b1111_0000,                            // 10
+            0b0101_0100,                            // 11
+            0b0101_0000, 0b0101_0000, 0b0101_0000,  // 12 .. 14
+            0b0101_0100,                            // 15
+        );
+        let shift_lut = _mm_setr_epi8(0, 0, 19, 4, -65, -65, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0);
+        translate(input, mask_lut, shift_lut, Some((b'/', 16)))
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    #[allow(overflowing_literals)]
+    unsafe fn translate_url_safe(input: __m128i) -> Result<__m128i, u32> {
+        #[rustfmt::skip]
+        let mask_lut = _mm_setr_epi8(
+            0b1010_1000,                            // 0
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  // 1 .. 9
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  //
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  //
+            0b1111_0000,                            // 10
+            0b0101_0000, 0b0101_0000,               // 11 .. 12
+            0b0101_0100,                            // 13
+            0b0101_0000,                            // 14
+            0b0111_0000,                            // 15
+        );
+        let shift_lut = _mm_setr_epi8(0, 0, 17, 4, -65, -65, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0);
+        translate(input, mask_lut, shift_lut, Some((b'_', -32)))
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    #[allow(overflowing_literals)]
+    unsafe fn translate_crypt(input: __m128i) -> Result<__m128i, u32> {
+        #[rustfmt::skip]
+        let mask_lut = _mm_setr_epi8(
+            0b1010_1000,                            // 0
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  // 1 .. 9
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  //
+            0b1111_1000, 0b1111_1000, 0b1111_1000,  //
+            0b1111_0000,                            // 10
+            0b0101_0000, 0b0101_0000, 0b0101_0000,  // 11 .. 13
+            0b0101_0100, 0b0101_0100,               // 14 .. 15
+        );
+        let shift_lut = _mm_setr_epi8(0, 0, -46, -46, -53, -53, -59, -59, 0, 0, 0, 0, 0, 0, 0, 0);
+        translate(input, mask_lut, shift_lut, None)
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    unsafe fn translate_fast(input: __m128i) -> Result<__m128i, u32> {
+        let gt_125 = _mm_cmpgt_epi8(input, _mm_set1_epi8(125));
+        let lt_62 = _mm_cmpgt_epi8(_mm_set1_epi8(62), input);
+        let invalid_mask = _mm_movemask_epi8(_mm_or_si128(gt_125, lt_62)) as u32;
+        if invalid_mask != 0 {
+            return Err(invalid_mask);
+        }
+        Ok(_mm_sub_epi8(input, _mm_set1_epi8(62)))
+    }
+
+    macro_rules! impl_translate {
+        ($( $cfg:ident => $translate:ident ),+) => {$(
+            impl Translate128i for $cfg {
+                #[inline]
+                unsafe fn translate_m128i(input: __m128i) -> Result<__m128i, u32> {
+                    $translate(input)
+                }
+            }
+        )+}
+    }
+    impl_translate!(
+        Std => translate_std,
+        StdNoPad => translate_std,
+        UrlSafe => translate_url_safe,
+        UrlSafeNoPad => translate_url_safe,
+        Crypt => translate_crypt,
+        Fast => translate_fast
+    );
+}
@@ -0,0 +1,137 @@
+use crate::decode::encoded_span;
+use crate::decode::io::into_io_err;
+use crate::Config;
+use alloc::vec::Vec;
+use std::io::{self, Read, Seek, SeekFrom};
+
+// The maximum number of 4 byte chunks decoded by a single call to read_at.
+const MAX_CHUNKS_PER_READ: u64 = 1024;
+
+/// Random access to the decoded contents of base64 data from a reader that
+/// supports seeking.
+///
+/// IndexedDecoder translates offsets into the decoded data into offsets of the
+/// 4 byte chunks of encoded data that contain them, so a small range can be
+/// extracted from a large encoded file without decoding everything before it.
+/// The encoded data must not contain anything other than the encoded
+/// characters, padding, and (if the config wraps its output) line separators
+/// in exactly the positions the config places them. In particular, a trailing
+/// newline is not permitted unless it's part of the config's line wrapping.
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::IndexedDecoder};
+/// use std::io::Cursor;
+///
+/// let encoded = STD.encode("hello indexed world");
+/// let mut decoder = IndexedDecoder::new(STD, Cursor::new(encoded));
+/// let mut buf = [0; 7];
+/// decoder.read_exact_at(6, &mut buf)?;
+/// assert_eq!(&buf, b"indexed");
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct IndexedDecoder<C, R> {
+    config: C,
+    rdr: R,
+    // The length of the encoded data in bytes. Determined on first use.
+    encoded_len: Option<u64>,
+    encoded: Vec<u8>,
+    decoded: Vec<u8>,
+}
+
+impl<C, R> IndexedDecoder<C, R>
+where
+    C: Config,
+    R: Read + Seek,
+{
+    /// Create a new IndexedDecoder that reads encoded data from the provided
+    /// reader. The encoded data is assumed to span from the start to the end of
+    /// the reader and must not change size while in use.
+    pub fn new(config: C, rdr: R) -> Self {
+        IndexedDecoder {
+            config,
+            rdr,
+            encoded_len: None,
+            encoded: Vec::new(),
+            decoded: Vec::new(),
+        }
+    }
+
+    /// Consume the IndexedDecoder, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.rdr
+    }
+
+    /// Read decoded data starting at `offset` bytes into the decoded data.
+    /// Returns the number of bytes read, which may be fewer than requested.
+    /// Returns 0 when `offset` is at or beyond the end of the decoded data.
+    /// Errors decoding the chunks covering the requested range are returned as
+    /// an io::Error wrapping the `DecodeError`.
+    pub fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let encoded_len = self.encoded_len()?;
+        let end = core::cmp::min(
+            offset + buf.len() as u64,
+            (offset / 3 + MAX_CHUNKS_PER_READ) * 3,
+        );
+        let (start_pos, end_pos) = match encoded_span(self.config, encoded_len, offset, end) {
+            Some(span) => span,
+            None => return Ok(0),
+        };
+
+        self.encoded.resize((end_pos - start_pos) as usize, 0);
+        self.rdr.seek(SeekFrom::Start(start_pos))?;
+        self.rdr.read_exact(&mut self.encoded)?;
+        self.decoded
+            .resize(crate::decode::max_decoded_len(self.encoded.len()) + 1, 0);
+        let decoded_len = self
+            .config
+            .decode_slice(&self.encoded, &mut self.decoded)
+            .map_err(into_io_err)?;
+
+        let skip = (offset % 3) as usize;
+        if skip >= decoded_len {
+            return Ok(0);
+        }
+        let n = core::cmp::min(buf.len(), decoded_len - skip);
+        buf[..n].copy_from_slice(&self.decoded[skip..skip + n]);
+        Ok(n)
+    }
+
+    /// Read exactly `buf.len()` bytes of decoded data starting at `offset`
+    /// bytes into the decoded data. Returns an error of kind `UnexpectedEof`
+    /// if the decoded data ends first.
+    pub fn read_exact_at(&mut self, mut offset: u64, mut buf: &mut [u8]) -> io::Result<()> {
+        while !buf.is_empty() {
+            match self.read_at(offset, buf)? {
+                0 => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ))
+                }
+                n => {
+                    offset += n as u64;
+                    buf = &mut buf[n..];
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn encoded_len(&mut self) -> io::Result<u64> {
+        match self.encoded_len {
+            Some(len) => Ok(len),
+            None => {
+                let len = self.rdr.seek(SeekFrom::End(0))?;
+                self.encoded_len = Some(len);
+                Ok(len)
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::decode::DecodeError;
+use crate::io::{LimitExceeded, ProgressHook, ProgressReader};
 use crate::Config;
+use alloc::vec;
 use std::io::Read;
 
 /// Decode base64 data from a std::io::Read.
@@ -10,7 +12,27 @@ pub struct DecodeReader<C, R> {
     data: [u8; 1024],
     pos: usize,
     cap: usize,
+    // data[..unfiltered] has had any line separators removed. The bytes in
+    // data[unfiltered..cap] may be the beginning of a separator that has only
+    // been partially read. Equal to cap when the config doesn't wrap lines.
+    unfiltered: usize,
     eof_seen: bool,
+    // number of bytes of input (excluding separators) that have been decoded
+    // and discarded from the front of data.
+    consumed: usize,
+
+    // the maximum number of bytes to decode before returning an error.
+    max_decoded_bytes: Option<u64>,
+    // number of decoded bytes returned so far.
+    decoded_bytes: u64,
+    // whether fill reads until the buffer is full rather than reading once.
+    eager_fill: bool,
+    // whether a partial chunk at EOF is left undecoded rather than an error.
+    truncated_eof: bool,
+    // the range of data that was left undecoded at EOF.
+    leftover: core::ops::Range<usize>,
+    // reports the number of bytes read from rdr, if requested.
+    progress: Option<ProgressHook>,
 
     decoded_partial_chunk: [u8; 3],
     // if bytes_contained_in_partial_chunk is zero then decoded_partial_chunk
@@ -33,14 +55,180 @@ where
             data: [0; 1024],
             pos: 0,
             cap: 0,
+            unfiltered: 0,
             eof_seen: false,
+            consumed: 0,
+            max_decoded_bytes: None,
+            decoded_bytes: 0,
+            eager_fill: false,
+            truncated_eof: false,
+            leftover: 0..0,
+            progress: None,
             decoded_partial_chunk: [0; 3],
             bytes_contained_in_partial_chunk: 0,
         }
     }
 
+    /// Limit the number of bytes that can be decoded. Reads return decoded
+    /// data up to the limit. Once it's determined that the decoded data
+    /// exceeds the limit, reads return an error wrapping
+    /// [LimitExceeded](struct.LimitExceeded.html). At most one byte beyond the
+    /// limit is decoded from the inner reader and it is never returned.
+    ///
+    /// ```
+    /// use radix64::{STD, io::DecodeReader};
+    /// use std::io::Read;
+    ///
+    /// let mut reader = DecodeReader::new(STD, "bXkgbWVzc2FnZQ==".as_bytes())
+    ///     .with_max_decoded_bytes(5);
+    /// let mut decoded = Vec::new();
+    /// assert!(reader.read_to_end(&mut decoded).is_err());
+    /// assert_eq!(decoded, b"my me");
+    /// ```
+    pub fn with_max_decoded_bytes(mut self, limit: u64) -> Self {
+        self.max_decoded_bytes = Some(limit);
+        self
+    }
+
+    /// Read from the underlying reader until the internal buffer is full (or
+    /// EOF is reached) before decoding, rather than decoding whatever a single
+    /// read returns. Readers that return data in small pieces, such as pipes
+    /// and TLS streams, otherwise cause the input to be decoded a few bytes at
+    /// a time, which is much slower than decoding larger blocks.
+    ///
+    /// Reads from the DecodeReader may block until enough data is available
+    /// to fill the buffer, so this is not suitable when decoded data needs to
+    /// be returned as soon as it arrives. If the underlying reader returns an
+    /// error after some data has been read, the data read so far is decoded
+    /// and the error is expected to be returned again by the next read.
+    ///
+    /// ```
+    /// use radix64::{STD, io::DecodeReader};
+    /// use std::io::Read;
+    ///
+    /// let src = "bXkg".as_bytes().chain("bWVzc2FnZQ==".as_bytes());
+    /// let mut reader = DecodeReader::new(STD, src).with_eager_fill();
+    /// let mut decoded = Vec::new();
+    /// reader.read_to_end(&mut decoded).unwrap();
+    /// assert_eq!(decoded, b"my message");
+    /// ```
+    pub fn with_eager_fill(mut self) -> Self {
+        self.eager_fill = true;
+        self
+    }
+
+    /// Treat input that ends partway through a 4 character chunk as a clean
+    /// EOF, rather than an error. Only complete chunks are decoded and the
+    /// remaining bytes are available from
+    /// [leftover](#method.leftover) once EOF has been reached. This allows
+    /// tailing a file that is still being written, by decoding the left over
+    /// bytes along with the data appended to the file later.
+    ///
+    /// A short final chunk is always left over, even for configs that don't
+    /// require padding, since more input may follow it.
+    ///
+    /// ```
+    /// use radix64::{STD, io::DecodeReader};
+    /// use std::io::Read;
+    ///
+    /// let mut reader = DecodeReader::new(STD, "bXkgbWVzc2".as_bytes()).with_truncated_eof();
+    /// let mut decoded = Vec::new();
+    /// reader.read_to_end(&mut decoded).unwrap();
+    /// assert_eq!(decoded, b"my mes");
+    /// assert_eq!(reader.leftover(), b"c2");
+    /// ```
+    pub fn with_truncated_eof(mut self) -> Self {
+        self.truncated_eof = true;
+        self
+    }
+
+    /// The encoded bytes at the end of the input that were not decoded
+    /// because they do not form a complete chunk. Empty unless
+    /// [with_truncated_eof](#method.with_truncated_eof) is set and EOF has
+    /// been reached. Line separators are removed from the left over bytes,
+    /// except for a separator that is cut short at the end of the input.
+    pub fn leftover(&self) -> &[u8] {
+        &self.data[self.leftover.clone()]
+    }
+
+    /// Report progress by calling `callback` with the total number of encoded
+    /// bytes read from the underlying reader each time at least `interval`
+    /// more bytes have been read, and once more with the final total when EOF
+    /// is reached. Decoding a large file can then drive a progress indicator
+    /// without a counting wrapper around the reader. The count starts over
+    /// when the DecodeReader is reset.
+    ///
+    /// ```
+    /// use radix64::{STD, io::DecodeReader};
+    /// use std::io::Read;
+    /// use std::sync::mpsc;
+    ///
+    /// let encoded = STD.encode(&[0; 3000]);
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut reader = DecodeReader::new(STD, encoded.as_bytes())
+    ///     .with_progress(1024, move |n| tx.send(n).unwrap());
+    /// reader.read_to_end(&mut Vec::new()).unwrap();
+    /// drop(reader);
+    /// assert_eq!(rx.iter().last(), Some(4000));
+    /// ```
+    pub fn with_progress<F>(mut self, interval: u64, callback: F) -> Self
+    where
+        F: FnMut(u64) + Send + Sync + 'static,
+    {
+        self.progress = Some(ProgressHook::new(interval, callback));
+        self
+    }
+
+    /// Discard any buffered state so that subsequent reads decode a new
+    /// document from the underlying reader. Buffered input that has not yet
+    /// been decoded is discarded. A limit set by `with_max_decoded_bytes` is
+    /// retained and applies to the new document.
+    pub fn reset(&mut self) {
+        self.pos = 0;
+        self.cap = 0;
+        self.unfiltered = 0;
+        self.eof_seen = false;
+        self.consumed = 0;
+        self.decoded_bytes = 0;
+        self.bytes_contained_in_partial_chunk = 0;
+        self.leftover = 0..0;
+        if let Some(progress) = &mut self.progress {
+            progress.reset();
+        }
+    }
+
+    /// Reset the DecodeReader to decode a new document from the provided
+    /// reader, returning the previous reader.
+    ///
+    /// ```
+    /// use radix64::{STD, io::DecodeReader};
+    /// use std::io::Read;
+    ///
+    /// let mut reader = DecodeReader::new(STD, "Zmlyc3Q=".as_bytes());
+    /// let mut decoded = String::new();
+    /// reader.read_to_string(&mut decoded).unwrap();
+    /// assert_eq!(decoded, "first");
+    ///
+    /// reader.reset_with("c2Vjb25k".as_bytes());
+    /// decoded.clear();
+    /// reader.read_to_string(&mut decoded).unwrap();
+    /// assert_eq!(decoded, "second");
+    /// ```
+    pub fn reset_with(&mut self, rdr: R) -> R {
+        self.reset();
+        core::mem::replace(&mut self.rdr, rdr)
+    }
+
+    pub(crate) fn get_ref(&self) -> &R {
+        &self.rdr
+    }
+
+    pub(crate) fn into_inner(self) -> R {
+        self.rdr
+    }
+
     fn write_partial_chunk(&mut self, output: &mut [u8]) -> usize {
-        let bytes_to_copy = std::cmp::min(self.bytes_contained_in_partial_chunk, output.len());
+        let bytes_to_copy = core::cmp::min(self.bytes_contained_in_partial_chunk, output.len());
         output[..bytes_to_copy].copy_from_slice(&self.decoded_partial_chunk[..bytes_to_copy]);
         self.bytes_contained_in_partial_chunk -= bytes_to_copy;
         // if bytes remain in the partial chunk move them to the beginning of the array.
@@ -54,29 +242,180 @@ where
     }
 
     fn fill(&mut self) -> std::io::Result<()> {
-        crate::copy_in_place(&mut self.data, self.pos..self.cap, 0);
+        self.data.copy_within(self.pos..self.cap, 0);
+        self.consumed += self.pos;
         self.cap -= self.pos;
+        self.unfiltered -= self.pos;
         self.pos = 0;
         let n = self.rdr.read(&mut self.data[self.cap..])?;
         if n == 0 {
             self.eof_seen = true;
         }
         self.cap += n;
+        let start = self.cap - n;
+        while self.eager_fill && !self.eof_seen && self.cap < self.data.len() {
+            match self.rdr.read(&mut self.data[self.cap..]) {
+                // EOF is recorded by the next fill, once the buffered data
+                // has been decoded.
+                Ok(0) => break,
+                Ok(n) => self.cap += n,
+                Err(ref err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                // Decode what has been read. The error is left to be
+                // reported by the next read.
+                Err(_) => break,
+            }
+        }
+        if let Some(progress) = &mut self.progress {
+            progress.advance(self.cap - start);
+            if self.eof_seen {
+                progress.finish();
+            }
+        }
+        match self.config.line_wrap() {
+            Some(wrap) => self.remove_separators(wrap.separator()),
+            None => self.unfiltered = self.cap,
+        }
 
         Ok(())
     }
 
+    // Remove any separators from data[unfiltered..cap], compacting the
+    // remaining data. A trailing partial separator is left in place until
+    // more data is read or EOF is reached.
+    fn remove_separators(&mut self, separator: &[u8]) {
+        let mut read_idx = self.unfiltered;
+        let mut write_idx = self.unfiltered;
+        while read_idx < self.cap {
+            let remaining = &self.data[read_idx..self.cap];
+            if remaining.starts_with(separator) {
+                read_idx += separator.len();
+            } else if (!self.eof_seen || self.truncated_eof)
+                && remaining.len() < separator.len()
+                && separator.starts_with(remaining)
+            {
+                break;
+            } else {
+                self.data[write_idx] = self.data[read_idx];
+                write_idx += 1;
+                read_idx += 1;
+            }
+        }
+        self.data.copy_within(read_idx..self.cap, write_idx);
+        self.cap -= read_idx - write_idx;
+        self.unfiltered = write_idx;
+    }
+
+    // Set aside any data following the last complete chunk once EOF has been
+    // reached, along with a trailing partial separator. Chunks are decoded
+    // from the beginning of data, so pos is always at a chunk boundary.
+    fn set_aside_leftover(&mut self) {
+        let end = self.unfiltered - (self.unfiltered - self.pos) % 4;
+        if end < self.cap {
+            self.leftover = end..self.cap;
+        }
+        self.cap = end;
+        self.unfiltered = end;
+    }
+
     fn end_of_decodable_data(&self) -> usize {
         if self.eof_seen {
             self.cap
         } else {
-            self.cap.saturating_sub(2)
+            self.unfiltered.saturating_sub(2)
         }
     }
 }
 
-fn into_io_err(err: DecodeError) -> std::io::Error {
-    std::io::Error::new(std::io::ErrorKind::Other, err)
+/// Decode everything read from `reader` and write it to `writer`, returning
+/// the number of decoded bytes written. Decoding errors are returned as an
+/// io::Error wrapping the `DecodeError`. Some of the decoded output may have
+/// been written before an error is detected.
+///
+/// This behaves like using `std::io::copy` to copy from a `DecodeReader` to
+/// `writer`, but decodes large blocks at a time without the additional
+/// buffering of the adapter. It's the fastest way to decode an entire stream.
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::copy_decode};
+///
+/// let mut decoded = Vec::new();
+/// copy_decode(STD, &mut &b"bXkgbWVzc2FnZQ=="[..], &mut decoded)?;
+/// assert_eq!(decoded, b"my message");
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub fn copy_decode<C, R, W>(config: C, reader: &mut R, writer: &mut W) -> std::io::Result<u64>
+where
+    C: Config,
+    R: Read + ?Sized,
+    W: std::io::Write + ?Sized,
+{
+    // The number of input bytes read at a time. Must be a multiple of 4 and
+    // fit in a 16-bit usize.
+    const BLOCK_SIZE: usize = 48 * 1024;
+    if config.line_wrap().is_some() {
+        // Separators may straddle blocks. Let DecodeReader deal with them.
+        return std::io::copy(&mut DecodeReader::new(config, reader), writer);
+    }
+    let mut input = vec![0; BLOCK_SIZE];
+    let mut output = vec![0; BLOCK_SIZE / 4 * 3];
+    // number of bytes at the front of input left over from the previous block.
+    let mut carried = 0;
+    // number of bytes of input decoded and discarded from the front of input.
+    let mut consumed = 0;
+    let mut written = 0;
+    loop {
+        let n = carried + crate::io::read_full(reader, &mut input[carried..])?;
+        if n < input.len() {
+            // This is the end of the input, which may contain padding.
+            let decoded_len = crate::decode::decode_slice(config, &input[..n], &mut output)
+                .map_err(|err| into_io_err(err.with_input_length(consumed + n)))?;
+            writer.write_all(&output[..decoded_len])?;
+            return Ok(written + decoded_len as u64);
+        }
+        // Hold back the final chunk, which may turn out to be the padded end
+        // of the input.
+        let (input_idx, output_idx) =
+            crate::decode::decode_full_chunks_without_padding(config, &input[..n - 4], &mut output)
+                .map_err(into_io_err)?;
+        debug_assert_eq!(input_idx, n - 4);
+        writer.write_all(&output[..output_idx])?;
+        written += output_idx as u64;
+        input.copy_within(input_idx..n, 0);
+        carried = n - input_idx;
+        consumed += input_idx;
+    }
+}
+
+/// Decode like [copy_decode](fn.copy_decode.html), calling `callback` with
+/// the total number of encoded bytes read each time at least `interval` more
+/// bytes have been read, and once more with the final total on success.
+pub fn copy_decode_with_progress<C, R, W, F>(
+    config: C,
+    reader: &mut R,
+    writer: &mut W,
+    interval: u64,
+    callback: F,
+) -> std::io::Result<u64>
+where
+    C: Config,
+    R: Read + ?Sized,
+    W: std::io::Write + ?Sized,
+    F: FnMut(u64) + Send + Sync + 'static,
+{
+    let mut reader = ProgressReader {
+        inner: reader,
+        hook: ProgressHook::new(interval, callback),
+    };
+    let written = copy_decode(config, &mut reader, writer)?;
+    reader.hook.finish();
+    Ok(written)
+}
+
+pub(crate) fn into_io_err(err: DecodeError) -> std::io::Error {
+    std::io::Error::other(err)
 }
 
 impl<C, R> Read for DecodeReader<C, R>
@@ -84,7 +423,39 @@ where
     C: Config,
     R: Read,
 {
-    fn read(&mut self, mut output: &mut [u8]) -> std::io::Result<usize> {
+    fn read(&mut self, output: &mut [u8]) -> std::io::Result<usize> {
+        let limit = match self.max_decoded_bytes {
+            Some(limit) => limit,
+            None => return self.read_unlimited(output),
+        };
+        if self.decoded_bytes > limit {
+            return Err(std::io::Error::other(LimitExceeded::new(limit)));
+        }
+        // Allow decoding one byte beyond the limit to detect that the limit
+        // has been exceeded.
+        let allowed = (limit - self.decoded_bytes).saturating_add(1);
+        let output_len = core::cmp::min(output.len() as u64, allowed) as usize;
+        let n = self.read_unlimited(&mut output[..output_len])?;
+        let remaining = limit - self.decoded_bytes;
+        if n as u64 > remaining {
+            // Return the data up to the limit. Subsequent reads will fail.
+            self.decoded_bytes = limit + 1;
+            if remaining == 0 {
+                return Err(std::io::Error::other(LimitExceeded::new(limit)));
+            }
+            return Ok(remaining as usize);
+        }
+        self.decoded_bytes += n as u64;
+        Ok(n)
+    }
+}
+
+impl<C, R> DecodeReader<C, R>
+where
+    C: Config,
+    R: Read,
+{
+    fn read_unlimited(&mut self, mut output: &mut [u8]) -> std::io::Result<usize> {
         // If we've previously partially returned a decoded chunk, return the
         // remaining bytes of the partial result before anything else.
         let mut bytes_written = 0;
@@ -100,6 +471,9 @@ where
         while self.end_of_decodable_data() - self.pos < 4 && !self.eof_seen {
             self.fill()?;
         }
+        if self.eof_seen && self.truncated_eof {
+            self.set_aside_leftover();
+        }
 
         let mut decodable_data = &self.data[self.pos..self.end_of_decodable_data()];
 
@@ -108,6 +482,11 @@ where
             return Ok(bytes_written);
         }
 
+        // Length errors are only detected at EOF, when the remaining data is
+        // the end of the input.
+        let input_length = self.consumed + self.cap;
+        let into_io_err = |err: DecodeError| into_io_err(err.with_input_length(input_length));
+
         if self.eof_seen {
             let start_len = decodable_data.len();
             decodable_data =
@@ -134,7 +513,11 @@ where
                 // may or may not fit into the output buffer.
                 if decodable_data.len() < 4
                     && output.len()
-                        >= output_bytes_needed_to_decode_partial_chunk(decodable_data.len())?
+                        >= output_bytes_needed_to_decode_partial_chunk(
+                            self.config,
+                            decodable_data.len(),
+                        )
+                        .map_err(into_io_err)?
                 {
                     // This is a partial chunk that fits within the output buffer. Decode it.
                     let output_idx =
@@ -207,12 +590,16 @@ where
     }
 }
 
-fn output_bytes_needed_to_decode_partial_chunk(
+fn output_bytes_needed_to_decode_partial_chunk<C>(
+    config: C,
     partial_chunk_len: usize,
-) -> Result<usize, std::io::Error> {
+) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
     Ok(match partial_chunk_len {
         0 => 0,
-        1 => return Err(into_io_err(DecodeError::InvalidLength)),
+        1 => return Err(crate::decode::invalid_length(config, partial_chunk_len)),
         2 => 1,
         3 => 2,
         _ => unreachable!("not a valid partial chunk length: {}", partial_chunk_len),
@@ -1,6 +1,11 @@
 use crate::encode::{encode_full_chunks_without_padding, encode_partial_chunk};
+use crate::wrap::LineState;
 use crate::Config;
-use std::fmt;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
 
 /// Display is a convenience wrapper that provides a Display impl for the passed
 /// in data.
@@ -29,24 +34,134 @@ where
     C: Config,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut buffer = [0; 1024];
-        let mut input = self.data;
-        while !input.is_empty() {
-            let (input_idx, mut output_idx) =
-                encode_full_chunks_without_padding(self.config, input, &mut buffer);
-            input = &input[input_idx..];
-            let output_remaining = buffer.len() - output_idx;
-            if output_remaining > 3 {
-                debug_assert!(input.len() < 3);
-                // We must have either consumed the entire input, or there is a partial chunk remaining with enough room in the buffer to encode it.
-                output_idx += encode_partial_chunk(self.config, &input, &mut buffer[output_idx..]);
-                input = &input[0..0];
+        write_encoded(self.config, self.data, &mut LineState::default(), f)
+    }
+}
+
+/// DisplayFromReader provides a Display impl that base64 encodes the data read
+/// from a std::io::Read. Input is read and encoded in small chunks as it's
+/// written to the formatter, so large files can be formatted without loading
+/// them into memory.
+///
+/// The reader is consumed by formatting, so formatting a second time will
+/// only output whatever remains in the reader. An error reading from the
+/// reader is reported as a `fmt::Error`, and the underlying io::Error can be
+/// retrieved with `take_error`.
+///
+/// ```
+/// use radix64::{DisplayFromReader, STD};
+///
+/// let display = DisplayFromReader::new(STD, &b"my message"[..]);
+/// assert_eq!(format!("{}", display), "bXkgbWVzc2FnZQ==");
+/// assert!(display.take_error().is_none());
+/// ```
+#[cfg(feature = "std")]
+pub struct DisplayFromReader<C, R> {
+    config: C,
+    reader: RefCell<R>,
+    error: RefCell<Option<io::Error>>,
+}
+
+#[cfg(feature = "std")]
+impl<C, R> DisplayFromReader<C, R>
+where
+    C: Config,
+    R: io::Read,
+{
+    /// Wrap the reader, providing a Display implementation that will base64
+    /// encode the data read according to the configuration specified.
+    pub fn new(config: C, reader: R) -> Self {
+        DisplayFromReader {
+            config,
+            reader: RefCell::new(reader),
+            error: RefCell::new(None),
+        }
+    }
+
+    /// Take the io::Error that caused formatting to fail, if any.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.error.borrow_mut().take()
+    }
+
+    /// Consume the DisplayFromReader, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, R> fmt::Display for DisplayFromReader<C, R>
+where
+    C: Config,
+    R: io::Read,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Must be a multiple of 3 so only the final read contains a partial
+        // chunk.
+        let mut input = [0; 768];
+        let mut reader = self.reader.borrow_mut();
+        let mut line_state = LineState::default();
+        loop {
+            let filled = match crate::io::read_full(&mut *reader, &mut input) {
+                Ok(filled) => filled,
+                Err(err) => {
+                    *self.error.borrow_mut() = Some(err);
+                    return Err(fmt::Error);
+                }
+            };
+            write_encoded(self.config, &input[..filled], &mut line_state, f)?;
+            if filled < input.len() {
+                return Ok(());
             }
-            // Encoded output is always ascii and therefore valid utf8.
-            debug_assert!(&buffer[..output_idx].iter().all(u8::is_ascii));
-            let output_str = unsafe { std::str::from_utf8_unchecked(&buffer[..output_idx]) };
-            f.write_str(output_str)?;
         }
-        Ok(())
     }
 }
+
+#[cfg(feature = "std")]
+impl<C, R> fmt::Debug for DisplayFromReader<C, R>
+where
+    C: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DisplayFromReader")
+            .field("config", &self.config)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+// Encode the input and write it to the formatter. Only the final call for a
+// given line_state may contain a partial chunk.
+pub(crate) fn write_encoded<C, W>(
+    config: C,
+    mut input: &[u8],
+    line_state: &mut LineState,
+    f: &mut W,
+) -> fmt::Result
+where
+    C: Config,
+    W: fmt::Write + ?Sized,
+{
+    let mut buffer = [0; 1024];
+    let line_wrap = config.line_wrap();
+    while !input.is_empty() {
+        let (input_idx, mut output_idx) =
+            encode_full_chunks_without_padding(config, input, &mut buffer);
+        input = &input[input_idx..];
+        let output_remaining = buffer.len() - output_idx;
+        if output_remaining > 3 {
+            debug_assert!(input.len() < 3);
+            // We must have either consumed the entire input, or there is a partial chunk remaining with enough room in the buffer to encode it.
+            output_idx += encode_partial_chunk(config, input, &mut buffer[output_idx..]);
+            input = &input[0..0];
+        }
+        // Encoded output is always ascii and therefore valid utf8.
+        debug_assert!(&buffer[..output_idx].iter().all(u8::is_ascii));
+        let output_str = unsafe { core::str::from_utf8_unchecked(&buffer[..output_idx]) };
+        match line_wrap {
+            Some(ref wrap) => line_state.write_fmt(wrap, output_str, f)?,
+            None => f.write_str(output_str)?,
+        }
+    }
+    Ok(())
+}
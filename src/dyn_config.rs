@@ -0,0 +1,94 @@
+use crate::{Config, CustomConfig, DecodeError};
+use alloc::vec::Vec;
+
+mod private {
+    pub trait Sealed {}
+
+    impl<C: crate::Config> Sealed for C {}
+    impl Sealed for crate::CustomConfig {}
+}
+
+/// An object safe counterpart of [Config](trait.Config.html), allowing configs
+/// of different types to be stored together as `&dyn DynConfig`. It is
+/// implemented for every Config, as well as `CustomConfig` itself, and cannot
+/// be implemented outside of this crate.
+pub trait DynConfig: private::Sealed {
+    /// Decode the input into the buffer as described by
+    /// [Config::decode_with_buffer](trait.Config.html#method.decode_with_buffer),
+    /// returning the number of decoded bytes at the beginning of the buffer.
+    fn decode_with_buffer_dyn(
+        &self,
+        input: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<usize, DecodeError>;
+}
+
+impl<C> DynConfig for C
+where
+    C: Config,
+{
+    #[inline]
+    fn decode_with_buffer_dyn(
+        &self,
+        input: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<usize, DecodeError> {
+        self.decode_with_buffer(input, buffer).map(<[u8]>::len)
+    }
+}
+
+impl DynConfig for CustomConfig {
+    #[inline]
+    fn decode_with_buffer_dyn(
+        &self,
+        input: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<usize, DecodeError> {
+        self.decode_with_buffer(input, buffer).map(<[u8]>::len)
+    }
+}
+
+/// Decode the input with each of the configs in order, returning the decoded
+/// output of the first config that accepts the input along with that config's
+/// index. This supports accepting "some base64 variant" from clients, with a
+/// single output buffer reused across the attempts.
+///
+/// Order the configs from most to least preferred. Input can be valid for more
+/// than one config, and configs with different alphabets, such as
+/// [CRYPT](constant.CRYPT.html), decode the same input to different bytes.
+///
+/// If no config accepts the input, the error from the first config is
+/// returned. Panics if `configs` is empty.
+///
+/// ```
+/// use radix64::{decode_any, DynConfig, STD, URL_SAFE_NO_PAD};
+///
+/// let configs: [&dyn DynConfig; 2] = [&STD, &URL_SAFE_NO_PAD];
+/// assert_eq!(decode_any(&configs, "-_8").unwrap(), (vec![0xfb, 0xff], 1));
+/// assert_eq!(decode_any(&configs, "+/8=").unwrap(), (vec![0xfb, 0xff], 0));
+/// assert!(decode_any(&configs, "+/8").is_err());
+/// ```
+pub fn decode_any<I>(configs: &[&dyn DynConfig], input: &I) -> Result<(Vec<u8>, usize), DecodeError>
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    assert!(
+        !configs.is_empty(),
+        "decode_any requires at least one config"
+    );
+    let input = input.as_ref();
+    let mut buffer = Vec::new();
+    let mut first_err = None;
+    for (idx, config) in configs.iter().enumerate() {
+        match config.decode_with_buffer_dyn(input, &mut buffer) {
+            Ok(len) => {
+                buffer.truncate(len);
+                return Ok((buffer, idx));
+            }
+            Err(err) => {
+                first_err.get_or_insert(err);
+            }
+        }
+    }
+    Err(first_err.expect("at least one config was attempted"))
+}
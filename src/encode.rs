@@ -1,25 +1,424 @@
 use crate::u6::U6;
+use crate::uninit::{as_uninit_mut, write_slice};
 use crate::Config;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{rc::Rc, string::String, vec, vec::Vec};
+use core::mem::MaybeUninit;
+use core::{error, fmt};
 
 pub(crate) mod block;
+#[cfg(feature = "std")]
 pub(crate) mod io;
+pub(crate) mod iter;
+#[cfg(feature = "std")]
+pub(crate) mod pipelined;
 
-pub(crate) fn encode_slice<C>(config: C, mut input: &[u8], mut output: &mut [u8]) -> usize
+pub(crate) fn encode_array<C, const N: usize, const M: usize>(config: C, input: &[u8; N]) -> [u8; M]
 where
     C: Config,
 {
-    let (input_idx, output_idx) = encode_full_chunks_without_padding(config, input, output);
+    assert_eq!(
+        config.encoded_output_len(N),
+        M,
+        "output array length does not match the encoded length"
+    );
+    let mut output = [0; M];
+    encode_slice(config, input, &mut output);
+    output
+}
+
+pub(crate) fn encode_slice<C>(config: C, input: &[u8], output: &mut [u8]) -> usize
+where
+    C: Config,
+{
+    // The encoders only write initialized bytes.
+    encode_uninit(config, input, unsafe { as_uninit_mut(output) })
+}
+
+// Encode into memory that may be uninitialized, returning the number of bytes
+// written. Those bytes are initialized when this returns.
+pub(crate) fn encode_uninit<C>(config: C, mut input: &[u8], output: &mut [MaybeUninit<u8>]) -> usize
+where
+    C: Config,
+{
+    let required = config.encoded_output_len(input.len());
+    if output.len() < required {
+        output_too_small(input.len(), required, output.len());
+    }
+    let (input_idx, output_idx) = encode_full_chunks_uninit(config, input, output);
     input = &input[input_idx..];
-    output = &mut output[output_idx..];
+    let encoded_len =
+        output_idx + encode_partial_chunk_uninit(config, input, &mut output[output_idx..]);
+
+    match config.line_wrap() {
+        Some(wrap) => wrap.insert_separators_uninit(output, encoded_len),
+        None => encoded_len,
+    }
+}
+
+// Encode into the output slice, returning an error rather than panicking when
+// it's too small.
+pub(crate) fn try_encode_slice<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, BufferTooSmall>
+where
+    C: Config,
+{
+    let required = config.encoded_output_len(input.len());
+    if output.len() < required {
+        return Err(BufferTooSmall::new(required, output.len()));
+    }
+    Ok(encode_slice(config, input, output))
+}
 
-    output_idx + encode_partial_chunk(config, input, output)
+/// The error returned when an output slice is too small to hold the output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    required: usize,
+    provided: usize,
+}
+
+impl BufferTooSmall {
+    pub(crate) fn new(required: usize, provided: usize) -> Self {
+        BufferTooSmall { required, provided }
+    }
+
+    /// The number of bytes the output slice needed to hold.
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// The number of bytes the output slice held.
+    pub fn provided(&self) -> usize {
+        self.provided
+    }
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "output slice too small: {} bytes required, {} provided",
+            self.required, self.provided
+        )
+    }
+}
+
+impl error::Error for BufferTooSmall {}
+
+#[cold]
+fn output_too_small(input_len: usize, required: usize, provided: usize) -> ! {
+    panic!(
+        "output slice too small to encode {} bytes: {} bytes required, {} provided",
+        input_len, required, provided
+    )
+}
+
+// Encodes input provided as a series of segments that when concatenated form
+// the data to encode. The bytes of a chunk that straddles two segments are
+// carried over to the next segment.
+#[cfg(feature = "alloc")]
+pub(crate) struct SegmentEncoder<C> {
+    config: C,
+    output: Vec<u8>,
+    output_len: usize,
+    carry: [u8; 3],
+    carry_len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<C> SegmentEncoder<C>
+where
+    C: Config,
+{
+    // input_len is the combined length of all the segments.
+    pub(crate) fn new(config: C, input_len: usize) -> Self {
+        SegmentEncoder {
+            config,
+            output: vec![0; max_encoded_len(config, input_len)],
+            output_len: 0,
+            carry: [0; 3],
+            carry_len: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, mut segment: &[u8]) {
+        use arrayref::array_mut_ref;
+        if self.carry_len > 0 {
+            let bytes_to_copy = core::cmp::min(3 - self.carry_len, segment.len());
+            self.carry[self.carry_len..self.carry_len + bytes_to_copy]
+                .copy_from_slice(&segment[..bytes_to_copy]);
+            self.carry_len += bytes_to_copy;
+            segment = &segment[bytes_to_copy..];
+            if self.carry_len < 3 {
+                return;
+            }
+            encode_chunk(
+                self.config,
+                self.carry,
+                array_mut_ref!(self.output, self.output_len, 4),
+            );
+            self.output_len += 4;
+            self.carry_len = 0;
+        }
+        let (input_idx, output_idx) = encode_full_chunks_without_padding(
+            self.config,
+            segment,
+            &mut self.output[self.output_len..],
+        );
+        self.output_len += output_idx;
+        let remaining = &segment[input_idx..];
+        self.carry[..remaining.len()].copy_from_slice(remaining);
+        self.carry_len = remaining.len();
+    }
+
+    pub(crate) fn finish(mut self) -> String {
+        let mut encoded_len = self.output_len
+            + encode_partial_chunk(
+                self.config,
+                &self.carry[..self.carry_len],
+                &mut self.output[self.output_len..],
+            );
+        if let Some(wrap) = self.config.line_wrap() {
+            encoded_len = wrap.insert_separators(&mut self.output, encoded_len);
+        }
+        self.output.truncate(encoded_len);
+        // The builtin alphabets are all ascii and the CustomConfigBuilder
+        // ensures any custom alphabets only contain ascii characters as well.
+        // Therefore we can bypass the utf8 check on the encoded output.
+        debug_assert!(self.output.iter().all(u8::is_ascii));
+        unsafe { String::from_utf8_unchecked(self.output) }
+    }
+}
+
+// Encode the input appending the output to the provided collection. The output
+// is produced using the same fixed size stack buffer as Display.
+pub(crate) fn encode_into<C, T>(config: C, input: &[u8], output: &mut T)
+where
+    C: Config,
+    T: Extend<u8>,
+{
+    use core::fmt::{self, Write};
+    struct ExtendWriter<'a, T>(&'a mut T);
+
+    impl<'a, T> fmt::Write for ExtendWriter<'a, T>
+    where
+        T: Extend<u8>,
+    {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.extend(s.as_bytes().iter().cloned());
+            Ok(())
+        }
+    }
+
+    write!(
+        ExtendWriter(output),
+        "{}",
+        crate::Display::new(config, &input)
+    )
+    .expect("writing to an Extend<u8> cannot fail");
+}
+
+// Encode the input into a shared allocation of exactly the encoded length.
+// Collecting a TrustedLen iterator allocates the Arc or Rc once, which is then
+// written in place while it is still uniquely owned.
+#[cfg(feature = "alloc")]
+macro_rules! encode_shared {
+    ($name:ident, $ptr:ident) => {
+        pub(crate) fn $name<C>(config: C, input: &[u8]) -> $ptr<str>
+        where
+            C: Config,
+        {
+            let len = config.encoded_output_len(input.len());
+            let mut bytes: $ptr<[u8]> = core::iter::repeat(0).take(len).collect();
+            let output = $ptr::get_mut(&mut bytes).expect("newly allocated");
+            let encoded_len = encode_slice(config, input, output);
+            debug_assert_eq!(encoded_len, len);
+            // The encoded output is always ascii, as described in
+            // Config::encode.
+            debug_assert!(bytes.iter().all(u8::is_ascii));
+            unsafe { $ptr::from_raw($ptr::into_raw(bytes) as *const str) }
+        }
+    };
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+encode_shared!(encode_arc, Arc);
+#[cfg(feature = "alloc")]
+encode_shared!(encode_rc, Rc);
+
+pub(crate) const LENGTH_OVERFLOW: &str = "encoded output length overflows usize";
+
+// The number of bytes produced by encoding the final input_len % 3 bytes of
+// input.
+#[inline]
+const fn partial_chunk_len(remainder: usize, padded: bool) -> usize {
+    match (remainder, padded) {
+        (0, _) => 0,
+        (_, true) => 4,
+        (rem, false) => rem + 1,
+    }
+}
+
+/// The number of bytes produced by encoding input_len bytes of input, excluding
+/// any line separators, or None if the length does not fit in a usize.
+#[inline]
+pub(crate) const fn checked_encoded_len(input_len: usize, padded: bool) -> Option<usize> {
+    match (input_len / 3).checked_mul(4) {
+        Some(full_chunks_len) => {
+            full_chunks_len.checked_add(partial_chunk_len(input_len % 3, padded))
+        }
+        None => None,
+    }
+}
+
+/// The number of bytes produced by encoding input_len bytes of input, excluding
+/// any line separators. Panics if the length does not fit in a usize, which
+/// can happen for large inputs on targets with a 16 or 32-bit usize.
+#[inline]
+pub(crate) const fn encoded_len(input_len: usize, padded: bool) -> usize {
+    match checked_encoded_len(input_len, padded) {
+        Some(len) => len,
+        None => panic!("{}", LENGTH_OVERFLOW),
+    }
+}
+
+/// Encode the input using the table and padding in a const context. The
+/// output array must be exactly the encoded length, which is checked at
+/// compile time when evaluated as a constant.
+pub(crate) const fn encode_const<const N: usize>(
+    table: &[u8; 64],
+    padding: Option<u8>,
+    input: &[u8],
+) -> [u8; N] {
+    assert!(
+        N == encoded_len(input.len(), padding.is_some()),
+        "output array length does not match the encoded length"
+    );
+    let mut output = [0; N];
+    let mut input_idx = 0;
+    let mut output_idx = 0;
+    while input.len() - input_idx >= 3 {
+        let (a, b, c) = (input[input_idx], input[input_idx + 1], input[input_idx + 2]);
+        output[output_idx] = table[(a >> 2) as usize];
+        output[output_idx + 1] = table[((a << 4 | b >> 4) & 0x3f) as usize];
+        output[output_idx + 2] = table[((b << 2 | c >> 6) & 0x3f) as usize];
+        output[output_idx + 3] = table[(c & 0x3f) as usize];
+        input_idx += 3;
+        output_idx += 4;
+    }
+    let remaining = input.len() - input_idx;
+    if remaining > 0 {
+        let a = input[input_idx];
+        let b = if remaining == 2 {
+            input[input_idx + 1]
+        } else {
+            0
+        };
+        output[output_idx] = table[(a >> 2) as usize];
+        output[output_idx + 1] = table[((a << 4 | b >> 4) & 0x3f) as usize];
+        if remaining == 2 {
+            output[output_idx + 2] = table[((b << 2) & 0x3f) as usize];
+        }
+        if let Some(padding) = padding {
+            if remaining == 1 {
+                output[output_idx + 2] = padding;
+            }
+            output[output_idx + 3] = padding;
+        }
+    }
+    output
+}
+
+/// The number of bytes produced by splitting encoded_len bytes of encoded
+/// output into lines of width bytes, separated by line endings of
+/// line_ending_len bytes, or None if the length does not fit in a usize. No
+/// line ending follows the final line. Panics if width is zero.
+#[inline]
+pub(crate) const fn checked_wrapped_len(
+    encoded_len: usize,
+    width: usize,
+    line_ending_len: usize,
+) -> Option<usize> {
+    assert!(width > 0, "line width must be non-zero");
+    let num_line_endings = encoded_len.saturating_sub(1) / width;
+    match num_line_endings.checked_mul(line_ending_len) {
+        Some(line_endings_len) => encoded_len.checked_add(line_endings_len),
+        None => None,
+    }
+}
+
+/// The same as checked_wrapped_len, but panics if the length does not fit in
+/// a usize.
+#[inline]
+pub(crate) const fn wrapped_len(encoded_len: usize, width: usize, line_ending_len: usize) -> usize {
+    match checked_wrapped_len(encoded_len, width, line_ending_len) {
+        Some(len) => len,
+        None => panic!("{}", LENGTH_OVERFLOW),
+    }
+}
+
+/// The number of bytes produced by encoding input_len bytes, including padding
+/// and line separators, saturating at u64::MAX. Unlike encoded_output_len this
+/// never panics and handles streams longer than a usize.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn encoded_len_u64<C: Config>(config: C, input_len: u64) -> u64 {
+    let remainder = (input_len % 3) as usize;
+    let encoded_len = (input_len / 3)
+        .saturating_mul(4)
+        .saturating_add(partial_chunk_len(remainder, config.padding_byte().is_some()) as u64);
+    match config.line_wrap() {
+        Some(wrap) => encoded_len.saturating_add(
+            encoded_len.saturating_sub(1) / wrap.interval() as u64 * wrap.separator().len() as u64,
+        ),
+        None => encoded_len,
+    }
+}
+
+/// A conservative estimate of the number of bytes needed to encode input_len
+/// bytes of input, including any line separators.
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn max_encoded_len<C>(config: C, input_len: usize) -> usize
+where
+    C: Config,
+{
+    // input_len * 4 / 3 + 3, without overflowing the intermediate product.
+    let encoded_len = (input_len / 3)
+        .checked_mul(4)
+        .and_then(|len| len.checked_add(input_len % 3 * 4 / 3 + 3))
+        .expect(LENGTH_OVERFLOW);
+    match config.line_wrap() {
+        Some(wrap) => encoded_len
+            .checked_add(wrap.separators_len(encoded_len))
+            .expect(LENGTH_OVERFLOW),
+        None => encoded_len,
+    }
 }
 
 #[inline]
 pub(crate) fn encode_full_chunks_without_padding<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [u8],
+) -> (usize, usize)
+where
+    C: Config,
+{
+    // The encoders only write initialized bytes.
+    encode_full_chunks_uninit(config, input, unsafe { as_uninit_mut(output) })
+}
+
+#[inline]
+pub(crate) fn encode_full_chunks_uninit<C>(
     config: C,
     mut input: &[u8],
-    mut output: &mut [u8],
+    mut output: &mut [MaybeUninit<u8>],
 ) -> (usize, usize)
 where
     C: Config,
@@ -38,7 +437,7 @@ where
     // Encode the remaining non-padding 3 byte chunks of input.
     let mut iter = EncodeIter::new(input, output);
     while let Some((input, output)) = iter.next_chunk() {
-        encode_chunk(config, *input, output);
+        write_slice(output, &encoded_chunk(config, *input));
     }
     let (chunk_input_idx, chunk_output_idx) = iter.remaining();
     (
@@ -52,39 +451,62 @@ pub(crate) fn encode_partial_chunk<C>(config: C, input: &[u8], output: &mut [u8]
 where
     C: Config,
 {
-    match input.len() {
+    // The encoders only write initialized bytes.
+    encode_partial_chunk_uninit(config, input, unsafe { as_uninit_mut(output) })
+}
+
+#[inline]
+pub(crate) fn encode_partial_chunk_uninit<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+) -> usize
+where
+    C: Config,
+{
+    let mut chunk = [0; 4];
+    let len = match input.len() {
         0 => 0,
         1 => {
-            output[0] = config.encode_u6(U6::from_low_six_bits(input[0] >> 2));
-            output[1] = config.encode_u6(U6::from_low_six_bits(input[0] << 4));
+            chunk[0] = config.encode_u6(U6::from_low_six_bits(input[0] >> 2));
+            chunk[1] = config.encode_u6(U6::from_low_six_bits(input[0] << 4));
             if let Some(padding) = config.padding_byte() {
-                output[2] = padding;
-                output[3] = padding;
+                chunk[2] = padding;
+                chunk[3] = padding;
                 4
             } else {
                 2
             }
         }
         2 => {
-            output[0] = config.encode_u6(U6::from_low_six_bits(input[0] >> 2));
-            output[1] = config.encode_u6(U6::from_low_six_bits(input[0] << 4 | input[1] >> 4));
-            output[2] = config.encode_u6(U6::from_low_six_bits(input[1] << 2));
+            chunk[0] = config.encode_u6(U6::from_low_six_bits(input[0] >> 2));
+            chunk[1] = config.encode_u6(U6::from_low_six_bits(input[0] << 4 | input[1] >> 4));
+            chunk[2] = config.encode_u6(U6::from_low_six_bits(input[1] << 2));
             if let Some(padding) = config.padding_byte() {
-                output[3] = padding;
+                chunk[3] = padding;
                 4
             } else {
                 3
             }
         }
         _ => panic!("invalid input remaining. Is the output buffer too small?"),
-    }
+    };
+    write_slice(output, &chunk[..len]);
+    len
+}
+
+pub(crate) fn encode_chunk<C: Config>(config: C, input: [u8; 3], output: &mut [u8; 4]) {
+    *output = encoded_chunk(config, input);
 }
 
-fn encode_chunk<C: Config>(config: C, input: [u8; 3], output: &mut [u8; 4]) {
-    output[0] = config.encode_u6(U6::from_low_six_bits(input[0] >> 2));
-    output[1] = config.encode_u6(U6::from_low_six_bits(input[0] << 4 | input[1] >> 4));
-    output[2] = config.encode_u6(U6::from_low_six_bits(input[1] << 2 | input[2] >> 6));
-    output[3] = config.encode_u6(U6::from_low_six_bits(input[2]));
+#[inline]
+fn encoded_chunk<C: Config>(config: C, input: [u8; 3]) -> [u8; 4] {
+    [
+        config.encode_u6(U6::from_low_six_bits(input[0] >> 2)),
+        config.encode_u6(U6::from_low_six_bits(input[0] << 4 | input[1] >> 4)),
+        config.encode_u6(U6::from_low_six_bits(input[1] << 2 | input[2] >> 6)),
+        config.encode_u6(U6::from_low_six_bits(input[2])),
+    ]
 }
 
 #[inline]
@@ -95,6 +517,23 @@ pub(crate) fn encode_using_table(table: &[u8; 64], input: U6) -> u8 {
     *encoded
 }
 
+// Pair tables for the builtin alphabets. 8 KiB each, small enough to stay in
+// the L1 cache while encoding.
+pub(crate) static STD_ENCODE_PAIRS: [u16; 4096] = pair_table(crate::tables::STD_ENCODE);
+pub(crate) static URL_SAFE_ENCODE_PAIRS: [u16; 4096] = pair_table(crate::tables::URL_SAFE_ENCODE);
+pub(crate) static CRYPT_ENCODE_PAIRS: [u16; 4096] = pair_table(crate::tables::CRYPT_ENCODE);
+pub(crate) static FAST_ENCODE_PAIRS: [u16; 4096] = pair_table(crate::tables::FAST_ENCODE);
+
+const fn pair_table(table: &[u8; 64]) -> [u16; 4096] {
+    let mut pairs = [0; 4096];
+    let mut idx = 0;
+    while idx < pairs.len() {
+        pairs[idx] = (table[idx >> 6] as u16) << 8 | table[idx & 0x3f] as u16;
+        idx += 1;
+    }
+    pairs
+}
+
 define_block_iter!(
     name = EncodeIter,
     input_chunk_size = 3,
@@ -106,6 +545,7 @@ define_block_iter!(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::vec;
 
     #[test]
     fn encode_slice_panics_on_short_output_slice() {
@@ -116,4 +556,9 @@ mod tests {
         .is_err();
         assert!(did_panic);
     }
+    #[test]
+    #[should_panic(expected = "to encode 4 bytes: 8 bytes required, 7 provided")]
+    fn encode_slice_panic_states_sizes() {
+        encode_slice(crate::STD, b"aaaa", &mut [0; 7]);
+    }
 }
@@ -1,18 +1,32 @@
 use crate::u6::U6;
+use crate::uninit::write_slice;
 use crate::{Config, CustomConfig};
+use core::mem::MaybeUninit;
 
-mod arch;
+pub(crate) mod arch;
 
+/// Provides the block encoder used when encoding with a config.
 pub trait IntoBlockEncoder: Copy {
+    /// The block encoder for this config.
     type BlockEncoder: BlockEncoder;
 
+    /// Returns the block encoder, selecting the fastest kernel available.
     fn into_block_encoder(self) -> Self::BlockEncoder;
 }
 
+/// Encodes input a block at a time.
 pub trait BlockEncoder: Copy {
-    fn encode_blocks(self, input: &[u8], output: &mut [u8]) -> (usize, usize);
+    /// Encode as many whole blocks of input as possible, returning the number
+    /// of input bytes consumed and output bytes written. Input is always
+    /// consumed a multiple of 3 bytes at a time, and no padding is written.
+    /// The remaining input is left for the caller, which may be all of it.
+    /// Kernels may read past the consumed input, but not past the end of it.
+    /// The output may be uninitialized. Kernels only write initialized bytes
+    /// to it and never read from it.
+    fn encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize);
 }
 
+/// The portable block encoder, used when no SIMD kernel is available.
 #[derive(Debug, Clone, Copy)]
 pub struct ScalarBlockEncoder<C>(C);
 
@@ -20,16 +34,34 @@ impl<C> ScalarBlockEncoder<C>
 where
     C: Config,
 {
+    /// Create a scalar block encoder for the provided config.
     #[inline]
-    pub(crate) fn new(config: C) -> Self {
+    pub fn new(config: C) -> Self {
         ScalarBlockEncoder(config)
     }
 
-    fn encode_chunk(self, input: u64, output: &mut [u8; 8]) {
-        for (idx, out) in output.iter_mut().enumerate() {
-            let shift_amount = 64 - (idx as u64 + 1) * 6;
-            let shifted_input = input >> shift_amount;
-            *out = self.0.encode_u6(U6::from_low_six_bits(shifted_input as u8));
+    #[inline]
+    fn encode_chunk(self, input: u64, output: &mut [MaybeUninit<u8>; 8]) {
+        match self.0.encode_pair_table() {
+            // Encode the 48 most significant bits of input as four pairs of
+            // characters, assembled in a u64 so they are written with a
+            // single store.
+            Some(pairs) => {
+                let encoded = (0..4).fold(0, |encoded, idx| {
+                    let pair = pairs[(input >> (52 - idx * 12)) as usize & 0xfff];
+                    (encoded << 16) | u64::from(pair)
+                });
+                write_slice(output, &encoded.to_be_bytes());
+            }
+            None => {
+                for (idx, out) in output.iter_mut().enumerate() {
+                    let shift_amount = 64 - (idx as u64 + 1) * 6;
+                    let shifted_input = input >> shift_amount;
+                    *out = MaybeUninit::new(
+                        self.0.encode_u6(U6::from_low_six_bits(shifted_input as u8)),
+                    );
+                }
+            }
         }
     }
 }
@@ -39,13 +71,13 @@ where
     C: Config,
 {
     #[inline]
-    fn encode_blocks(self, input: &[u8], output: &mut [u8]) -> (usize, usize) {
+    fn encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
         use arrayref::{array_mut_ref, array_ref};
         let mut iter = BlockIter::new(input, output);
         while let Some((input_block, output_block)) = iter.next_chunk() {
             for i in 0..4 {
                 self.encode_chunk(
-                    from_be_bytes(*array_ref!(input_block, i * 6, 8)),
+                    u64::from_be_bytes(*array_ref!(input_block, i * 6, 8)),
                     array_mut_ref!(output_block, i * 8, 8),
                 );
             }
@@ -54,15 +86,6 @@ where
     }
 }
 
-#[inline]
-fn from_be_bytes(input: [u8; 8]) -> u64 {
-    let mut output: u64 = 0;
-    unsafe {
-        std::ptr::copy_nonoverlapping(input.as_ptr(), &mut output as *mut u64 as *mut u8, 8);
-    }
-    output.to_be()
-}
-
 define_block_iter!(
     name = BlockIter,
     input_chunk_size = 26,
@@ -79,3 +102,22 @@ impl IntoBlockEncoder for &CustomConfig {
         ScalarBlockEncoder::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::STD;
+    use std::vec::Vec;
+
+    #[test]
+    fn scalar_encode_blocks() {
+        let input: Vec<u8> = (0..26).collect();
+        let mut output = [0; 32];
+        assert_eq!(
+            ScalarBlockEncoder::new(STD)
+                .encode_blocks(&input, unsafe { crate::uninit::as_uninit_mut(&mut output) }),
+            (24, 32)
+        );
+        assert_eq!(&output, b"AAECAwQFBgcICQoLDA0ODxAREhMUFRYX");
+    }
+}
@@ -1,22 +1,13 @@
 /// SSE implementation of base64 encoding.
-use crate::Config;
 use crate::encode::block::{BlockEncoder, IntoBlockEncoder, ScalarBlockEncoder};
 use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+use core::mem::MaybeUninit;
 
 #[derive(Debug,Clone,Copy)]
 pub struct Encoder<C>(C);
 
-impl<C> BlockEncoder for Encoder<C> where C: Config + avx2::Translate256i {
-    #[inline]
-    fn encode_blocks(self, input: &[u8], output: &mut [u8]) -> (usize, usize) {
-        if let Ok(encoder) = avx2::Encoder::new(self.0) {
-            encoder.encode_blocks(input, output)
-        } else {
-            ScalarBlockEncoder::new(self.0).encode_blocks(input, output)
-        }
-    }
-}
-
+// Each builtin config tries the widest kernel that is compiled in and selected
+// by the active backend, falling back to the scalar implementation.
 macro_rules! define_into_block_encoder {
     ($( $cfg:ident ),+) => {$(
         impl IntoBlockEncoder for $cfg {
@@ -27,35 +18,64 @@ macro_rules! define_into_block_encoder {
                 Encoder(self)
             }
         }
+
+        impl BlockEncoder for Encoder<$cfg> {
+            #[inline]
+            fn encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
+                #[cfg(feature = "avx512vbmi")]
+                {
+                    if let Ok(encoder) = avx512vbmi::Encoder::new(self.0) {
+                        return encoder.encode_blocks(input, output);
+                    }
+                }
+                #[cfg(feature = "avx2")]
+                {
+                    if let Ok(encoder) = avx2::Encoder::new(self.0) {
+                        return encoder.encode_blocks(input, output);
+                    }
+                }
+                #[cfg(feature = "ssse3")]
+                {
+                    if let Ok(encoder) = ssse3::Encoder::new(self.0) {
+                        return encoder.encode_blocks(input, output);
+                    }
+                }
+                ScalarBlockEncoder::new(self.0).encode_blocks(input, output)
+            }
+        }
     )+}
 }
 define_into_block_encoder!(Std,StdNoPad,UrlSafe,UrlSafeNoPad,Crypt,Fast);
 
-mod avx2 {
+#[cfg(feature = "avx2")]
+pub(crate) mod avx2 {
      #[cfg(target_arch = "x86")]
-    use std::arch::x86::*;
+    use core::arch::x86::*;
     #[cfg(target_arch = "x86_64")]
-    use std::arch::x86_64::*;
+    use core::arch::x86_64::*;
     use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+    use core::mem::MaybeUninit;
 
     pub trait Translate256i: Copy {
         unsafe fn translate_m256i(input: __m256i) -> __m256i;
     }
 
+    // The AVX-512 VBMI backend constructs this directly, since creating it
+    // already ensured the CPU supports AVX2.
     #[derive(Debug,Clone,Copy)]
-    pub(crate) struct Encoder<C>(C);
+    pub(crate) struct Encoder<C>(pub(super) C);
 
     impl<C> Encoder<C> where C: Translate256i {
         #[inline]
         pub(crate) fn new(config: C) -> Result<Self, ()> {
-            if is_x86_feature_detected!("avx2") {
+            if crate::backend::use_avx2() {
                 Ok(Encoder(config))
             } else {
                 Err(())
             }
         }
 
-        pub(crate) fn encode_blocks(self, input: &[u8], output: &mut [u8]) -> (usize, usize) {
+        pub(crate) fn encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
             // The unsafe block is required because _encode_blocks relies on AVX2
             // intrinsics. This is safe because Encoder::new() ensures that an
             // encoder is only successfully created when the CPU supports AVX2.
@@ -63,7 +83,7 @@ mod avx2 {
         }
 
         #[target_feature(enable = "avx2")]
-        unsafe fn _encode_blocks(self, input: &[u8], output: &mut [u8]) -> (usize, usize) {
+        unsafe fn _encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
             let mut iter = BlockIter::new(input, output);
             while let Some((input, output)) = iter.next_chunk() {
                 #[allow(clippy::cast_ptr_alignment)]
@@ -120,6 +140,14 @@ mod avx2 {
 
     }
 
+    /// Encode a single block without checking for AVX2 support. Used by
+    /// crate::arch.
+    #[target_feature(enable = "avx2")]
+    #[inline]
+    pub(crate) unsafe fn encode_block<C: Translate256i>(config: C, input: __m256i) -> __m256i {
+        Encoder(config).encode_block(input)
+    }
+
     define_block_iter!(name=BlockIter, input_chunk_size=28, input_stride=24, output_chunk_size=32, output_stride=32);
 
     #[target_feature(enable = "avx2")]
@@ -244,4 +272,242 @@ mod avx2 {
             translate_fast(input)
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "avx512vbmi")]
+pub(crate) mod avx512vbmi {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+    use core::mem::MaybeUninit;
+
+    /// The encode table of a config, which vpermb indexes directly.
+    pub trait Tables: Copy {
+        const ENCODE: &'static [u8; 64];
+    }
+
+    #[derive(Debug,Clone,Copy)]
+    pub(crate) struct Encoder<C>(C);
+
+    impl<C> Encoder<C> where C: Tables + super::avx2::Translate256i {
+        #[inline]
+        pub(crate) fn new(config: C) -> Result<Self, ()> {
+            if crate::backend::use_avx512vbmi() {
+                Ok(Encoder(config))
+            } else {
+                Err(())
+            }
+        }
+
+        pub(crate) fn encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
+            // The unsafe block is required because _encode_blocks relies on
+            // AVX-512 intrinsics. This is safe because Encoder::new() ensures
+            // that an encoder is only successfully created when the CPU
+            // supports AVX-512 VBMI.
+            let (input_idx, output_idx) = unsafe { self._encode_blocks(input, output) };
+            // Finish any input too short for a 512-bit block with AVX2, which
+            // every CPU supporting AVX-512 VBMI also supports. The AVX2 encoder is
+            // constructed directly rather than with new(), which checks the
+            // active backend again and fails if set_backend has changed it
+            // since.
+            let avx2 = super::avx2::Encoder(self.0);
+            let (input_rem, output_rem) = avx2.encode_blocks(&input[input_idx..], &mut output[output_idx..]);
+            (input_idx + input_rem, output_idx + output_rem)
+        }
+
+        #[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+        unsafe fn _encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
+            let lut = _mm512_loadu_si512(C::ENCODE.as_ptr() as *const _);
+            let mut iter = BlockIter::new(input, output);
+            while let Some((input, output)) = iter.next_chunk() {
+                let data = _mm512_loadu_si512(input.as_ptr() as *const _);
+                _mm512_storeu_si512(output.as_mut_ptr() as *mut _, encode_block(lut, data));
+            }
+            iter.remaining()
+        }
+    }
+
+    // Encode the first 48 bytes of input. Each group of 3 input bytes is
+    // spread across a 32-bit lane, vpmultishiftqb extracts the four 6-bit
+    // values, and vpermb translates them using the 64 byte encode table.
+    #[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+    #[inline]
+    unsafe fn encode_block(lut: __m512i, input: __m512i) -> __m512i {
+        #[rustfmt::skip]
+        let input = _mm512_permutexvar_epi8(
+            _mm512_setr_epi32(
+                0x0102_0001, 0x0405_0304, 0x0708_0607, 0x0a0b_090a,
+                0x0d0e_0c0d, 0x1011_0f10, 0x1314_1213, 0x1617_1516,
+                0x191a_1819, 0x1c1d_1b1c, 0x1f20_1e1f, 0x2223_2122,
+                0x2526_2425, 0x2829_2728, 0x2b2c_2a2b, 0x2e2f_2d2e,
+            ),
+            input,
+        );
+        let indices = _mm512_multishift_epi64_epi8(_mm512_set1_epi64(0x3036_242a_1016_040a), input);
+        _mm512_permutexvar_epi8(indices, lut)
+    }
+
+    define_block_iter!(name=BlockIter, input_chunk_size=64, input_stride=48, output_chunk_size=64, output_stride=64);
+
+    macro_rules! impl_tables {
+        ($( $cfg:ident => $encode_table:ident ),+) => {$(
+            impl Tables for $cfg {
+                const ENCODE: &'static [u8; 64] = crate::tables::$encode_table;
+            }
+        )+}
+    }
+    impl_tables!(
+        Std => STD_ENCODE,
+        StdNoPad => STD_ENCODE,
+        UrlSafe => URL_SAFE_ENCODE,
+        UrlSafeNoPad => URL_SAFE_ENCODE,
+        Crypt => CRYPT_ENCODE,
+        Fast => FAST_ENCODE
+    );
+}
+
+#[cfg(feature = "ssse3")]
+pub(crate) mod ssse3 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    use crate::{Std, StdNoPad, UrlSafe, UrlSafeNoPad, Crypt, Fast};
+    use core::mem::MaybeUninit;
+
+    pub trait Translate128i: Copy {
+        unsafe fn translate_m128i(input: __m128i) -> __m128i;
+    }
+
+    #[derive(Debug,Clone,Copy)]
+    pub(crate) struct Encoder<C>(C);
+
+    impl<C> Encoder<C> where C: Translate128i {
+        #[inline]
+        pub(crate) fn new(config: C) -> Result<Self, ()> {
+            if crate::backend::use_ssse3() {
+                Ok(Encoder(config))
+            } else {
+                Err(())
+            }
+        }
+
+        pub(crate) fn encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
+            // The unsafe block is required because _encode_blocks relies on
+            // SSSE3 intrinsics. This is safe because Encoder::new() ensures
+            // that an encoder is only successfully created when the CPU
+            // supports SSSE3.
+            unsafe { self._encode_blocks(input, output) }
+        }
+
+        #[target_feature(enable = "ssse3")]
+        unsafe fn _encode_blocks(self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> (usize, usize) {
+            let mut iter = BlockIter::new(input, output);
+            while let Some((input, output)) = iter.next_chunk() {
+                #[allow(clippy::cast_ptr_alignment)]
+                let data = _mm_loadu_si128(input.as_ptr() as *const __m128i);
+                #[allow(clippy::cast_ptr_alignment)]
+                _mm_storeu_si128(output.as_mut_ptr() as *mut __m128i, self.encode_block(data));
+            }
+            iter.remaining()
+        }
+
+        // The 128-bit equivalent of the AVX2 kernel, encoding the first 12
+        // bytes of input.
+        #[target_feature(enable = "ssse3")]
+        unsafe fn encode_block(self, input: __m128i) -> __m128i {
+            let input = _mm_shuffle_epi8(
+                input,
+                _mm_setr_epi8(2, 2, 1, 0, 5, 5, 4, 3, 8, 8, 7, 6, 11, 11, 10, 9),
+            );
+            let mask = _mm_set1_epi32(0x3F00_0000);
+            let res = _mm_and_si128(_mm_srli_epi32(input, 2), mask);
+            let mask = _mm_srli_epi32(mask, 8);
+            let res = _mm_or_si128(res, _mm_and_si128(_mm_srli_epi32(input, 4), mask));
+            let mask = _mm_srli_epi32(mask, 8);
+            let res = _mm_or_si128(res, _mm_and_si128(_mm_srli_epi32(input, 6), mask));
+            let mask = _mm_srli_epi32(mask, 8);
+            let res = _mm_or_si128(res, _mm_and_si128(input, mask));
+            let res = _mm_shuffle_epi8(
+                res,
+                _mm_setr_epi8(3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12),
+            );
+            C::translate_m128i(res)
+        }
+    }
+
+    define_block_iter!(name=BlockIter, input_chunk_size=16, input_stride=12, output_chunk_size=16, output_stride=16);
+
+    // Translate 6-bit values to an alphabet where the last two characters
+    // follow the digits, as in the std and url safe alphabets.
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    unsafe fn translate_with_last_two(input: __m128i, char62: u8, char63: u8) -> __m128i {
+        let s1mask = _mm_cmpgt_epi8(_mm_set1_epi8(26), input);
+        let mut blockmask = s1mask;
+        let s2mask = _mm_andnot_si128(blockmask, _mm_cmpgt_epi8(_mm_set1_epi8(52), input));
+        blockmask = _mm_or_si128(blockmask, s2mask);
+        let s3mask = _mm_andnot_si128(blockmask, _mm_cmpgt_epi8(_mm_set1_epi8(62), input));
+        blockmask = _mm_or_si128(blockmask, s3mask);
+        let s4mask = _mm_andnot_si128(blockmask, _mm_cmpgt_epi8(_mm_set1_epi8(63), input));
+        blockmask = _mm_or_si128(blockmask, s4mask);
+        let s1 = _mm_and_si128(s1mask, _mm_add_epi8(input, _mm_set1_epi8(b'A' as i8)));
+        let s2 = _mm_and_si128(s2mask, _mm_add_epi8(input, _mm_set1_epi8(b'a' as i8 - 26)));
+        let s3 = _mm_and_si128(s3mask, _mm_add_epi8(input, _mm_set1_epi8(b'0' as i8 - 52)));
+        let s4 = _mm_and_si128(s4mask, _mm_set1_epi8(char62 as i8));
+        let s5 = _mm_andnot_si128(blockmask, _mm_set1_epi8(char63 as i8));
+        _mm_or_si128(s1, _mm_or_si128(s2, _mm_or_si128(s3, _mm_or_si128(s4, s5))))
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    unsafe fn translate_std(input: __m128i) -> __m128i {
+        translate_with_last_two(input, b'+', b'/')
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    unsafe fn translate_url_safe(input: __m128i) -> __m128i {
+        translate_with_last_two(input, b'-', b'_')
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    unsafe fn translate_crypt(input: __m128i) -> __m128i {
+        let s1mask = _mm_cmpgt_epi8(_mm_set1_epi8(12), input);
+        let mut blockmask = s1mask;
+        let s2mask = _mm_andnot_si128(blockmask, _mm_cmpgt_epi8(_mm_set1_epi8(38), input));
+        blockmask = _mm_or_si128(blockmask, s2mask);
+        let s1 = _mm_and_si128(s1mask, _mm_add_epi8(input, _mm_set1_epi8(b'.' as i8)));
+        let s2 = _mm_and_si128(s2mask, _mm_add_epi8(input, _mm_set1_epi8(b'A' as i8 - 12)));
+        let s3 = _mm_andnot_si128(blockmask, _mm_add_epi8(input, _mm_set1_epi8(b'a' as i8 - 38)));
+        _mm_or_si128(s1, _mm_or_si128(s2, s3))
+    }
+
+    #[target_feature(enable = "ssse3")]
+    #[inline]
+    unsafe fn translate_fast(input: __m128i) -> __m128i {
+        _mm_add_epi8(input, _mm_set1_epi8(62))
+    }
+
+    macro_rules! impl_translate {
+        ($( $cfg:ident => $translate:ident ),+) => {$(
+            impl Translate128i for $cfg {
+                #[inline]
+                unsafe fn translate_m128i(input: __m128i) -> __m128i {
+                    $translate(input)
+                }
+            }
+        )+}
+    }
+    impl_translate!(
+        Std => translate_std,
+        StdNoPad => translate_std,
+        UrlSafe => translate_url_safe,
+        UrlSafeNoPad => translate_url_safe,
+        Crypt => translate_crypt,
+        Fast => translate_fast
+    );
+}
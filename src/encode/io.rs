@@ -1,6 +1,10 @@
-use crate::copy_in_place;
-use crate::encode::{encode_chunk, encode_full_chunks_without_padding, encode_partial_chunk};
+use crate::encode::{
+    encode_chunk, encode_full_chunks_without_padding, encode_partial_chunk, encoded_len_u64,
+};
+use crate::io::{LimitExceeded, ProgressHook, ProgressReader};
+use crate::wrap::LineState;
 use crate::Config;
+use alloc::vec;
 use std::{fmt, fmt::Debug, io};
 
 /// Encode base64 data to a std::io::Write.
@@ -27,6 +31,15 @@ where
     partial_input: [u8; 3],
     // number of bytes in partial_input.
     bytes_in_partial_input: usize,
+    // position within the current line of output written to the inner writer.
+    // Only used when the config wraps its output.
+    line_state: LineState,
+    // The maximum number of encoded bytes in each message, if limited.
+    max_encoded_bytes: Option<u64>,
+    // number of input bytes consumed by writes in the current message.
+    message_input_bytes: u64,
+    // reports the number of input bytes consumed, if requested.
+    progress: Option<ProgressHook>,
     // A flag used to indicate that a panic was encountered when writing to the
     // inner writer. Used in the Drop impl to not attempt writing to the inner
     // writer again.
@@ -47,15 +60,69 @@ where
             bytes_in_pending_output: 0,
             partial_input: [0; 3],
             bytes_in_partial_input: 0,
+            line_state: LineState::default(),
+            max_encoded_bytes: None,
+            message_input_bytes: 0,
+            progress: None,
             panicked: false,
         }
     }
 
+    /// Limit the number of encoded bytes, including padding and line
+    /// separators, that each message can produce. A write that would cause the
+    /// encoded message to exceed the limit returns an error wrapping
+    /// [LimitExceeded](struct.LimitExceeded.html) without consuming any of its
+    /// input, so nothing is encoded beyond what fits. The limit applies to the
+    /// data written until `finish` or
+    /// [finish_message](#method.finish_message), which starts a new message.
+    ///
+    /// ```
+    /// use radix64::{STD, io::EncodeWriter};
+    /// use std::io::Write;
+    ///
+    /// let mut writer = EncodeWriter::new(STD, Vec::new()).with_max_encoded_bytes(8);
+    /// writer.write_all(b"abcd").unwrap();
+    /// assert!(writer.write_all(b"ef").is_ok());
+    /// assert!(writer.write_all(b"g").is_err());
+    /// assert_eq!(writer.finish().unwrap(), b"YWJjZGVm");
+    /// ```
+    pub fn with_max_encoded_bytes(mut self, limit: u64) -> Self {
+        self.max_encoded_bytes = Some(limit);
+        self
+    }
+
+    /// Report progress by calling `callback` with the total number of input
+    /// bytes consumed by writes each time at least `interval` more bytes have
+    /// been consumed, and once more with the final total when the data is
+    /// finished. Encoding a large file can then drive a progress indicator
+    /// without a counting wrapper around the writer.
+    ///
+    /// ```
+    /// use radix64::{STD, io::EncodeWriter};
+    /// use std::io::Write;
+    /// use std::sync::mpsc;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut writer = EncodeWriter::new(STD, Vec::new())
+    ///     .with_progress(4096, move |n| tx.send(n).unwrap());
+    /// writer.write_all(&[0; 10_000]).unwrap();
+    /// writer.finish().unwrap();
+    /// assert_eq!(rx.iter().last(), Some(10_000));
+    /// ```
+    pub fn with_progress<F>(mut self, interval: u64, callback: F) -> Self
+    where
+        F: FnMut(u64) + Send + Sync + 'static,
+    {
+        self.progress = Some(ProgressHook::new(interval, callback));
+        self
+    }
+
     /// Indicate that we are finished writing. Any partial chunks will be written
     /// to the underyling writer. This may invoke write on the underlying writer
     /// multiple times. On success the underlying writer is returned. On error a
     /// `FinishError` is returned that allows recovering the EncodeWriter if
     /// desired.
+    #[allow(clippy::result_large_err)]
     pub fn finish(mut self) -> Result<W, FinishError<Self>> {
         match self.do_finish() {
             Ok(()) => Ok(self.inner.take().unwrap()),
@@ -63,6 +130,43 @@ where
         }
     }
 
+    /// Finish the current message without giving up the underlying writer.
+    /// Any partial chunk is padded and written, followed by `separator`, and
+    /// the underlying writer is flushed. The next input written starts a new
+    /// message, beginning on a new line if the config wraps its output. Pass
+    /// an empty separator to write the messages back to back.
+    ///
+    /// If an error is returned the message's encoded data may have been
+    /// written without the separator. Retrying writes whatever remains of the
+    /// message followed by the entire separator.
+    ///
+    /// ```
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use radix64::{STD, io::EncodeWriter};
+    /// use std::io::Write;
+    ///
+    /// let mut writer = EncodeWriter::new(STD, Vec::new());
+    /// writer.write_all(b"first")?;
+    /// writer.finish_message(b"\n")?;
+    /// writer.write_all(b"second")?;
+    /// writer.finish_message(b"\n")?;
+    /// let encoded = writer.finish()?;
+    /// assert_eq!(encoded, b"Zmlyc3Q=\nc2Vjb25k\n");
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    pub fn finish_message(&mut self, separator: &[u8]) -> io::Result<()> {
+        self.do_finish()?;
+        self.line_state = LineState::default();
+        self.message_input_bytes = 0;
+        self.panicked = true;
+        let inner = self.inner.as_mut().unwrap();
+        let res = inner.write_all(separator).and_then(|()| inner.flush());
+        self.panicked = false;
+        res
+    }
+
     fn do_finish(&mut self) -> io::Result<()> {
         while self.bytes_in_pending_output > 0 || self.bytes_in_partial_input > 0 {
             let bytes_remaining_in_pending_output =
@@ -78,16 +182,37 @@ where
             }
             self.write_atleast(self.bytes_in_pending_output)?;
         }
+        if let Some(progress) = &mut self.progress {
+            progress.finish();
+        }
         Ok(())
     }
 
     fn write_to_inner<R>(&mut self, range: R) -> io::Result<usize>
     where
-        R: std::slice::SliceIndex<[u8], Output = [u8]>,
+        R: core::slice::SliceIndex<[u8], Output = [u8]>,
     {
         self.panicked = true;
         let input = &self.pending_output[range];
-        let res = self.inner.as_mut().unwrap().write(input);
+        let inner = self.inner.as_mut().unwrap();
+        let res = match self.config.line_wrap() {
+            Some(ref wrap) if !input.is_empty() => {
+                let line_state = &mut self.line_state;
+                if let Some(separator) = line_state.pending_separator(wrap) {
+                    // Writing a separator does not consume any pending output.
+                    inner.write(separator).map(|n| {
+                        line_state.separator_written(wrap, n);
+                        0
+                    })
+                } else {
+                    let line_len = core::cmp::min(input.len(), line_state.remaining_in_line(wrap));
+                    inner
+                        .write(&input[..line_len])
+                        .inspect(|&n| line_state.data_written(n))
+                }
+            }
+            _ => inner.write(input),
+        };
         self.panicked = false;
         res
     }
@@ -110,21 +235,13 @@ where
 
     fn consume_pending_output(&mut self, num_bytes: usize) {
         debug_assert!(num_bytes <= self.bytes_in_pending_output);
-        copy_in_place(
-            &mut self.pending_output[..self.bytes_in_pending_output],
-            num_bytes..,
-            0,
-        );
+        self.pending_output[..self.bytes_in_pending_output].copy_within(num_bytes.., 0);
         self.bytes_in_pending_output -= num_bytes;
     }
-}
 
-impl<C, W> io::Write for EncodeWriter<C, W>
-where
-    C: Config,
-    W: io::Write,
-{
-    fn write(&mut self, mut input: &[u8]) -> io::Result<usize> {
+    // Encode as much of the input as possible into pending_output, writing to
+    // the inner writer as needed. Returns the number of input bytes consumed.
+    fn encode_input(&mut self, mut input: &[u8]) -> io::Result<usize> {
         let mut input_bytes_consumed = 0;
         let mut bytes_in_partial_input_checkpoint = 0;
         let mut bytes_in_pending_output_checkpoint = 0;
@@ -185,7 +302,7 @@ where
                 // We have a partial chunk from a previous write. Complete the
                 // chunk if possible. Returning if input was too small to
                 // complete the chunk.
-                let bytes_to_copy = std::cmp::min(input.len(), 3 - self.bytes_in_partial_input);
+                let bytes_to_copy = core::cmp::min(input.len(), 3 - self.bytes_in_partial_input);
                 self.partial_input
                     [self.bytes_in_partial_input..self.bytes_in_partial_input + bytes_to_copy]
                     .clone_from_slice(&input[..bytes_to_copy]);
@@ -231,6 +348,27 @@ where
             }
         }
     }
+}
+
+impl<C, W> io::Write for EncodeWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    fn write(&mut self, input: &[u8]) -> io::Result<usize> {
+        if let Some(limit) = self.max_encoded_bytes {
+            let input_len = self.message_input_bytes + input.len() as u64;
+            if encoded_len_u64(self.config, input_len) > limit {
+                return Err(io::Error::other(LimitExceeded::new(limit)));
+            }
+        }
+        let n = self.encode_input(input)?;
+        self.message_input_bytes += n as u64;
+        if let Some(progress) = &mut self.progress {
+            progress.advance(n);
+        }
+        Ok(n)
+    }
 
     /// This will only flush full chunks of base64 data. Partial chunks cannot be
     /// written until we're done writing completely.
@@ -241,6 +379,31 @@ where
     }
 }
 
+/// Formatted text is encoded as it's written, without first being collected
+/// into a String. An error from the underlying writer is reported as
+/// `fmt::Error`, discarding the details, and some of the text may have been
+/// encoded before it occurred. `std::io::Write::write_fmt` retains the
+/// `io::Error`, but only one of the two traits can be in scope when using
+/// `write!`.
+///
+/// ```
+/// use radix64::{STD, io::EncodeWriter};
+/// use std::fmt::Write;
+///
+/// let mut writer = EncodeWriter::new(STD, Vec::new());
+/// write!(writer, "{} {}", "my", "message").unwrap();
+/// assert_eq!(writer.finish().unwrap(), b"bXkgbWVzc2FnZQ==");
+/// ```
+impl<C, W> fmt::Write for EncodeWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        io::Write::write_all(self, s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
 impl<C, W> Drop for EncodeWriter<C, W>
 where
     C: Config,
@@ -265,17 +428,130 @@ where
             .field("bytes_in_pending_output", &self.bytes_in_pending_output)
             .field("partial_input", &&self.partial_input[..])
             .field("bytes_in_partial_input", &self.bytes_in_partial_input)
+            .field("line_state", &self.line_state)
+            .field("max_encoded_bytes", &self.max_encoded_bytes)
+            .field("message_input_bytes", &self.message_input_bytes)
+            .field("progress", &self.progress)
             .field("panicked", &self.panicked)
             .finish()
     }
 }
 
+/// Encode everything read from `reader` and write it to `writer`, returning
+/// the number of encoded bytes written.
+///
+/// This behaves like using `std::io::copy` to copy from `reader` to an
+/// `EncodeWriter`, but encodes large blocks at a time without the additional
+/// buffering of the adapter. It's the fastest way to encode an entire stream.
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::copy_encode};
+///
+/// let mut encoded = Vec::new();
+/// copy_encode(STD, &mut &b"my message"[..], &mut encoded)?;
+/// assert_eq!(encoded, b"bXkgbWVzc2FnZQ==");
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub fn copy_encode<C, R, W>(config: C, reader: &mut R, writer: &mut W) -> io::Result<u64>
+where
+    C: Config,
+    R: io::Read + ?Sized,
+    W: io::Write + ?Sized,
+{
+    // The number of input bytes encoded at a time. When the config wraps its
+    // output each full block must end at the end of a line, so that the
+    // separator between blocks can be written separately.
+    const TARGET_BLOCK_SIZE: usize = 48 * 1024;
+    let block_unit = match config.line_wrap() {
+        Some(wrap) => {
+            let interval = wrap.interval();
+            // Each unit encodes to the least common multiple of the interval
+            // and the chunk size.
+            let (mut a, mut b) = (interval, 4);
+            while b != 0 {
+                let r = a % b;
+                a = b;
+                b = r;
+            }
+            interval / a * 3
+        }
+        None => 3,
+    };
+    let block_size = core::cmp::max(TARGET_BLOCK_SIZE / block_unit, 1) * block_unit;
+    let mut input = vec![0; block_size];
+    let mut output = vec![0; crate::encode::max_encoded_len(config, block_size)];
+    let mut written = 0;
+    loop {
+        let n = crate::io::read_full(reader, &mut input)?;
+        if n == 0 {
+            return Ok(written);
+        }
+        if written > 0 {
+            if let Some(wrap) = config.line_wrap() {
+                writer.write_all(wrap.separator())?;
+                written += wrap.separator().len() as u64;
+            }
+        }
+        let encoded_len = crate::encode::encode_slice(config, &input[..n], &mut output);
+        writer.write_all(&output[..encoded_len])?;
+        written += encoded_len as u64;
+        if n < input.len() {
+            return Ok(written);
+        }
+    }
+}
+
+/// Encode like [copy_encode](fn.copy_encode.html), calling `callback` with
+/// the total number of input bytes read each time at least `interval` more
+/// bytes have been read, and once more with the final total on success.
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::copy_encode_with_progress};
+/// use std::sync::{Arc, Mutex};
+///
+/// let reports = Arc::new(Mutex::new(Vec::new()));
+/// let progress = reports.clone();
+/// let mut encoded = Vec::new();
+/// copy_encode_with_progress(STD, &mut &[0; 100][..], &mut encoded, 64, move |n| {
+///     progress.lock().unwrap().push(n)
+/// })?;
+/// assert_eq!(*reports.lock().unwrap(), [100]);
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub fn copy_encode_with_progress<C, R, W, F>(
+    config: C,
+    reader: &mut R,
+    writer: &mut W,
+    interval: u64,
+    callback: F,
+) -> io::Result<u64>
+where
+    C: Config,
+    R: io::Read + ?Sized,
+    W: io::Write + ?Sized,
+    F: FnMut(u64) + Send + Sync + 'static,
+{
+    let mut reader = ProgressReader {
+        inner: reader,
+        hook: ProgressHook::new(interval, callback),
+    };
+    let written = copy_encode(config, &mut reader, writer)?;
+    reader.hook.finish();
+    Ok(written)
+}
+
 #[derive(Debug)]
 /// FinishError is returned from `EncodeWriter::finish` it indicates that the
 /// underlying writer returned an error when attempting to write the final chunk.
 /// It's possible to recover the EncodeWriter from this error if retrying the
 /// finish call is desired.
-pub struct FinishError<T>(T, io::Error);
+pub struct FinishError<T>(pub(crate) T, pub(crate) io::Error);
 
 impl<T> FinishError<T> {
     /// The io::Error from the underlying writer.
@@ -289,11 +565,7 @@ impl<T> FinishError<T> {
     }
 }
 
-impl<T: Send + fmt::Debug> std::error::Error for FinishError<T> {
-    fn description(&self) -> &str {
-        std::error::Error::description(self.error())
-    }
-}
+impl<T: Send + fmt::Debug> core::error::Error for FinishError<T> {}
 
 impl<T> fmt::Display for FinishError<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
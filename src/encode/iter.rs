@@ -0,0 +1,137 @@
+use crate::encode::{encode_full_chunks_without_padding, encode_partial_chunk};
+use crate::wrap::{LineState, LineWrap};
+use crate::Config;
+use core::iter::FusedIterator;
+
+// Number of input bytes encoded at a time. Must be a multiple of 3.
+const INPUT_CHUNK_SIZE: usize = 48;
+
+/// An iterator over the bytes of the encoded input, returned by
+/// [Config::encode_bytes_iter](trait.Config.html#method.encode_bytes_iter).
+///
+/// Input is encoded a small piece at a time into a fixed size buffer as the
+/// iterator advances, so no allocation is made.
+#[derive(Debug, Clone)]
+pub struct EncodeBytes<'a, C> {
+    config: C,
+    input: &'a [u8],
+    line_wrap: Option<LineWrap>,
+    line_state: LineState,
+    buffer: [u8; INPUT_CHUNK_SIZE / 3 * 4],
+    pos: usize,
+    len: usize,
+    remaining: usize,
+}
+
+impl<'a, C> EncodeBytes<'a, C>
+where
+    C: Config,
+{
+    pub(crate) fn new(config: C, input: &'a [u8]) -> Self {
+        EncodeBytes {
+            config,
+            input,
+            line_wrap: config.line_wrap(),
+            line_state: LineState::default(),
+            buffer: [0; INPUT_CHUNK_SIZE / 3 * 4],
+            pos: 0,
+            len: 0,
+            remaining: config.encoded_output_len(input.len()),
+        }
+    }
+
+    // Encode the next piece of input into the buffer, returning false once
+    // all the input has been encoded.
+    fn refill(&mut self) -> bool {
+        let n = core::cmp::min(self.input.len(), INPUT_CHUNK_SIZE);
+        let (chunk, rest) = self.input.split_at(n);
+        let (input_idx, output_idx) =
+            encode_full_chunks_without_padding(self.config, chunk, &mut self.buffer);
+        let partial_chunk_len = encode_partial_chunk(
+            self.config,
+            &chunk[input_idx..],
+            &mut self.buffer[output_idx..],
+        );
+        self.input = rest;
+        self.pos = 0;
+        self.len = output_idx + partial_chunk_len;
+        self.len > 0
+    }
+}
+
+impl<'a, C> Iterator for EncodeBytes<'a, C>
+where
+    C: Config,
+{
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.pos == self.len && !self.refill() {
+            return None;
+        }
+        self.remaining -= 1;
+        if let Some(ref wrap) = self.line_wrap {
+            // Separators are only written before more encoded data, so the
+            // output never ends with one.
+            if let Some(separator) = self.line_state.pending_separator(wrap) {
+                let b = separator[0];
+                self.line_state.separator_written(wrap, 1);
+                return Some(b);
+            }
+            self.line_state.data_written(1);
+        }
+        let b = self.buffer[self.pos];
+        self.pos += 1;
+        Some(b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, C> ExactSizeIterator for EncodeBytes<'a, C> where C: Config {}
+
+impl<'a, C> FusedIterator for EncodeBytes<'a, C> where C: Config {}
+
+/// An iterator over the characters of the encoded input, returned by
+/// [Config::encode_iter](trait.Config.html#method.encode_iter).
+///
+/// This yields the same output as [EncodeBytes](struct.EncodeBytes.html), as
+/// chars.
+#[derive(Debug, Clone)]
+pub struct EncodeChars<'a, C>(EncodeBytes<'a, C>);
+
+impl<'a, C> EncodeChars<'a, C>
+where
+    C: Config,
+{
+    pub(crate) fn new(config: C, input: &'a [u8]) -> Self {
+        EncodeChars(EncodeBytes::new(config, input))
+    }
+}
+
+impl<'a, C> Iterator for EncodeChars<'a, C>
+where
+    C: Config,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        // The encoded output is always ascii, as described in
+        // Config::encode.
+        self.0.next().map(char::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, C> ExactSizeIterator for EncodeChars<'a, C> where C: Config {}
+
+impl<'a, C> FusedIterator for EncodeChars<'a, C> where C: Config {}
@@ -0,0 +1,294 @@
+use crate::encode::io::FinishError;
+use crate::encode::{encode_full_chunks_without_padding, encode_partial_chunk};
+use crate::wrap::LineState;
+use crate::Config;
+use alloc::vec::Vec;
+use std::sync::mpsc;
+use std::{fmt, fmt::Debug, io, thread};
+
+// The number of unencoded bytes handed to the encoder thread at a time. Must
+// be a multiple of 3 so that only the final block contains a partial chunk.
+const BLOCK_SIZE: usize = 48 * 1024;
+
+/// Encode base64 data to a std::io::Write, encoding on a background thread.
+///
+/// PipelinedEncodeWriter behaves like [EncodeWriter](struct.EncodeWriter.html)
+/// but is double buffered. Input is collected into large blocks that are
+/// encoded on a dedicated thread while the previously encoded block is being
+/// written to the underlying writer. When the underlying writer is slow (a
+/// file or network socket) this overlaps the encoding work with the IO.
+///
+/// Writes to the underlying writer are always performed on the calling
+/// thread, so errors are reported from the `write`, `flush`, or `finish` call
+/// that encountered them. As with EncodeWriter, `finish()` will automatically
+/// be invoked on Drop if not done explicitly, ignoring any errors.
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::PipelinedEncodeWriter};
+/// use std::io::Write;
+///
+/// let mut writer = PipelinedEncodeWriter::new(STD, Vec::new());
+/// writer.write_all(b"my message")?;
+/// let encoded = writer.finish()?;
+/// assert_eq!(encoded, b"bXkgbWVzc2FnZQ==");
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub struct PipelinedEncodeWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    config: C,
+    inner: Option<W>,
+    // unencoded input that has not yet been sent to the encoder thread.
+    input: Vec<u8>,
+    // channel of (input, output buffer) pairs sent to the encoder thread.
+    to_encoder: mpsc::Sender<(Vec<u8>, Vec<u8>)>,
+    // channel of (encoded output, emptied input buffer) pairs returned from the
+    // encoder thread.
+    from_encoder: mpsc::Receiver<(Vec<u8>, Vec<u8>)>,
+    // number of blocks sent to the encoder thread that have not been received.
+    blocks_in_flight: usize,
+    // already encoded output, waiting to be written.
+    pending_output: Vec<u8>,
+    // number of bytes at the front of pending_output already written.
+    pending_output_written: usize,
+    // buffers no longer in use, available to be reused for input or output.
+    spare_buffers: Vec<Vec<u8>>,
+    // position within the current line of output written to the inner writer.
+    // Only used when the config wraps its output.
+    line_state: LineState,
+    // A flag used to indicate that a panic was encountered when writing to the
+    // inner writer. Used in the Drop impl to not attempt writing to the inner
+    // writer again.
+    panicked: bool,
+}
+
+impl<C, W> PipelinedEncodeWriter<C, W>
+where
+    C: Config + Send + 'static,
+    W: io::Write,
+{
+    /// Create a new PipelinedEncodeWriter that wraps the provided writer. This
+    /// spawns the thread used for encoding, which exits once the
+    /// PipelinedEncodeWriter is finished or dropped.
+    pub fn new(config: C, writer: W) -> Self {
+        let (to_encoder, encoder_input) = mpsc::channel::<(Vec<u8>, Vec<u8>)>();
+        let (encoder_output, from_encoder) = mpsc::channel();
+        thread::spawn(move || {
+            for (mut input, mut output) in encoder_input {
+                encode_block(config, &input, &mut output);
+                input.clear();
+                if encoder_output.send((output, input)).is_err() {
+                    break;
+                }
+            }
+        });
+        PipelinedEncodeWriter {
+            config,
+            inner: Some(writer),
+            input: Vec::with_capacity(BLOCK_SIZE),
+            to_encoder,
+            from_encoder,
+            blocks_in_flight: 0,
+            pending_output: Vec::new(),
+            pending_output_written: 0,
+            spare_buffers: Vec::new(),
+            line_state: LineState::default(),
+            panicked: false,
+        }
+    }
+}
+
+impl<C, W> PipelinedEncodeWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    /// Indicate that we are finished writing. Any buffered input, including
+    /// partial chunks, will be encoded and written to the underyling writer.
+    /// On success the underlying writer is returned. On error a `FinishError`
+    /// is returned that allows recovering the PipelinedEncodeWriter if desired.
+    #[allow(clippy::result_large_err)]
+    pub fn finish(mut self) -> Result<W, FinishError<Self>> {
+        match self.do_finish() {
+            Ok(()) => Ok(self.inner.take().unwrap()),
+            Err(err) => Err(FinishError(self, err)),
+        }
+    }
+
+    fn do_finish(&mut self) -> io::Result<()> {
+        if !self.input.is_empty() {
+            let input = core::mem::take(&mut self.input);
+            self.send_block(input)?;
+        }
+        self.drain(0)
+    }
+
+    // Send a block of input to the encoder thread.
+    fn send_block(&mut self, input: Vec<u8>) -> io::Result<()> {
+        let output = self.spare_buffers.pop().unwrap_or_default();
+        self.to_encoder
+            .send((input, output))
+            .map_err(|_| encoder_thread_died())?;
+        self.blocks_in_flight += 1;
+        Ok(())
+    }
+
+    // Write all pending output and receive encoded blocks from the encoder
+    // thread until at most max_blocks_in_flight remain being encoded.
+    fn drain(&mut self, max_blocks_in_flight: usize) -> io::Result<()> {
+        loop {
+            while self.pending_output_written < self.pending_output.len() {
+                match self.write_to_inner() {
+                    Ok(0) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::WriteZero,
+                            "failed to write encoded output",
+                        ));
+                    }
+                    Ok(_) => {}
+                    Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                    Err(err) => return Err(err),
+                }
+            }
+            if self.blocks_in_flight <= max_blocks_in_flight {
+                return Ok(());
+            }
+            let (output, input) = self
+                .from_encoder
+                .recv()
+                .map_err(|_| encoder_thread_died())?;
+            self.blocks_in_flight -= 1;
+            let mut written = core::mem::replace(&mut self.pending_output, output);
+            written.clear();
+            self.spare_buffers.push(written);
+            self.spare_buffers.push(input);
+            self.pending_output_written = 0;
+        }
+    }
+
+    // Write some of the pending output to the inner writer, returning the
+    // number of bytes the inner writer accepted. These may be separator bytes
+    // that do not consume any pending output.
+    fn write_to_inner(&mut self) -> io::Result<usize> {
+        self.panicked = true;
+        let input = &self.pending_output[self.pending_output_written..];
+        let inner = self.inner.as_mut().unwrap();
+        let res = match self.config.line_wrap() {
+            Some(ref wrap) if !input.is_empty() => {
+                let line_state = &mut self.line_state;
+                if let Some(separator) = line_state.pending_separator(wrap) {
+                    inner
+                        .write(separator)
+                        .inspect(|&n| line_state.separator_written(wrap, n))
+                } else {
+                    let line_len = core::cmp::min(input.len(), line_state.remaining_in_line(wrap));
+                    let written = &mut self.pending_output_written;
+                    inner.write(&input[..line_len]).inspect(|&n| {
+                        line_state.data_written(n);
+                        *written += n;
+                    })
+                }
+            }
+            _ => {
+                let written = &mut self.pending_output_written;
+                inner.write(input).inspect(|&n| *written += n)
+            }
+        };
+        self.panicked = false;
+        res
+    }
+}
+
+impl<C, W> io::Write for PipelinedEncodeWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    fn write(&mut self, input: &[u8]) -> io::Result<usize> {
+        // Write out everything except the most recently submitted block before
+        // accepting any input, so an error never leaves input half consumed.
+        // The most recent block continues encoding while its predecessor is
+        // written.
+        self.drain(1)?;
+        let n = core::cmp::min(input.len(), BLOCK_SIZE - self.input.len());
+        self.input.extend_from_slice(&input[..n]);
+        if self.input.len() == BLOCK_SIZE {
+            let next = self
+                .spare_buffers
+                .pop()
+                .unwrap_or_else(|| Vec::with_capacity(BLOCK_SIZE));
+            let block = core::mem::replace(&mut self.input, next);
+            self.send_block(block)?;
+        }
+        Ok(n)
+    }
+
+    /// This will only flush full chunks of base64 data. Partial chunks cannot be
+    /// written until we're done writing completely.
+    fn flush(&mut self) -> io::Result<()> {
+        let full_chunks_len = self.input.len() - self.input.len() % 3;
+        if full_chunks_len > 0 {
+            let mut next = self.spare_buffers.pop().unwrap_or_default();
+            next.extend_from_slice(&self.input[full_chunks_len..]);
+            let mut block = core::mem::replace(&mut self.input, next);
+            block.truncate(full_chunks_len);
+            self.send_block(block)?;
+        }
+        self.drain(0)?;
+        self.panicked = true;
+        let res = self.inner.as_mut().unwrap().flush();
+        self.panicked = false;
+        res
+    }
+}
+
+impl<C, W> Drop for PipelinedEncodeWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    fn drop(&mut self) {
+        if self.inner.is_some() && !self.panicked {
+            let _ = self.do_finish();
+        }
+    }
+}
+
+impl<C, W> Debug for PipelinedEncodeWriter<C, W>
+where
+    C: Config + Debug,
+    W: io::Write,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("PipelinedEncodeWriter")
+            .field("config", &self.config)
+            .field("bytes_in_input", &self.input.len())
+            .field("blocks_in_flight", &self.blocks_in_flight)
+            .field(
+                "bytes_in_pending_output",
+                &(self.pending_output.len() - self.pending_output_written),
+            )
+            .field("line_state", &self.line_state)
+            .field("panicked", &self.panicked)
+            .finish()
+    }
+}
+
+// Encode a block of input without line separators. Separators are inserted as
+// the output is written so that lines can span blocks.
+fn encode_block<C: Config>(config: C, input: &[u8], output: &mut Vec<u8>) {
+    output.resize(input.len() * 4 / 3 + 4, 0);
+    let (input_idx, output_idx) = encode_full_chunks_without_padding(config, input, output);
+    let encoded_len =
+        output_idx + encode_partial_chunk(config, &input[input_idx..], &mut output[output_idx..]);
+    output.truncate(encoded_len);
+}
+
+fn encoder_thread_died() -> io::Error {
+    io::Error::other("base64 encoder thread exited unexpectedly")
+}
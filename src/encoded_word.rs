@@ -0,0 +1,264 @@
+//! Building and parsing MIME encoded-words as described in
+//! [RFC 2047](https://tools.ietf.org/html/rfc2047).
+//!
+//! Encoded-words carry non-ascii text in email headers, such as a `Subject`
+//! line. Words produced here use the `UTF-8` charset with the `B` encoding,
+//! which is base64 using the [STD](../constant.STD.html) config.
+//!
+//! ```
+//! use radix64::encoded_word;
+//!
+//! let subject = encoded_word::encode("caf\u{e9}");
+//! assert_eq!(subject, "=?UTF-8?B?Y2Fmw6k=?=");
+//! assert_eq!(encoded_word::decode(&subject).unwrap(), "caf\u{e9}");
+//! ```
+//!
+//! # Line length
+//!
+//! An encoded-word may be at most 75 characters long. Longer text is split
+//! into several words, each containing whole characters, and the words are
+//! separated by a folded line break (`"\r\n "`). Every line after the first is
+//! then at most 76 characters, the limit RFC 2047 places on header lines
+//! containing encoded-words. The first word shares its line with the header
+//! name, so callers that need to honor the limit on that line should start the
+//! value on a new folded line.
+use crate::{DecodeError, STD};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{error, fmt};
+
+// The longest encoded-word permitted by RFC 2047.
+const MAX_WORD_LEN: usize = 75;
+const PREFIX: &str = "=?UTF-8?B?";
+const SUFFIX: &str = "?=";
+// The number of input bytes that fit in the encoded-text of a single word.
+const MAX_BYTES_PER_WORD: usize = (MAX_WORD_LEN - PREFIX.len() - SUFFIX.len()) / 4 * 3;
+
+/// Errors that can occur when decoding encoded-words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodedWordError {
+    /// The input is not of the form `=?charset?encoding?encoded-text?=`.
+    Malformed,
+    /// The word uses an encoding other than `B`. The encoding is provided.
+    UnsupportedEncoding(char),
+    /// The word uses a charset that cannot be converted to a `String`. The
+    /// charset is provided.
+    UnsupportedCharset(String),
+    /// The encoded-text is not valid base64.
+    Decode(DecodeError),
+    /// The decoded text is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for EncodedWordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EncodedWordError::Malformed => write!(f, "malformed encoded-word"),
+            EncodedWordError::UnsupportedEncoding(encoding) => {
+                write!(f, "unsupported encoded-word encoding {:?}", encoding)
+            }
+            EncodedWordError::UnsupportedCharset(ref charset) => {
+                write!(f, "unsupported encoded-word charset {:?}", charset)
+            }
+            EncodedWordError::Decode(ref err) => write!(f, "invalid encoded-text: {}", err),
+            EncodedWordError::InvalidUtf8 => write!(f, "encoded-word is not valid UTF-8"),
+        }
+    }
+}
+
+impl error::Error for EncodedWordError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            EncodedWordError::Decode(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A single encoded-word parsed by [decode_word](fn.decode_word.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedWord<'a> {
+    charset: &'a str,
+    language: Option<&'a str>,
+    data: Vec<u8>,
+}
+
+impl<'a> EncodedWord<'a> {
+    /// The charset of the decoded data, as written in the word.
+    pub fn charset(&self) -> &'a str {
+        self.charset
+    }
+
+    /// The language of the text, if specified as described in
+    /// [RFC 2231](https://tools.ietf.org/html/rfc2231#section-5).
+    pub fn language(&self) -> Option<&'a str> {
+        self.language
+    }
+
+    /// The decoded data, in the word's charset.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consume the EncodedWord, returning the decoded data.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Encode the text as one or more encoded-words separated by folded line
+/// breaks. Returns an empty string if the text is empty.
+///
+/// ```
+/// use radix64::encoded_word;
+///
+/// let encoded = encoded_word::encode(&"\u{e9}".repeat(30));
+/// let lines: Vec<_> = encoded.split("\r\n ").collect();
+/// assert_eq!(lines.len(), 2);
+/// assert!(lines.iter().all(|word| word.len() <= 75));
+/// ```
+pub fn encode(text: &str) -> String {
+    let mut encoded = String::new();
+    for word in encode_words(text) {
+        if !encoded.is_empty() {
+            encoded.push_str("\r\n ");
+        }
+        encoded.push_str(&word);
+    }
+    encoded
+}
+
+/// Encode the text as a sequence of encoded-words, each at most 75
+/// characters. Multibyte characters are never split between words.
+pub fn encode_words(text: &str) -> EncodeWords<'_> {
+    EncodeWords { text }
+}
+
+/// An iterator over the encoded-words of some text. Created by
+/// [encode_words](fn.encode_words.html).
+#[derive(Debug, Clone)]
+pub struct EncodeWords<'a> {
+    text: &'a str,
+}
+
+impl Iterator for EncodeWords<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.text.is_empty() {
+            return None;
+        }
+        let mut end = core::cmp::min(self.text.len(), MAX_BYTES_PER_WORD);
+        while !self.text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, rest) = self.text.split_at(end);
+        self.text = rest;
+        let mut word = String::with_capacity(MAX_WORD_LEN);
+        word.push_str(PREFIX);
+        word.push_str(&STD.encode(chunk));
+        word.push_str(SUFFIX);
+        Some(word)
+    }
+}
+
+/// Parse and decode a single encoded-word using the `B` encoding. The charset
+/// is not interpreted, allowing the caller to convert the data from any
+/// charset.
+///
+/// ```
+/// use radix64::encoded_word;
+///
+/// let word = encoded_word::decode_word("=?ISO-8859-1*fr?b?Y2Fm6Q==?=").unwrap();
+/// assert_eq!(word.charset(), "ISO-8859-1");
+/// assert_eq!(word.language(), Some("fr"));
+/// assert_eq!(word.data(), b"caf\xe9");
+/// ```
+pub fn decode_word(word: &str) -> Result<EncodedWord<'_>, EncodedWordError> {
+    let inner = word
+        .strip_prefix("=?")
+        .and_then(|word| word.strip_suffix("?="))
+        .ok_or(EncodedWordError::Malformed)?;
+    let mut parts = inner.splitn(3, '?');
+    let (charset, encoding, encoded_text) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(charset), Some(encoding), Some(encoded_text)) => (charset, encoding, encoded_text),
+        _ => return Err(EncodedWordError::Malformed),
+    };
+    let is_token = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_graphic());
+    if !is_token(charset) || !is_token(encoded_text) || encoded_text.contains('?') {
+        return Err(EncodedWordError::Malformed);
+    }
+    let mut encoding_chars = encoding.chars();
+    match (encoding_chars.next(), encoding_chars.next()) {
+        (Some('B'), None) | (Some('b'), None) => {}
+        (Some(encoding), None) => return Err(EncodedWordError::UnsupportedEncoding(encoding)),
+        _ => return Err(EncodedWordError::Malformed),
+    }
+    let (charset, language) = match charset.find('*') {
+        Some(idx) => (&charset[..idx], Some(&charset[idx + 1..])),
+        None => (charset, None),
+    };
+    let data = STD.decode(encoded_text).map_err(EncodedWordError::Decode)?;
+    Ok(EncodedWord {
+        charset,
+        language,
+        data,
+    })
+}
+
+/// Decode the encoded-words within unstructured header text, such as a
+/// `Subject`. Text that is not an encoded-word is returned unchanged, and the
+/// whitespace separating adjacent encoded-words is removed. Only words in the
+/// `UTF-8` or `US-ASCII` charsets using the `B` encoding can be decoded.
+///
+/// ```
+/// use radix64::encoded_word;
+///
+/// let subject = "Re: =?UTF-8?B?Y2Fm?=\r\n =?utf-8?B?w6k=?= menu";
+/// assert_eq!(encoded_word::decode(subject).unwrap(), "Re: caf\u{e9} menu");
+/// ```
+pub fn decode(text: &str) -> Result<String, EncodedWordError> {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut previous_was_word = false;
+    while !rest.is_empty() {
+        let whitespace_len = rest
+            .find(|c: char| !c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let (whitespace, remaining) = rest.split_at(whitespace_len);
+        let token_len = remaining
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(remaining.len());
+        let (token, remaining) = remaining.split_at(token_len);
+        rest = remaining;
+
+        let word = match decode_word(token) {
+            Ok(word) => Some(word),
+            Err(EncodedWordError::Malformed) => None,
+            Err(err) => return Err(err),
+        };
+        match word {
+            Some(word) => {
+                let charset = word.charset();
+                if !charset.eq_ignore_ascii_case("utf-8")
+                    && !charset.eq_ignore_ascii_case("us-ascii")
+                {
+                    return Err(EncodedWordError::UnsupportedCharset(charset.to_owned()));
+                }
+                if !previous_was_word {
+                    decoded.push_str(whitespace);
+                }
+                let text =
+                    core::str::from_utf8(word.data()).map_err(|_| EncodedWordError::InvalidUtf8)?;
+                decoded.push_str(text);
+                previous_was_word = true;
+            }
+            None => {
+                decoded.push_str(whitespace);
+                decoded.push_str(token);
+                previous_was_word = false;
+            }
+        }
+    }
+    Ok(decoded)
+}
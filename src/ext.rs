@@ -0,0 +1,51 @@
+//! Extension traits providing encoding and decoding methods on byte slices and
+//! strings.
+//!
+//! These provide the `data.to_base64(config)` call shape familiar from
+//! `rustc-serialize`. They are thin wrappers around
+//! [Config::encode](../trait.Config.html#method.encode) and
+//! [Config::decode](../trait.Config.html#method.decode) and must be imported
+//! to be used.
+//!
+//! ```
+//! use radix64::ext::{FromBase64, ToBase64};
+//! use radix64::STD;
+//!
+//! assert_eq!(b"foo".to_base64(STD), "Zm9v");
+//! assert_eq!("Zm9v".from_base64(STD).unwrap(), b"foo");
+//! ```
+use crate::{Config, DecodeError};
+use alloc::{string::String, vec::Vec};
+
+/// Encode data as base64.
+pub trait ToBase64 {
+    /// Encode the data using the provided config.
+    fn to_base64<C: Config>(&self, config: C) -> String;
+}
+
+impl<T> ToBase64 for T
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    #[inline]
+    fn to_base64<C: Config>(&self, config: C) -> String {
+        config.encode(self)
+    }
+}
+
+/// Decode base64 data.
+pub trait FromBase64 {
+    /// Decode the data using the provided config.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_base64<C: Config>(&self, config: C) -> Result<Vec<u8>, DecodeError>;
+}
+
+impl<T> FromBase64 for T
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    #[inline]
+    fn from_base64<C: Config>(&self, config: C) -> Result<Vec<u8>, DecodeError> {
+        config.decode(self)
+    }
+}
@@ -0,0 +1,102 @@
+//! Encoding arbitrary bytes as file names.
+//!
+//! File names produced here use the [URL_SAFE_NO_PAD](../constant.URL_SAFE_NO_PAD.html)
+//! alphabet, which contains only ascii letters, digits, `-`, and `_`. They
+//! never contain a path separator, never start with `.`, and are never empty
+//! unless the input is. With the caveats below, that makes them suitable for
+//! naming entries in cache directories keyed by hashes or other binary
+//! identifiers.
+//!
+//! ```
+//! use radix64::filename;
+//!
+//! let key = [0xfb, 0xff, 0x3e, 0x01];
+//! let name = filename::encode(&key);
+//! assert_eq!(name, "-_8-AQ");
+//! assert_eq!(filename::decode(&name).unwrap(), key);
+//! ```
+//!
+//! # Case-insensitive filesystems
+//!
+//! The alphabet distinguishes upper and lower case letters. On filesystems
+//! that ignore case, such as the defaults on Windows and macOS, two different
+//! inputs can produce names that refer to the same file. Each character of a
+//! name carries 6 bits, but only about 5.2 of them survive case folding, so
+//! size keys accordingly (or hash them) when the directory may live on such a
+//! filesystem.
+//!
+//! # Reserved names on Windows
+//!
+//! Windows refuses to create files named `COM1` to `COM9` or `LPT1` to
+//! `LPT9`, in any case and with or without an extension. Each is a valid
+//! 4 character name, produced by encoding some 3 byte input. Inputs of other
+//! lengths never produce them, and the 3 character reserved names `CON`,
+//! `PRN`, `AUX` and `NUL` are never produced. When a directory may live on
+//! Windows, avoid 3 byte keys or add a fixed prefix to every name. An
+//! extension does not help.
+//!
+//! ```
+//! use radix64::filename;
+//!
+//! assert_eq!(filename::encode(b"\x08\xe3\x35"), "COM1");
+//! ```
+//!
+//! # Leading `-`
+//!
+//! Names start with `-` when the first 6 bits of the input are `111110`, as
+//! in the example above. Command line tools given such a name as an argument
+//! treat it as an option. Pass names after `--`, or as `./name`, when
+//! invoking such tools.
+//!
+//! # Truncation
+//!
+//! Filesystems commonly limit names to 255 bytes. [encode_capped](fn.encode_capped.html)
+//! limits the length of the name by truncating it, which discards the
+//! information needed to decode it. Each character kept retains 6 bits of the
+//! input, so truncating the name of a cryptographic hash is equivalent to
+//! truncating the hash: a 43 character name keeps 258 bits, more than a full
+//! SHA-256 digest. When the input is not already a uniformly distributed hash,
+//! names that share a long prefix collide after truncation. Hash such input
+//! first and use the hash as the name.
+use crate::{DecodeError, URL_SAFE_NO_PAD};
+use alloc::{string::String, vec::Vec};
+
+/// Encode the input as a file name.
+pub fn encode<I>(input: &I) -> String
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    URL_SAFE_NO_PAD.encode(input)
+}
+
+/// Encode the input as a file name of at most `max_len` characters. Names
+/// longer than `max_len` are truncated and can no longer be decoded. See the
+/// [module documentation](index.html#truncation) for the effect truncation
+/// has on collisions.
+///
+/// ```
+/// use radix64::filename;
+///
+/// let name = filename::encode_capped(&[0xff; 200], 255);
+/// assert_eq!(name.len(), 255);
+/// assert!(filename::encode(&[0xff; 200]).starts_with(&name));
+/// ```
+pub fn encode_capped<I>(input: &I, max_len: usize) -> String
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    let input = input.as_ref();
+    // Only the input covered by the first max_len characters is encoded.
+    let input_len = core::cmp::min(input.len(), max_len.div_ceil(4) * 3);
+    let mut name = URL_SAFE_NO_PAD.encode(&input[..input_len]);
+    name.truncate(max_len);
+    name
+}
+
+/// Decode a file name produced by `encode`.
+pub fn decode<I>(name: &I) -> Result<Vec<u8>, DecodeError>
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    URL_SAFE_NO_PAD.decode(name)
+}
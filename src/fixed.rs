@@ -0,0 +1,187 @@
+//! OutputBuffer implementations for the fixed capacity vectors and strings
+//! of the `arrayvec` and `heapless` crates.
+//!
+//! Like a `&mut [u8]`, the buffers panic if output would exceed their
+//! capacity when used with `encode_to` and `decode_to`. `try_encode_to` and
+//! `try_decode_to` return an error instead, without writing anything.
+//!
+//! The strings report invalid UTF-8 with a `DecodeUtf8Error`, which holds the
+//! decoded bytes, so they are only output buffers with the `alloc` feature.
+use crate::uninit::as_uninit_mut;
+#[cfg(feature = "alloc")]
+use crate::DecodeUtf8Error;
+use crate::{DecodeError, DecodeSliceError, OutputBuffer};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::mem::MaybeUninit;
+
+// Panics, before anything is written, if a fixed capacity buffer doesn't have
+// room for len more bytes.
+fn check_capacity(spare: usize, len: usize) {
+    if spare < len {
+        panic!(
+            "output buffer too small: {} bytes required, {} available",
+            len, spare
+        );
+    }
+}
+
+// Decode into the zeroed tail of a fixed capacity string. The tail is valid
+// UTF-8 again when this returns: either the decoded output was valid or the
+// tail is zeroed again. Returns the number of bytes to keep.
+#[cfg(feature = "alloc")]
+fn decode_into_str<F>(tail: &mut str, decode: F) -> Result<usize, DecodeUtf8Error>
+where
+    F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+{
+    // The decoders may write past the end of their output, so everything after
+    // the decoded bytes is zeroed before the tail is used as a str.
+    let bytes = unsafe { tail.as_bytes_mut() };
+    let result = decode(unsafe { as_uninit_mut(bytes) });
+    let written = *result.as_ref().unwrap_or(&0);
+    bytes[written..].iter_mut().for_each(|b| *b = 0);
+    result?;
+    if core::str::from_utf8(&bytes[..written]).is_err() {
+        let decoded = bytes[..written].to_vec();
+        bytes[..written].iter_mut().for_each(|b| *b = 0);
+        return Err(String::from_utf8(decoded).unwrap_err().into());
+    }
+    Ok(written)
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> OutputBuffer for arrayvec::ArrayVec<u8, CAP> {
+    type DecodeError = DecodeSliceError;
+
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]),
+    {
+        check_capacity(self.remaining_capacity(), len);
+        let start = self.len();
+        self.extend(core::iter::repeat_n(0, len));
+        // encode only writes initialized bytes.
+        encode(unsafe { as_uninit_mut(&mut self[start..]) });
+    }
+
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, DecodeSliceError>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+    {
+        check_capacity(self.remaining_capacity(), max_len);
+        let start = self.len();
+        self.extend(core::iter::repeat_n(0, max_len));
+        // decode only writes initialized bytes.
+        let result = decode(unsafe { as_uninit_mut(&mut self[start..]) });
+        self.truncate(start + *result.as_ref().unwrap_or(&0));
+        Ok(result?)
+    }
+
+    fn spare_capacity(&self) -> Option<usize> {
+        Some(self.remaining_capacity())
+    }
+}
+
+#[cfg(all(feature = "arrayvec", feature = "alloc"))]
+impl<const CAP: usize> OutputBuffer for arrayvec::ArrayString<CAP> {
+    type DecodeError = DecodeUtf8Error;
+
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]),
+    {
+        check_capacity(self.remaining_capacity(), len);
+        let start = self.len();
+        (0..len).for_each(|_| self.push('\0'));
+        // The encoded output is always ascii, as described in
+        // Config::encode_with_buffer, so the str remains valid.
+        encode(unsafe { as_uninit_mut(self[start..].as_bytes_mut()) });
+    }
+
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, DecodeUtf8Error>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+    {
+        check_capacity(self.remaining_capacity(), max_len);
+        let start = self.len();
+        (0..max_len).for_each(|_| self.push('\0'));
+        let result = decode_into_str(&mut self[start..], decode);
+        self.truncate(start + *result.as_ref().unwrap_or(&0));
+        result
+    }
+
+    fn spare_capacity(&self) -> Option<usize> {
+        Some(self.remaining_capacity())
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> OutputBuffer for heapless::Vec<u8, N> {
+    type DecodeError = DecodeSliceError;
+
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]),
+    {
+        let start = self.len();
+        check_capacity(self.capacity() - start, len);
+        // The capacity was checked above, so resizing can't fail.
+        let _ = self.resize(start + len, 0);
+        // encode only writes initialized bytes.
+        encode(unsafe { as_uninit_mut(&mut self[start..]) });
+    }
+
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, DecodeSliceError>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+    {
+        let start = self.len();
+        check_capacity(self.capacity() - start, max_len);
+        let _ = self.resize(start + max_len, 0);
+        // decode only writes initialized bytes.
+        let result = decode(unsafe { as_uninit_mut(&mut self[start..]) });
+        self.truncate(start + *result.as_ref().unwrap_or(&0));
+        Ok(result?)
+    }
+
+    fn spare_capacity(&self) -> Option<usize> {
+        Some(self.capacity() - self.len())
+    }
+}
+
+#[cfg(all(feature = "heapless", feature = "alloc"))]
+impl<const N: usize> OutputBuffer for heapless::String<N> {
+    type DecodeError = DecodeUtf8Error;
+
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]),
+    {
+        let start = self.len();
+        check_capacity(self.capacity() - start, len);
+        (0..len).for_each(|_| {
+            let _ = self.push('\0');
+        });
+        // The encoded output is always ascii, as described in
+        // Config::encode_with_buffer, so the str remains valid.
+        encode(unsafe { as_uninit_mut(self.as_mut_str()[start..].as_bytes_mut()) });
+    }
+
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, DecodeUtf8Error>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+    {
+        let start = self.len();
+        check_capacity(self.capacity() - start, max_len);
+        (0..max_len).for_each(|_| {
+            let _ = self.push('\0');
+        });
+        let result = decode_into_str(&mut self.as_mut_str()[start..], decode);
+        self.truncate(start + *result.as_ref().unwrap_or(&0));
+        result
+    }
+
+    fn spare_capacity(&self) -> Option<usize> {
+        Some(self.capacity() - self.len())
+    }
+}
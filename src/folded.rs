@@ -0,0 +1,111 @@
+//! Base64 property values in folded content lines, as used by vCard
+//! ([RFC 6350](https://tools.ietf.org/html/rfc6350#section-3.2)) and
+//! iCalendar ([RFC 5545](https://tools.ietf.org/html/rfc5545#section-3.1)).
+//!
+//! Content lines longer than 75 octets are folded by inserting a line break
+//! followed by a single space, which the reader removes before interpreting
+//! the line. Binary property values, such as a `PHOTO` or an `ATTACH`, are
+//! encoded using the [STD](../constant.STD.html) config.
+//!
+//! ```
+//! use radix64::folded;
+//!
+//! let photo = vec![0xff; 100];
+//! let line = folded::encode_property("PHOTO;ENCODING=b;TYPE=JPEG", &photo);
+//! assert!(line.split("\r\n").all(|line| line.len() <= 75));
+//!
+//! let value = &line[line.find(':').unwrap() + 1..];
+//! assert_eq!(folded::decode(value).unwrap(), photo);
+//! ```
+use crate::{DecodeError, STD};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+// The maximum length of a content line in octets, excluding the line break.
+const MAX_LINE_LEN: usize = 75;
+const FOLD: &str = "\r\n ";
+
+/// Encode the input and fold it into lines of at most 75 octets.
+/// `first_line_len` is the number of octets that precede the value on its
+/// first line, such as the property name, parameters, and `:`.
+///
+/// ```
+/// use radix64::folded;
+///
+/// let value = folded::encode(70, b"hello");
+/// assert_eq!(value, "aGVsb\r\n G8=");
+/// ```
+pub fn encode<I>(first_line_len: usize, input: &I) -> String
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    let encoded = STD.encode(input);
+    let mut folded = String::with_capacity(encoded.len() + encoded.len() / 24 + FOLD.len());
+    let mut rest = encoded.as_str();
+    let mut line_remaining = MAX_LINE_LEN.saturating_sub(first_line_len);
+    while rest.len() > line_remaining {
+        let (line, remaining) = rest.split_at(line_remaining);
+        folded.push_str(line);
+        folded.push_str(FOLD);
+        rest = remaining;
+        // Continuation lines begin with the space of the fold.
+        line_remaining = MAX_LINE_LEN - 1;
+    }
+    folded.push_str(rest);
+    folded
+}
+
+/// Build a folded content line consisting of the property name and any
+/// parameters, followed by `:` and the encoded input. No line break follows
+/// the final line.
+pub fn encode_property<I>(name: &str, input: &I) -> String
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    let mut line = String::with_capacity(name.len() + 1);
+    line.push_str(name);
+    line.push(':');
+    line.push_str(&encode(line.len(), input));
+    line
+}
+
+/// Remove the folding from a content line or property value. A line break
+/// (`"\r\n"`, or a bare `"\n"` as written by some producers) followed by a
+/// space or tab is removed along with that single whitespace character.
+///
+/// ```
+/// use radix64::folded;
+///
+/// assert_eq!(folded::unfold("aGVs\r\n bG8=\n\tIQ=="), "aGVsbG8=IQ==");
+/// ```
+pub fn unfold(text: &str) -> Cow<'_, str> {
+    let fold_len = |rest: &[u8]| match rest {
+        [b'\r', b'\n', b' ', ..] | [b'\r', b'\n', b'\t', ..] => 3,
+        [b'\n', b' ', ..] | [b'\n', b'\t', ..] => 2,
+        _ => 0,
+    };
+    let bytes = text.as_bytes();
+    let first_fold = match (0..bytes.len()).find(|&i| fold_len(&bytes[i..]) > 0) {
+        Some(idx) => idx,
+        None => return Cow::Borrowed(text),
+    };
+    let mut unfolded = String::with_capacity(text.len());
+    let mut start = 0;
+    let mut idx = first_fold;
+    while idx < bytes.len() {
+        match fold_len(&bytes[idx..]) {
+            0 => idx += 1,
+            len => {
+                unfolded.push_str(&text[start..idx]);
+                idx += len;
+                start = idx;
+            }
+        }
+    }
+    unfolded.push_str(&text[start..]);
+    Cow::Owned(unfolded)
+}
+
+/// Unfold and decode a base64 property value.
+pub fn decode(value: &str) -> Result<Vec<u8>, DecodeError> {
+    STD.decode(unfold(value).as_ref())
+}
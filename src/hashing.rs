@@ -0,0 +1,161 @@
+//! Adapters that hash the unencoded data streaming through the io adapters.
+use crate::decode::io::DecodeReader;
+use crate::encode::io::{EncodeWriter, FinishError};
+use crate::Config;
+use digest::{Digest, Output};
+use std::{fmt, io};
+
+/// A DecodeReader that hashes the decoded data as it's read.
+///
+/// This allows verifying a checksum of a base64 armored payload while decoding
+/// it, without a second pass over the decoded data.
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::{DecodeReader, HashingDecodeReader}};
+/// use sha2::{Digest, Sha256};
+/// use std::io::Read;
+///
+/// let reader = DecodeReader::new(STD, "aGVsbG8=".as_bytes());
+/// let mut reader = HashingDecodeReader::new(reader, Sha256::new());
+/// let mut decoded = Vec::new();
+/// reader.read_to_end(&mut decoded)?;
+/// assert_eq!(reader.finalize(), Sha256::digest(b"hello"));
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub struct HashingDecodeReader<C, R, D> {
+    inner: DecodeReader<C, R>,
+    digest: D,
+}
+
+impl<C, R, D> HashingDecodeReader<C, R, D>
+where
+    C: Config,
+    R: io::Read,
+    D: Digest,
+{
+    /// Create a new HashingDecodeReader that updates `digest` with the data
+    /// read from the provided DecodeReader.
+    pub fn new(inner: DecodeReader<C, R>, digest: D) -> Self {
+        HashingDecodeReader { inner, digest }
+    }
+
+    /// The digest of the data read so far.
+    pub fn digest(&self) -> &D {
+        &self.digest
+    }
+
+    /// Consume the HashingDecodeReader, returning the hash of the data read.
+    pub fn finalize(self) -> Output<D> {
+        self.digest.finalize()
+    }
+
+    /// Consume the HashingDecodeReader, returning the DecodeReader and digest.
+    pub fn into_inner(self) -> (DecodeReader<C, R>, D) {
+        (self.inner, self.digest)
+    }
+}
+
+impl<C, R, D> io::Read for HashingDecodeReader<C, R, D>
+where
+    C: Config,
+    R: io::Read,
+    D: Digest,
+{
+    fn read(&mut self, output: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(output)?;
+        self.digest.update(&output[..n]);
+        Ok(n)
+    }
+}
+
+/// An EncodeWriter that hashes the unencoded data as it's written.
+///
+/// ```
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// use radix64::{STD, io::{EncodeWriter, HashingEncodeWriter}};
+/// use sha2::{Digest, Sha256};
+/// use std::io::Write;
+///
+/// let writer = EncodeWriter::new(STD, Vec::new());
+/// let mut writer = HashingEncodeWriter::new(writer, Sha256::new());
+/// writer.write_all(b"hello")?;
+/// let (encoded, hash) = writer.finish()?;
+/// assert_eq!(encoded, b"aGVsbG8=");
+/// assert_eq!(hash, Sha256::digest(b"hello"));
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub struct HashingEncodeWriter<C, W, D>
+where
+    C: Config,
+    W: io::Write,
+{
+    inner: EncodeWriter<C, W>,
+    digest: D,
+}
+
+impl<C, W, D> HashingEncodeWriter<C, W, D>
+where
+    C: Config,
+    W: io::Write,
+    D: Digest,
+{
+    /// Create a new HashingEncodeWriter that updates `digest` with the data
+    /// written to the provided EncodeWriter.
+    pub fn new(inner: EncodeWriter<C, W>, digest: D) -> Self {
+        HashingEncodeWriter { inner, digest }
+    }
+
+    /// The digest of the data written so far.
+    pub fn digest(&self) -> &D {
+        &self.digest
+    }
+
+    /// Indicate that we are finished writing. See
+    /// [EncodeWriter::finish](struct.EncodeWriter.html#method.finish). On
+    /// success the underlying writer is returned along with the hash of the
+    /// data written.
+    #[allow(clippy::result_large_err)]
+    pub fn finish(self) -> Result<(W, Output<D>), FinishError<Self>> {
+        let HashingEncodeWriter { inner, digest } = self;
+        match inner.finish() {
+            Ok(writer) => Ok((writer, digest.finalize())),
+            Err(FinishError(inner, err)) => {
+                Err(FinishError(HashingEncodeWriter { inner, digest }, err))
+            }
+        }
+    }
+}
+
+impl<C, W, D> io::Write for HashingEncodeWriter<C, W, D>
+where
+    C: Config,
+    W: io::Write,
+    D: Digest,
+{
+    fn write(&mut self, input: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(input)?;
+        self.digest.update(&input[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<C, W, D> fmt::Debug for HashingEncodeWriter<C, W, D>
+where
+    C: Config + fmt::Debug,
+    W: io::Write,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("HashingEncodeWriter")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
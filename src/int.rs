@@ -0,0 +1,93 @@
+use crate::{Config, DecodeError};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{error, fmt};
+
+/// Errors that can occur when decoding an integer encoded by `encode_u64` or
+/// `encode_u128`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeIntError {
+    /// The input is not valid base64.
+    Decode(DecodeError),
+    /// The input decodes to no bytes or to more bytes than the integer holds.
+    InvalidLength {
+        /// The number of decoded bytes.
+        decoded_len: usize,
+        /// The size of the integer in bytes.
+        max_len: usize,
+    },
+    /// The decoded bytes begin with a zero byte, which the encoder strips.
+    /// Rejecting it ensures every integer has a single encoding.
+    LeadingZero,
+}
+
+impl fmt::Display for DecodeIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeIntError::Decode(ref err) => err.fmt(f),
+            DecodeIntError::InvalidLength {
+                decoded_len,
+                max_len,
+            } => write!(
+                f,
+                "decoded {} bytes, expected 1 to {} bytes",
+                decoded_len, max_len
+            ),
+            DecodeIntError::LeadingZero => write!(f, "decoded integer has a leading zero byte"),
+        }
+    }
+}
+
+impl error::Error for DecodeIntError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DecodeIntError::Decode(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for DecodeIntError {
+    fn from(err: DecodeError) -> Self {
+        DecodeIntError::Decode(err)
+    }
+}
+
+// Encode the big-endian bytes of an integer without its leading zero bytes,
+// keeping at least one byte so zero has an encoding.
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_uint<C>(config: C, bytes: &[u8]) -> String
+where
+    C: Config,
+{
+    let skip = bytes
+        .iter()
+        .take(bytes.len() - 1)
+        .take_while(|&&b| b == 0)
+        .count();
+    config.encode(&bytes[skip..])
+}
+
+// Decode the big-endian bytes of an integer encoded by encode_uint.
+pub(crate) fn decode_uint<C, const N: usize>(
+    config: C,
+    input: &[u8],
+) -> Result<[u8; N], DecodeIntError>
+where
+    C: Config,
+{
+    let decoded_len = crate::decode::checked_decoded_len(config, input)?;
+    if decoded_len == 0 || decoded_len > N {
+        return Err(DecodeIntError::InvalidLength {
+            decoded_len,
+            max_len: N,
+        });
+    }
+    let mut bytes = [0; N];
+    crate::decode::decode_slice(config, input, &mut bytes[N - decoded_len..])?;
+    if decoded_len > 1 && bytes[N - decoded_len] == 0 {
+        return Err(DecodeIntError::LeadingZero);
+    }
+    Ok(bytes)
+}
@@ -2,7 +2,7 @@
 //!
 //! ### Received base64 encoded data from stdin, decode it, and print it to stdout.
 //! ```
-//! # fn example() -> Result<(), Box<std::error::Error>> {
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! use radix64::{STD, io::DecodeReader};
 //! use std::io;
 //!
@@ -15,7 +15,7 @@
 //!
 //! ### Received data from stdin, encode it, and print it to stdout.
 //! ```
-//! # fn example() -> Result<(), Box<std::error::Error>> {
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! use radix64::{STD, io::EncodeWriter};
 //! use std::io;
 //! let mut dst = EncodeWriter::new(STD, io::stdout());
@@ -26,5 +26,134 @@
 //! # }
 //! ```
 
-pub use crate::decode::io::DecodeReader;
-pub use crate::encode::io::{EncodeWriter, FinishError};
+pub use crate::decode::armor::ArmorReader;
+pub use crate::decode::indexed::IndexedDecoder;
+pub use crate::decode::io::{copy_decode, copy_decode_with_progress, DecodeReader};
+pub use crate::encode::io::{copy_encode, copy_encode_with_progress, EncodeWriter, FinishError};
+pub use crate::encode::pipelined::PipelinedEncodeWriter;
+#[cfg(feature = "digest")]
+pub use crate::hashing::{HashingDecodeReader, HashingEncodeWriter};
+
+use alloc::boxed::Box;
+use std::{error, fmt, io};
+
+/// LimitExceeded is the error wrapped by an io::Error when a configured limit
+/// on the amount of data processed has been exceeded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitExceeded {
+    limit: u64,
+}
+
+impl LimitExceeded {
+    pub(crate) fn new(limit: u64) -> Self {
+        LimitExceeded { limit }
+    }
+
+    /// The limit that was exceeded.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "limit of {} bytes exceeded", self.limit)
+    }
+}
+
+impl error::Error for LimitExceeded {}
+
+// Read from the reader until the buffer is full or EOF is reached, returning
+// the number of bytes read.
+pub(crate) fn read_full<R>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize>
+where
+    R: io::Read + ?Sized,
+{
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(filled)
+}
+
+// Reports the total number of bytes processed to a callback each time at
+// least another interval of bytes has been processed.
+pub(crate) struct ProgressHook {
+    interval: u64,
+    processed: u64,
+    next_report: u64,
+    last_reported: Option<u64>,
+    callback: Box<dyn FnMut(u64) + Send + Sync>,
+}
+
+impl ProgressHook {
+    pub(crate) fn new<F>(interval: u64, callback: F) -> Self
+    where
+        F: FnMut(u64) + Send + Sync + 'static,
+    {
+        ProgressHook {
+            interval,
+            processed: 0,
+            next_report: interval,
+            last_reported: None,
+            callback: Box::new(callback),
+        }
+    }
+
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.processed += n as u64;
+        if n > 0 && self.processed >= self.next_report {
+            self.report();
+            self.next_report = self.processed.saturating_add(self.interval);
+        }
+    }
+
+    // Report the final total, unless it was the last value reported.
+    pub(crate) fn finish(&mut self) {
+        if self.last_reported != Some(self.processed) {
+            self.report();
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.processed = 0;
+        self.next_report = self.interval;
+        self.last_reported = None;
+    }
+
+    fn report(&mut self) {
+        (self.callback)(self.processed);
+        self.last_reported = Some(self.processed);
+    }
+}
+
+impl fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ProgressHook")
+            .field("interval", &self.interval)
+            .field("processed", &self.processed)
+            .finish()
+    }
+}
+
+// A reader that advances a ProgressHook by the number of bytes read.
+pub(crate) struct ProgressReader<'a, R: ?Sized> {
+    pub(crate) inner: &'a mut R,
+    pub(crate) hook: ProgressHook,
+}
+
+impl<R> io::Read for ProgressReader<'_, R>
+where
+    R: io::Read + ?Sized,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hook.advance(n);
+        Ok(n)
+    }
+}
@@ -34,7 +34,7 @@
 //!
 //! Decode data from stdin.
 //! ```
-//! # fn example() -> Result<(), Box<std::error::Error>> {
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! # use std::io::Read;
 //! use radix64::{STD, io::DecodeReader};
 //! let mut reader = DecodeReader::new(STD, std::io::stdin());
@@ -46,7 +46,7 @@
 //!
 //! Encode data to stdout.
 //! ```
-//! # fn example() -> Result<(), Box<std::error::Error>> {
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! # use std::io::Write;
 //! use radix64::{STD, io::EncodeWriter};
 //! let mut writer = EncodeWriter::new(STD, std::io::stdout());
@@ -77,6 +77,17 @@
 //! | `decode_with_buffer` | Returns a `&[u8]` within the buffer | Only if the buffer needs to grow |
 //! | `decode_slice`       | Writes to provided `&mut [u8]`      | Never                            |
 //!
+//! The allocating methods require the `alloc` feature, and the io adapters
+//! and runtime CPU feature detection require the `std` feature. Both are
+//! enabled by default. Without them the crate is `no_std` and only the
+//! methods that never allocate, such as `encode_slice`, `decode_slice` and
+//! `encode_to` with a slice or fixed capacity buffer, are available. Size the
+//! output with `encoded_output_len` and `maximum_decoded_output_len`.
+//!
+//! ```toml
+//! radix64 = { version = "0.6", default-features = false, features = ["simd"] }
+//! ```
+//!
 //! # Performance
 //!
 //! The provided configurations `STD`, `URL_SAFE`, and `CRYPT` (along with the
@@ -121,15 +132,118 @@
 //! | 32 bytes        | 969 MiB/s          | 976 MiB/s         |
 //! | 128 bytes       | 1.59 GiB/s         | 1.55 GiB/s        |
 //! | 8192 bytes      | 2.04 GiB/s         | 1.98 GiB/s        |
+//!
+//! # Cargo Features
+//!
+//! The `std` feature (enabled by default) adds the [io](io/index.html) module,
+//! runtime CPU feature detection and `BufferPool`. It implies the `alloc`
+//! feature, which adds every method and module that allocates. Without `std`
+//! a SIMD backend is only used when it's enabled at compile time, for example
+//! with `-C target-feature=+avx2`.
+//!
+//! The `simd` feature (enabled by default) turns on every SIMD backend. Each
+//! backend can also be enabled individually, which allows compiling out
+//! kernels that will never run on the target platform.
+//!
+//! | Feature      | Backend                              |
+//! | ------------ | ------------------------------------ |
+//! | `avx2`       | AVX2 on x86 and x86_64               |
+//! | `avx512vbmi` | AVX-512 VBMI on x86 and x86_64       |
+//! | `ssse3`      | SSSE3 on x86 and x86_64              |
+//! | `neon`       | NEON on aarch64 (reserved)           |
+//! | `wasm-simd`  | simd128 on wasm32 (reserved)         |
+//!
+//! Backends marked as reserved do not provide any kernels yet and fall back to
+//! the scalar implementation. The `avx512vbmi` feature implies `avx2`, which
+//! it uses for input too short to fill a 512-bit block. On x86 and x86_64 the `avx2` feature also adds
+//! the [arch](arch/index.html) module, which exposes the AVX2 block kernels for
+//! use in fused SIMD pipelines.
+//!
+//! The optional `secrecy` feature adds `decode_secret`, which decodes into a
+//! [`secrecy::SecretVec`](https://docs.rs/secrecy/0.8/secrecy/type.SecretVec.html)
+//! that is zeroized when dropped.
+//!
+//! The optional `digest` feature adds [HashingDecodeReader](io/struct.HashingDecodeReader.html)
+//! and [HashingEncodeWriter](io/struct.HashingEncodeWriter.html), which hash
+//! the unencoded data while streaming it through base64.
+//!
+//! The optional `tokio` feature adds the [offload](offload/index.html) module
+//! for encoding and decoding large payloads from async code.
+//!
+//! The optional `serde` feature implements `Serialize` and `Deserialize` for
+//! [Base64](struct.Base64.html), which is represented as a base64 string, and
+//! adds the [serde](serde/index.html) module. The
+//! `axum` and `actix-web` features enable `serde` and allow Base64 to be used
+//! as a request extractor and response in those frameworks. See the
+//! [web](web/index.html) module. The optional `borsh` and `rkyv` features
+//! implement those binary formats for Base64, storing the encoded string.
+//!
+//! The optional `bytes` feature adds `encode_buf` and `decode_buf`, which
+//! accept any [`bytes::Buf`](https://docs.rs/bytes/1/bytes/buf/trait.Buf.html).
+//!
+//! The optional `arrayvec` and `heapless` features implement
+//! [OutputBuffer](trait.OutputBuffer.html) for the fixed capacity vectors and
+//! strings of those crates, for encoding and decoding without allocating.
+//! Use `try_encode_to` and `try_decode_to` to get an error rather than a
+//! panic when the capacity is exceeded.
+//!
+//! The optional `bytemuck` feature adds `encode_pod_slice` and
+//! `decode_pod_vec`, which encode and decode slices of
+//! [`bytemuck::Pod`](https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html)
+//! values, such as `&[f32]`, as their in-memory bytes.
+//!
+//! The optional `rayon` feature adds `encode_parallel` and `decode_parallel`,
+//! which split large inputs across the
+//! [rayon](https://docs.rs/rayon/1) thread pool.
+//!
+//! The optional `clap` feature adds the [cli](cli/index.html) module, which
+//! provides a value parser for base64 command line arguments.
+//!
+//! The `unstable-block` feature adds the [block](block/index.html) module,
+//! which exposes the block encoder and decoder traits for benchmarking and
+//! for checking kernels written outside this crate. It is not covered by
+//! semver.
+//!
+//! The optional `testing` feature adds the [testing](testing/index.html)
+//! module, which checks that the SIMD backends selected on the host agree with
+//! the scalar implementation.
 
+#![no_std]
 #![deny(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+pub use crate::backend::{active_backend, reset_backend, set_backend, Backend, UnsupportedBackend};
 #[doc(inline)]
 pub use crate::configs::CustomConfig;
-pub use crate::decode::DecodeError;
+#[cfg(feature = "alloc")]
+pub use crate::decode::DecodeUtf8Error;
+pub use crate::decode::{DecodeError, DecodeSliceError};
 pub use crate::display::Display;
+#[cfg(feature = "std")]
+pub use crate::display::DisplayFromReader;
+#[cfg(feature = "alloc")]
+pub use crate::dyn_config::{decode_any, DynConfig};
+pub use crate::encode::iter::{EncodeBytes, EncodeChars};
+pub use crate::encode::BufferTooSmall;
+pub use crate::int::DecodeIntError;
+#[cfg(feature = "alloc")]
+pub use crate::normalize::normalize;
+pub use crate::output::OutputBuffer;
+#[cfg(feature = "bytemuck")]
+pub use crate::pod::PodDecodeError;
+#[cfg(feature = "std")]
+pub use crate::pool::{BufferPool, PooledBuffer};
+#[cfg(feature = "alloc")]
+pub use crate::wrapper::Base64;
 
 use crate::configs::{Crypt, Fast, Std, StdNoPad, UrlSafe, UrlSafeNoPad};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::mem::MaybeUninit;
 
 /// Encode and Decode using the standard characer set with padding.
 ///
@@ -164,13 +278,23 @@ pub const CRYPT: Crypt = Crypt;
 pub const FAST: Fast = Fast;
 
 mod private {
+    use crate::configs::DecodePadding;
     use crate::decode::block::IntoBlockDecoder;
     use crate::encode::block::IntoBlockEncoder;
     use crate::u6::U6;
+    use crate::wrap::LineWrap;
     pub trait SealedConfig: IntoBlockEncoder + IntoBlockDecoder {
         /// Encodes the six bits of input into the 8 bits of output.
         fn encode_u6(self, input: U6) -> u8;
 
+        /// A table of the two characters encoding every 12-bit value, the
+        /// first in the high byte. Configs that provide one encode two
+        /// characters per table lookup in the scalar encoder.
+        #[inline]
+        fn encode_pair_table(self) -> Option<&'static [u16; 4096]> {
+            None
+        }
+
         /// Decodes the encoded byte into six bits matching the original input.
         /// config::INVALID_VALUE is returned on invalid input.
         fn decode_u8(self, input: u8) -> u8;
@@ -178,6 +302,23 @@ mod private {
         /// Indicates whether this configuration uses padding and if so, which
         /// character to use.
         fn padding_byte(self) -> Option<u8>;
+
+        /// Indicates how padding is treated when decoding. By default padding
+        /// is required when the config pads on encode and forbidden otherwise.
+        #[inline]
+        fn decode_padding(self) -> DecodePadding {
+            match self.padding_byte() {
+                Some(padding) => DecodePadding::Required(padding),
+                None => DecodePadding::Forbidden,
+            }
+        }
+
+        /// Indicates whether encoded output is split into lines and if so, the
+        /// line length and separator.
+        #[inline]
+        fn line_wrap(self) -> Option<LineWrap> {
+            None
+        }
     }
 }
 
@@ -187,17 +328,40 @@ mod private {
 /// configuration. This trait is sealed and not intended to be implemented
 /// outside of this crate. Custom configurations can be defined using
 /// [CustomConfig](struct.CustomConfig.html).
+///
+/// Config is implemented for references to any Config, so generic code works
+/// the same with builtin configs, references to them, and references to a
+/// `CustomConfig`.
+///
+/// ```
+/// use radix64::{Config, CustomConfig, STD};
+///
+/// fn encode_hello<C: Config>(config: C) -> String {
+///     config.encode("hello")
+/// }
+///
+/// let custom = CustomConfig::with_alphabet(
+///     "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+/// )
+/// .build()
+/// .unwrap();
+/// assert_eq!(encode_hello(STD), "aGVsbG8=");
+/// assert_eq!(encode_hello(&STD), "aGVsbG8=");
+/// assert_eq!(encode_hello(&custom), "aGVsbG8=");
+/// assert_eq!(encode_hello(&&custom), "aGVsbG8=");
+/// ```
 pub trait Config: Copy + private::SealedConfig {
     /// Encode the provided input into a String.
+    #[cfg(feature = "alloc")]
     #[inline]
     fn encode<I>(self, input: &I) -> String
     where
         I: AsRef<[u8]> + ?Sized,
     {
         let input = input.as_ref();
-        let mut output = vec![0; input.len() * 4 / 3 + 3];
-        let bytes_written = crate::encode::encode_slice(self, input, output.as_mut_slice());
-        output.truncate(bytes_written);
+        let mut output = Vec::with_capacity(crate::encode::max_encoded_len(self, input.len()));
+        let bytes_written = crate::encode::encode_uninit(self, input, output.spare_capacity_mut());
+        unsafe { output.set_len(bytes_written) };
         // The builtin alphabets are all ascii and the CustomConfigBuilder
         // ensures any custom alphabets only contain ascii characters as well.
         // Therefore we can bypass the utf8 check on the encoded output.
@@ -205,6 +369,36 @@ pub trait Config: Copy + private::SealedConfig {
         unsafe { String::from_utf8_unchecked(output) }
     }
 
+    /// Encode the provided input into a String, unless the encoded output
+    /// would be longer than `max_encoded_len` bytes. The limit is checked
+    /// before any work is done, so oversized input is rejected without
+    /// encoding or allocating. This suits outputs with a fixed maximum size,
+    /// such as a database column or protocol field.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// assert_eq!(STD.encode_capped("hello", 8).unwrap(), "aGVsbG8=");
+    /// let err = STD.encode_capped("hello!", 7).unwrap_err();
+    /// assert_eq!(err.limit(), 7);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn encode_capped<I>(
+        self,
+        input: &I,
+        max_encoded_len: usize,
+    ) -> Result<String, crate::io::LimitExceeded>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        if crate::encode::encoded_len_u64(self, input.len() as u64) > max_encoded_len as u64 {
+            return Err(crate::io::LimitExceeded::new(max_encoded_len as u64));
+        }
+        Ok(self.encode(input))
+    }
+
     /// Encode the provided input into the provided buffer, returning a &str of
     /// the encoded input. The returned &str is a view into the beginning of the
     /// provided buffer that contains the encoded data. This method *overwrites*
@@ -214,51 +408,510 @@ pub trait Config: Copy + private::SealedConfig {
     /// each invocation and will only be resized when necessary. Any data in the
     /// buffer outside the range of the returned &str is not part of the encoded
     /// output and should be ignored.
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn encode_with_buffer<'i, 'b, I>(self, input: &'i I, buffer: &'b mut Vec<u8>) -> &'b str
+    fn encode_with_buffer<'b, I>(self, input: &I, buffer: &'b mut Vec<u8>) -> &'b str
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        let input = input.as_ref();
-        let output_size = input.len() * 4 / 3 + 3;
-        if output_size > buffer.len() {
-            buffer.resize(output_size, 0);
-        }
-        let num_encoded_bytes = crate::encode::encode_slice(self, input, buffer.as_mut_slice());
-        let encoded = &buffer[..num_encoded_bytes];
+        buffer.clear();
+        self.encode_to(input, buffer);
+        let encoded = buffer.as_slice();
         // The builtin alphabets are all ascii and the CustomConfigBuilder
         // ensures any custom alphabets only contain ascii characters as well.
         // Therefore we can bypass the utf8 check on the encoded output.
         debug_assert!(encoded.iter().all(u8::is_ascii));
-        unsafe { std::str::from_utf8_unchecked(encoded) }
+        unsafe { core::str::from_utf8_unchecked(encoded) }
+    }
+
+    /// Encode the provided input into the provided String, returning a &str of
+    /// the encoded input. This behaves like `encode_with_buffer` for callers
+    /// that ultimately need the encoded data stored in a String. The contents
+    /// of the String are *replaced* by the encoded output, while its allocation
+    /// is reused and only grows when necessary.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let mut buffer = String::new();
+    /// for input in &["hello", "world!"] {
+    ///     let encoded = STD.encode_with_str_buffer(input, &mut buffer);
+    ///     assert_eq!(encoded, STD.encode(input));
+    /// }
+    /// assert_eq!(buffer, "d29ybGQh");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_with_str_buffer<'b, I>(self, input: &I, buffer: &'b mut String) -> &'b str
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        buffer.clear();
+        self.encode_to(input, buffer);
+        buffer.as_str()
+    }
+
+    /// Encode the provided input, appending the encoded output to the provided
+    /// String. Unlike `encode_with_str_buffer` the existing contents of the
+    /// String are kept, which allows building composite values such as
+    /// headers or data URIs without encoding into a separate String first.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let mut header = String::from("Basic ");
+    /// STD.encode_append("user:pass", &mut header);
+    /// assert_eq!(header, "Basic dXNlcjpwYXNz");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_append<I>(self, input: &I, buffer: &mut String)
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        self.encode_to(input, buffer);
+    }
+
+    /// Encode the provided input into an `Arc<str>`. The encoded output is
+    /// written directly into a shared allocation of exactly the encoded
+    /// length, avoiding the copy of converting a String. This suits caches
+    /// that hand the same encoded value to many tasks.
+    ///
+    /// ```
+    /// use radix64::STD;
+    /// use std::sync::Arc;
+    ///
+    /// let encoded: Arc<str> = STD.encode_arc("hello");
+    /// assert_eq!(&*encoded, "aGVsbG8=");
+    /// ```
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[inline]
+    fn encode_arc<I>(self, input: &I) -> alloc::sync::Arc<str>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::encode::encode_arc(self, input.as_ref())
+    }
+
+    /// Encode the provided input into an `Rc<str>`. This is the single
+    /// threaded counterpart of `encode_arc`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_rc<I>(self, input: &I) -> alloc::rc::Rc<str>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::encode::encode_rc(self, input.as_ref())
+    }
+
+    /// Encode the provided input, appending the encoded output to any
+    /// [OutputBuffer](trait.OutputBuffer.html), such as a `Vec<u8>`, a
+    /// `String` or a `&mut [u8]`. Returns the number of bytes written. A
+    /// slice is advanced past the output and panics, before writing anything,
+    /// if it's too small.
+    ///
+    /// ```
+    /// use radix64::{Config, STD};
+    ///
+    /// let mut s = String::from("token=");
+    /// STD.encode_to("hello", &mut s);
+    /// assert_eq!(s, "token=aGVsbG8=");
+    ///
+    /// let mut buf = [0; 16];
+    /// let mut remaining = &mut buf[..];
+    /// STD.encode_to("hello", &mut remaining);
+    /// STD.encode_to("world", &mut remaining);
+    /// assert_eq!(remaining.len(), 0);
+    /// assert_eq!(&buf, b"aGVsbG8=d29ybGQ=");
+    /// ```
+    #[inline]
+    fn encode_to<I, O>(self, input: &I, output: &mut O) -> usize
+    where
+        I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+    {
+        let input = input.as_ref();
+        let len = self.encoded_output_len(input.len());
+        output.append_encoded(len, |buffer| {
+            crate::encode::encode_uninit(self, input, buffer);
+        });
+        len
+    }
+
+    /// Encode the provided input, appending the encoded output to any
+    /// [OutputBuffer](trait.OutputBuffer.html) like `encode_to`. Unlike
+    /// `encode_to` this returns an error, before writing anything, if the
+    /// output would exceed the capacity of a fixed size buffer such as a
+    /// slice or, with the `arrayvec` and `heapless` features, their vectors
+    /// and strings.
+    ///
+    /// ```
+    /// use radix64::{Config, STD};
+    ///
+    /// let mut buf = [0; 4];
+    /// let err = STD.try_encode_to("hello", &mut &mut buf[..]).unwrap_err();
+    /// assert_eq!(err.required(), 8);
+    /// ```
+    #[inline]
+    fn try_encode_to<I, O>(self, input: &I, output: &mut O) -> Result<usize, BufferTooSmall>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+    {
+        let input = input.as_ref();
+        let len = self.encoded_output_len(input.len());
+        match output.spare_capacity() {
+            Some(spare) if spare < len => Err(BufferTooSmall::new(len, spare)),
+            _ => Ok(self.encode_to(input, output)),
+        }
     }
 
     /// Encode the provided input into the provided output slice. The slice must
-    /// be large enough to contain the encoded output and panics if it's not.
-    /// Use `input.len() * 4 / 3 + 3` as a conservative estimate. It returns the
-    /// number of bytes of encoded output written to the output slice. This
-    /// method allows for the most control over memory placement, but
-    /// `encode_with_buffer` is typically more ergonomic and just as performant.
+    /// be large enough to contain the encoded output and panics, before
+    /// writing anything, if it's not. The panic message states the required
+    /// and provided sizes. Use `encoded_output_len` to size the slice exactly.
+    /// It returns the number of bytes of encoded output written to the output
+    /// slice. This method allows for the most control over memory placement,
+    /// but `encode_with_buffer` is typically more ergonomic and just as
+    /// performant.
     #[inline]
     fn encode_slice<I>(self, input: &I, output: &mut [u8]) -> usize
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        crate::encode::encode_slice(self, input.as_ref(), output)
+        self.encode_to(input, &mut &mut *output)
+    }
+
+    /// Encode a fixed size input, such as a key or hash, into a fixed size
+    /// array on the stack. Stable Rust can't compute the output length in the
+    /// signature, so it's a second const parameter, usually inferred from the
+    /// destination's type. It must equal `encoded_output_len(N)`, otherwise
+    /// this panics.
+    ///
+    /// ```
+    /// use radix64::{Config, STD, URL_SAFE_NO_PAD};
+    ///
+    /// let key = [0xfb; 16];
+    /// let encoded: [u8; 24] = STD.encode_array(&key);
+    /// assert_eq!(&encoded, b"+/v7+/v7+/v7+/v7+/v7+w==");
+    /// let encoded: [u8; 22] = URL_SAFE_NO_PAD.encode_array(&key);
+    /// assert_eq!(&encoded, b"-_v7-_v7-_v7-_v7-_v7-w");
+    /// ```
+    #[inline]
+    fn encode_array<const N: usize, const M: usize>(self, input: &[u8; N]) -> [u8; M] {
+        crate::encode::encode_array(self, input)
+    }
+
+    /// Encode the provided input into the provided output slice, returning the
+    /// number of bytes written. Unlike `encode_slice` this returns an error,
+    /// before writing anything, if the slice is too small. The error holds the
+    /// required length so the caller can provide a larger slice and retry.
+    ///
+    /// ```
+    /// use radix64::{Config, STD};
+    ///
+    /// let mut output = [0; 4];
+    /// let err = STD.try_encode_slice("hello", &mut output).unwrap_err();
+    /// assert_eq!(err.required(), 8);
+    /// let mut output = vec![0; err.required()];
+    /// assert_eq!(STD.try_encode_slice("hello", &mut output), Ok(8));
+    /// assert_eq!(output, b"aGVsbG8=");
+    /// ```
+    #[inline]
+    fn try_encode_slice<I>(self, input: &I, output: &mut [u8]) -> Result<usize, BufferTooSmall>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::encode::try_encode_slice(self, input.as_ref(), output)
+    }
+
+    /// Encode the provided input into the provided uninitialized output slice,
+    /// returning a &str of the encoded output at the beginning of the slice.
+    /// This behaves like `encode_slice`, including panicking if the slice is
+    /// too small, but allows encoding into memory that has not been
+    /// initialized, such as the spare capacity of a `Vec` or a slot in an
+    /// arena, without zeroing it first.
+    ///
+    /// ```
+    /// use radix64::{Config, STD};
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut output = [MaybeUninit::uninit(); 8];
+    /// assert_eq!(STD.encode_slice_uninit("hello", &mut output), "aGVsbG8=");
+    /// ```
+    #[inline]
+    fn encode_slice_uninit<'o, I>(self, input: &I, output: &'o mut [MaybeUninit<u8>]) -> &'o str
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        let num_encoded_bytes = crate::encode::encode_uninit(self, input.as_ref(), output);
+        // The encoders initialized the bytes they report as written.
+        let encoded = unsafe { crate::uninit::assume_init_mut(&mut output[..num_encoded_bytes]) };
+        // The encoded output is always ascii, as described in
+        // encode_with_buffer.
+        debug_assert!(encoded.iter().all(u8::is_ascii));
+        unsafe { core::str::from_utf8_unchecked(encoded) }
+    }
+
+    /// Encode the provided input, appending the encoded output to any
+    /// collection that implements `Extend<u8>`, such as a `VecDeque<u8>`.
+    /// The output is produced in fixed size pieces without any intermediate
+    /// allocation.
+    ///
+    /// ```
+    /// use radix64::STD;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut output: VecDeque<u8> = b"data: ".iter().cloned().collect();
+    /// STD.encode_into("hello", &mut output);
+    /// assert_eq!(output, b"data: aGVsbG8=");
+    /// ```
+    #[inline]
+    fn encode_into<I, T>(self, input: &I, output: &mut T)
+    where
+        I: AsRef<[u8]> + ?Sized,
+        T: Extend<u8>,
+    {
+        crate::encode::encode_into(self, input.as_ref(), output)
+    }
+
+    /// Returns an iterator over the characters of the encoded input. The
+    /// input is encoded lazily, a small piece at a time, as the iterator
+    /// advances, without allocating. This suits sinks that consume output a
+    /// character at a time.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let mut chars = STD.encode_iter("hello");
+    /// assert_eq!(chars.len(), 8);
+    /// assert_eq!(chars.next(), Some('a'));
+    /// assert_eq!(chars.collect::<String>(), "GVsbG8=");
+    /// ```
+    #[inline]
+    fn encode_iter<I>(self, input: &I) -> EncodeChars<'_, Self>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        EncodeChars::new(self, input.as_ref())
+    }
+
+    /// Returns an iterator over the bytes of the encoded input. This behaves
+    /// like `encode_iter`, yielding each character as a `u8`.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let encoded: Vec<u8> = STD.encode_bytes_iter("hello").collect();
+    /// assert_eq!(encoded, b"aGVsbG8=");
+    /// ```
+    #[inline]
+    fn encode_bytes_iter<I>(self, input: &I) -> EncodeBytes<'_, Self>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        EncodeBytes::new(self, input.as_ref())
+    }
+
+    /// Encode the provided input and write the encoded output to `writer`,
+    /// returning the number of encoded bytes written. This is a shorthand for
+    /// [copy_encode](io/fn.copy_encode.html) with the input as the reader.
+    ///
+    /// ```
+    /// # fn example() -> std::io::Result<()> {
+    /// use radix64::STD;
+    ///
+    /// let mut output = Vec::new();
+    /// STD.encode_to_writer("my message", &mut output)?;
+    /// assert_eq!(output, b"bXkgbWVzc2FnZQ==");
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn encode_to_writer<I, W>(self, input: &I, writer: &mut W) -> std::io::Result<u64>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        W: std::io::Write + ?Sized,
+    {
+        crate::io::copy_encode(self, &mut input.as_ref(), writer)
+    }
+
+    /// Encode the provided input and write the encoded output to any
+    /// `fmt::Write`, such as a String or a `fmt::Formatter`. The output is
+    /// produced in fixed size pieces without any intermediate allocation.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let mut output = String::from("data: ");
+    /// STD.encode_to_fmt("hello", &mut output).unwrap();
+    /// assert_eq!(output, "data: aGVsbG8=");
+    /// ```
+    #[inline]
+    fn encode_to_fmt<I, W>(self, input: &I, writer: &mut W) -> core::fmt::Result
+    where
+        I: AsRef<[u8]> + ?Sized,
+        W: core::fmt::Write + ?Sized,
+    {
+        let mut line_state = crate::wrap::LineState::default();
+        crate::display::write_encoded(self, input.as_ref(), &mut line_state, writer)
+    }
+
+    /// Encode input that is split across two slices, such as those returned by
+    /// `VecDeque::as_slices`. The output is the same as encoding the
+    /// concatenation of the slices, without first copying them together.
+    ///
+    /// ```
+    /// use radix64::STD;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut ring: VecDeque<u8> = VecDeque::with_capacity(8);
+    /// ring.extend(b"xxxhel");
+    /// ring.drain(..3);
+    /// ring.extend(b"lo");
+    /// let (first, second) = ring.as_slices();
+    /// assert_eq!(STD.encode_slices(first, second), "aGVsbG8=");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_slices<A, B>(self, first: &A, second: &B) -> String
+    where
+        A: AsRef<[u8]> + ?Sized,
+        B: AsRef<[u8]> + ?Sized,
+    {
+        let (first, second) = (first.as_ref(), second.as_ref());
+        let mut encoder = crate::encode::SegmentEncoder::new(self, first.len() + second.len());
+        encoder.push(first);
+        encoder.push(second);
+        encoder.finish()
+    }
+
+    /// Encode the remaining contents of a `bytes::Buf`, which may be stored in
+    /// any number of non-contiguous chunks. The buf is advanced to its end.
+    #[cfg(feature = "bytes")]
+    fn encode_buf<B>(self, mut buf: B) -> String
+    where
+        B: bytes::Buf,
+    {
+        let mut encoder = crate::encode::SegmentEncoder::new(self, buf.remaining());
+        while buf.has_remaining() {
+            let chunk = buf.chunk();
+            let chunk_len = chunk.len();
+            encoder.push(chunk);
+            buf.advance(chunk_len);
+        }
+        encoder.finish()
+    }
+
+    /// Encode the provided input into pieces of at most `max_chars`
+    /// characters each. Every piece encodes a whole number of 3 byte chunks
+    /// (except possibly the last) so each piece can be decoded on its own, and
+    /// concatenating the decoded pieces yields the original input. This is
+    /// useful for transports with a size limit on each message. Use
+    /// `decode_chunked` to decode and concatenate the pieces.
+    ///
+    /// Panics if `max_chars` is too small to hold a single 4 character chunk
+    /// (plus any line separators).
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let pieces = STD.encode_chunked("hello world", 8);
+    /// assert_eq!(pieces, vec!["aGVsbG8g", "d29ybGQ="]);
+    /// assert_eq!(STD.decode_chunked(&pieces).unwrap(), b"hello world");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn encode_chunked<I>(self, input: &I, max_chars: usize) -> Vec<String>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        let wrapped_len = |encoded_len: usize| match self.line_wrap() {
+            Some(wrap) => encoded_len + wrap.separators_len(encoded_len),
+            None => encoded_len,
+        };
+        let mut bytes_per_piece = max_chars / 4 * 3;
+        while bytes_per_piece > 0 && wrapped_len(bytes_per_piece / 3 * 4) > max_chars {
+            bytes_per_piece -= 3;
+        }
+        assert!(
+            bytes_per_piece > 0,
+            "max_chars of {} cannot hold a single encoded chunk",
+            max_chars
+        );
+        input
+            .chunks(bytes_per_piece)
+            .map(|piece| self.encode(piece))
+            .collect()
+    }
+
+    /// Decode pieces produced by `encode_chunked`, concatenating the decoded
+    /// output. Each piece must be independently decodable.
+    #[cfg(feature = "alloc")]
+    fn decode_chunked<P, I>(self, pieces: P) -> Result<Vec<u8>, DecodeError>
+    where
+        P: IntoIterator<Item = I>,
+        I: AsRef<[u8]>,
+    {
+        let mut output = Vec::new();
+        for piece in pieces {
+            self.decode_into(&piece, &mut output)?;
+        }
+        Ok(output)
     }
 
     /// Decode the provided input.
+    #[cfg(feature = "alloc")]
     #[inline]
     fn decode<I>(self, input: &I) -> Result<Vec<u8>, DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
         let mut output = Vec::new();
-        let decoded_len = self.decode_with_buffer(input, &mut output)?.len();
-        output.truncate(decoded_len);
+        self.decode_with_buffer(input, &mut output)?;
         Ok(output)
     }
 
+    /// Decode the provided input and check that the decoded data is valid
+    /// UTF-8, returning it as a String.
+    ///
+    /// ```
+    /// use radix64::{DecodeUtf8Error, STD};
+    ///
+    /// assert_eq!(STD.decode_utf8("aGVsbG8=").unwrap(), "hello");
+    /// match STD.decode_utf8("/w==") {
+    ///     Err(DecodeUtf8Error::Utf8(err)) => assert_eq!(err.into_bytes(), [0xff]),
+    ///     other => panic!("unexpected result: {:?}", other),
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_utf8<I>(self, input: &I) -> Result<String, DecodeUtf8Error>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        Ok(String::from_utf8(self.decode(input)?)?)
+    }
+
+    /// Decode the provided str. This behaves like `decode` but first checks
+    /// whether the input is entirely ascii, which can be done far faster than
+    /// decoding. Input containing non-ascii characters, such as a corrupted
+    /// value extracted from JSON, is rejected without being decoded.
+    ///
+    /// ```
+    /// use radix64::{DecodeError, STD};
+    ///
+    /// assert_eq!(STD.decode_str("aGVsbG8=").unwrap(), b"hello");
+    /// assert_eq!(STD.decode_str("aGVsb\u{e9}="), Err(DecodeError::InvalidByte(0xc3)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_str(self, input: &str) -> Result<Vec<u8>, DecodeError> {
+        crate::decode::decode_str(self, input)
+    }
+
     /// Decode the provided input into the provided buffer, returning a &[u8] of
     /// the decoded input. The returned &[u8] is a view into the beginning of the
     /// provided buffer that contains the decoded data. This method *overwrites*
@@ -268,36 +921,766 @@ pub trait Config: Copy + private::SealedConfig {
     /// each invocation and will only be resized when necessary. Any data in the
     /// buffer outside the range of the returned &[u8] is not part of the decoded
     /// output and should be ignored.
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn decode_with_buffer<'i, 'b, I>(
+    fn decode_with_buffer<'b, I>(
         self,
-        input: &'i I,
+        input: &I,
         buffer: &'b mut Vec<u8>,
     ) -> Result<&'b [u8], DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        buffer.clear();
+        self.decode_to(input, buffer)?;
+        Ok(buffer.as_slice())
+    }
+
+    /// Decode the provided input, appending the decoded output to the provided
+    /// Vec. Unlike `decode_with_buffer` the existing contents of the Vec are
+    /// kept, which allows decoding several values into one contiguous buffer.
+    /// On error the Vec is left unchanged.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let mut buffer = Vec::new();
+    /// STD.decode_append("aGVsbG8=", &mut buffer).unwrap();
+    /// STD.decode_append("d29ybGQ=", &mut buffer).unwrap();
+    /// assert_eq!(buffer, b"helloworld");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_append<I>(self, input: &I, buffer: &mut Vec<u8>) -> Result<(), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        self.decode_to(input, buffer).map(|_| ())
+    }
+
+    /// Decode the provided buffer in place, returning the decoded output at
+    /// the beginning of the buffer. Decoded output is always shorter than the
+    /// encoded input, so no other output buffer is needed. On error the
+    /// contents of the buffer are unspecified.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let mut buffer = *b"aGVsbG8=";
+    /// assert_eq!(STD.decode_in_place(&mut buffer).unwrap(), b"hello");
+    /// ```
+    #[inline]
+    fn decode_in_place(self, buffer: &mut [u8]) -> Result<&[u8], DecodeError> {
+        let decoded_len = crate::decode::decode_in_place(self, buffer)?;
+        Ok(&buffer[..decoded_len])
+    }
+
+    /// Decode the complete 4 character chunks of input that may have been cut
+    /// off mid-chunk, such as the current contents of a file that is still
+    /// being written. Returns the decoded output along with the number of
+    /// bytes at the end of the input that were left over. The left over
+    /// bytes, including any line separators among them, should be decoded
+    /// along with whatever input follows them.
+    ///
+    /// A short final chunk is always left over, even for configs that don't
+    /// require padding, since more input may follow it.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// assert_eq!(STD.decode_truncated("bXkgbWVzc2").unwrap(), (b"my mes".to_vec(), 2));
+    /// assert_eq!(STD.decode_truncated("bXkgbWVzc2FnZQ==").unwrap(), (b"my message".to_vec(), 0));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn decode_truncated<I>(self, input: &I) -> Result<(Vec<u8>, usize), DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
         let input = input.as_ref();
-        let output_size = input.len() * 3 / 4 + 1;
-        if output_size > buffer.len() {
-            buffer.resize(output_size, 0);
+        let complete_len = crate::decode::complete_chunks_len(self, input);
+        let decoded = self.decode(&input[..complete_len])?;
+        Ok((decoded, input.len() - complete_len))
+    }
+
+    /// Decode the base64 document at the start of the input, returning the
+    /// decoded output along with the number of input bytes consumed. The
+    /// document extends through the last symbol of the alphabet before the
+    /// first byte that is neither a symbol nor a line separator, followed by
+    /// any padding that completes its final chunk. This extracts a base64
+    /// field followed by other data without first scanning for the boundary.
+    ///
+    /// An error is returned if the document itself is invalid, such as when
+    /// its padding is missing but required by the config.
+    ///
+    /// ```
+    /// use radix64::{STD, STD_NO_PAD};
+    ///
+    /// assert_eq!(STD.decode_prefix("aGk=;rest").unwrap(), (b"hi".to_vec(), 4));
+    /// assert_eq!(STD.decode_prefix("aGk=aGk=").unwrap(), (b"hi".to_vec(), 4));
+    /// assert_eq!(STD_NO_PAD.decode_prefix("aGk rest").unwrap(), (b"hi".to_vec(), 3));
+    /// assert!(STD.decode_prefix("aGk rest").is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn decode_prefix<I>(self, input: &I) -> Result<(Vec<u8>, usize), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        let prefix_len = crate::decode::prefix_len(self, input);
+        let decoded = self.decode(&input[..prefix_len])?;
+        Ok((decoded, prefix_len))
+    }
+
+    /// Decode the base64 document at the start of the input like
+    /// `decode_prefix`, returning the decoded output along with the remaining
+    /// input that follows the document.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let (decoded, rest) = STD.decode_prefix_rest("aGk=;rest").unwrap();
+    /// assert_eq!(decoded, b"hi");
+    /// assert_eq!(rest, b";rest");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn decode_prefix_rest<I>(self, input: &I) -> Result<(Vec<u8>, &[u8]), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        let (decoded, prefix_len) = self.decode_prefix(input)?;
+        Ok((decoded, &input[prefix_len..]))
+    }
+
+    /// Check that the provided input is valid for this config without
+    /// producing any decoded output. The input is decoded into a small
+    /// scratch buffer that is discarded, so this returns the same result as
+    /// `decode` without allocating space for the decoded data.
+    ///
+    /// ```
+    /// use radix64::{DecodeError, STD};
+    ///
+    /// assert_eq!(STD.validate("aGVsbG8="), Ok(()));
+    /// assert_eq!(STD.validate("aGVsbG8*"), Err(DecodeError::InvalidByte(b'*')));
+    /// assert_eq!(STD.validate("aGVsbG9="), Err(DecodeError::InvalidTrailingBits));
+    /// ```
+    #[inline]
+    fn validate<I>(self, input: &I) -> Result<(), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::decode::validate(self, input.as_ref())
+    }
+
+    /// Returns true if the input is in canonical form for this config, meaning
+    /// it is exactly what `encode` would produce for the data it decodes to.
+    /// Canonical input contains no characters outside the alphabet, is padded
+    /// (or not) as the config pads when encoding, has any line separators in
+    /// the positions encoding would place them, and has all the discarded bits
+    /// of the final character set to zero. No output is produced.
+    ///
+    /// This is useful when encoded strings are used as identifiers, where
+    /// accepting a non-canonical alias of the same bytes would be a problem.
+    ///
+    /// ```
+    /// use radix64::{STD, STD_NO_PAD};
+    ///
+    /// assert!(STD.is_canonical("aGk="));
+    /// assert!(!STD.is_canonical("aGl="));
+    /// assert!(!STD.is_canonical("aGk"));
+    /// assert!(STD_NO_PAD.is_canonical("aGk"));
+    /// ```
+    #[inline]
+    fn is_canonical<I>(self, input: &I) -> bool
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::decode::is_canonical(self, input.as_ref())
+    }
+
+    /// Decode the provided input, appending the decoded output to any
+    /// collection that implements `Extend<u8>`, such as a `VecDeque<u8>`. If
+    /// an error is returned some of the decoded output may have already been
+    /// appended.
+    ///
+    /// ```
+    /// use radix64::STD;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut output = VecDeque::new();
+    /// STD.decode_into("aGVsbG8=", &mut output).unwrap();
+    /// assert_eq!(output, b"hello");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_into<I, T>(self, input: &I, output: &mut T) -> Result<(), DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        T: Extend<u8>,
+    {
+        crate::decode::decode_into(self, input.as_ref(), output)
+    }
+
+    /// Decode the provided input and write the decoded output to `writer`,
+    /// returning the number of decoded bytes written. This is a shorthand for
+    /// [copy_decode](io/fn.copy_decode.html) with the input as the reader.
+    /// Decoding errors are returned as an io::Error wrapping the
+    /// `DecodeError`, and some of the decoded output may have been written
+    /// before an error is detected.
+    ///
+    /// ```
+    /// # fn example() -> std::io::Result<()> {
+    /// use radix64::STD;
+    ///
+    /// let mut output = Vec::new();
+    /// STD.decode_to_writer("bXkgbWVzc2FnZQ==", &mut output)?;
+    /// assert_eq!(output, b"my message");
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn decode_to_writer<I, W>(self, input: &I, writer: &mut W) -> std::io::Result<u64>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        W: std::io::Write + ?Sized,
+    {
+        crate::io::copy_decode(self, &mut input.as_ref(), writer)
+    }
+
+    /// Decode only the bytes within `range` of the decoded output. Only the 4
+    /// byte chunks of input that cover the range are decoded, so a small range
+    /// can be extracted from a large input without allocating space for all
+    /// of the decoded output. The range is clamped to the end of the decoded
+    /// output. Errors are only reported for the chunks that were decoded.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let encoded = STD.encode("hello, world");
+    /// assert_eq!(STD.decode_range(&encoded, 7..12).unwrap(), b"world");
+    /// assert_eq!(STD.decode_range(&encoded, 10..100).unwrap(), b"ld");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_range<I>(
+        self,
+        input: &I,
+        range: core::ops::Range<usize>,
+    ) -> Result<Vec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::decode::decode_range(self, input.as_ref(), range)
+    }
+
+    /// Decode UTF-16 encoded input, such as strings from Windows APIs or
+    /// JavaScript, without first converting it to UTF-8. Any code unit outside
+    /// the ASCII range is reported as `DecodeError::InvalidByte(0xFF)`.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let input: Vec<u16> = "aGVsbG8=".encode_utf16().collect();
+    /// assert_eq!(STD.decode_utf16(&input).unwrap(), b"hello");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_utf16(self, input: &[u16]) -> Result<Vec<u8>, DecodeError> {
+        crate::decode::decode_utf16(self, input)
+    }
+
+    /// Decode input that is split across two slices, such as those returned
+    /// by `VecDeque::as_slices`. The result is the same as decoding the
+    /// concatenation of the slices, without first copying them together.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// assert_eq!(STD.decode_slices("aGVs", "bG8=").unwrap(), b"hello");
+    /// assert_eq!(STD.decode_slices("aGVsb", "G8=").unwrap(), b"hello");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_slices<A, B>(self, first: &A, second: &B) -> Result<Vec<u8>, DecodeError>
+    where
+        A: AsRef<[u8]> + ?Sized,
+        B: AsRef<[u8]> + ?Sized,
+    {
+        crate::decode::decode_split(self, [first.as_ref(), second.as_ref()].iter().cloned())
+    }
+
+    /// Decode input that is split across any number of segments, such as the
+    /// chunks of a rope or a set of scatter buffers. The result is the same as
+    /// decoding the concatenation of the segments, without first copying them
+    /// together. The segments are iterated twice, once to find the length and
+    /// padding of the input and once to decode it.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let segments: Vec<&[u8]> = vec![b"aG", b"VsbG", b"8="];
+    /// assert_eq!(STD.decode_split(segments.iter().cloned()).unwrap(), b"hello");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_split<'a, S>(self, segments: S) -> Result<Vec<u8>, DecodeError>
+    where
+        S: IntoIterator<Item = &'a [u8]>,
+        S::IntoIter: Clone,
+    {
+        crate::decode::decode_split(self, segments.into_iter())
+    }
+
+    /// Decode the remaining contents of a `bytes::Buf`, which may be stored in
+    /// any number of non-contiguous chunks. The buf is advanced to its end.
+    #[cfg(feature = "bytes")]
+    fn decode_buf<B>(self, buf: B) -> Result<Vec<u8>, DecodeError>
+    where
+        B: bytes::Buf,
+    {
+        crate::decode::decode_buf(self, buf)
+    }
+
+    /// Decode the provided input after resolving any percent-escapes, such as
+    /// `%3D`, `%2B`, and `%2F`. This is useful for values extracted from query
+    /// strings and cookies that may have been percent-encoded in transit. The
+    /// escapes are resolved while decoding, without first copying the
+    /// unescaped input. A `%` that is not followed by two hex digits results
+    /// in `DecodeError::InvalidByte(b'%')`.
+    ///
+    /// ```
+    /// use radix64::URL_SAFE;
+    ///
+    /// let decoded = URL_SAFE.decode_percent_encoded("aGk_Pw%3D%3D").unwrap();
+    /// assert_eq!(decoded, b"hi??");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_percent_encoded<I>(self, input: &I) -> Result<Vec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        let mut output = Vec::new();
+        output.append_decoded(crate::decode::max_decoded_len(input.len()) + 1, |buffer| {
+            crate::percent::decode_uninit(self, input, buffer)
+        })?;
+        Ok(output)
+    }
+
+    /// Decode the provided input into a `SecretVec` so that the decoded bytes
+    /// are zeroized when dropped. The output is allocated once with enough
+    /// capacity to hold the decoded data and any scratch space used while
+    /// decoding is zeroized, including when an error is returned.
+    ///
+    /// ```
+    /// use radix64::STD;
+    /// use secrecy::ExposeSecret;
+    ///
+    /// let key = STD.decode_secret("c2VjcmV0IGtleQ==").unwrap();
+    /// assert_eq!(key.expose_secret().as_slice(), b"secret key");
+    /// ```
+    #[cfg(feature = "secrecy")]
+    fn decode_secret<I>(self, input: &I) -> Result<secrecy::SecretVec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        use secrecy::zeroize::Zeroize;
+        let input = input.as_ref();
+        let mut output = Vec::with_capacity(crate::decode::max_decoded_len(input.len()) + 1);
+        match crate::decode::decode_uninit(self, input, output.spare_capacity_mut()) {
+            Ok(decoded_len) => {
+                // decode_uninit initialized the decoded bytes.
+                unsafe { output.set_len(decoded_len) };
+                output.spare_capacity_mut().zeroize();
+                Ok(secrecy::SecretVec::new(output))
+            }
+            Err(err) => {
+                output.spare_capacity_mut().zeroize();
+                Err(err)
+            }
+        }
+    }
+
+    /// Encode a slice of plain old data values, such as `&[f32]`, as its
+    /// in-memory bytes. The bytes are in native endianness, so the output
+    /// should only be decoded on platforms with the same byte order.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let samples = [1.0f32, -0.5, 0.25];
+    /// let encoded = STD.encode_pod_slice(&samples);
+    /// assert_eq!(STD.decode_pod_vec::<f32, _>(&encoded).unwrap(), samples);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    fn encode_pod_slice<T>(self, input: &[T]) -> String
+    where
+        T: bytemuck::Pod,
+    {
+        self.encode(bytemuck::cast_slice::<T, u8>(input))
+    }
+
+    /// Decode the provided input into a `Vec` of plain old data values. The
+    /// values are decoded in place into storage aligned for `T`, and an error
+    /// is returned if the decoded data is not a whole number of values.
+    ///
+    /// Panics if `T` is zero-sized.
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    fn decode_pod_vec<T, I>(self, input: &I) -> Result<Vec<T>, PodDecodeError>
+    where
+        T: bytemuck::Pod,
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::pod::decode_pod_vec(self, input.as_ref())
+    }
+
+    /// Encode the provided input, splitting large inputs across the rayon
+    /// thread pool. The output is identical to `encode`. Inputs smaller than
+    /// a few hundred kilobytes, and configs with line wrapping, are encoded on
+    /// the calling thread.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let input = vec![0xfb; 4 * 1024 * 1024];
+    /// assert_eq!(STD.encode_parallel(&input), STD.encode(&input));
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn encode_parallel<I>(self, input: &I) -> String
+    where
+        I: AsRef<[u8]> + ?Sized,
+        Self: Sync,
+    {
+        crate::parallel::encode_parallel(self, input.as_ref())
+    }
+
+    /// Decode the provided input, splitting large inputs across the rayon
+    /// thread pool. The output, and any error, is identical to `decode`.
+    /// Inputs smaller than a few hundred kilobytes, and configs with line
+    /// wrapping, are decoded on the calling thread.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn decode_parallel<I>(self, input: &I) -> Result<Vec<u8>, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        Self: Sync,
+    {
+        crate::parallel::decode_parallel(self, input.as_ref())
+    }
+
+    /// Decode the provided input, appending the decoded output to any
+    /// [OutputBuffer](trait.OutputBuffer.html). Returns the number of bytes
+    /// written. Nothing is appended if the input is invalid, though the
+    /// front of a slice may have been overwritten. A slice panics, before
+    /// writing anything, if it's smaller than the decoded output. The error
+    /// type depends on the buffer: decoding into a `String` returns a
+    /// `DecodeUtf8Error`, which also reports output that isn't valid UTF-8.
+    ///
+    /// ```
+    /// use radix64::{Config, STD};
+    ///
+    /// let mut v = b"hi ".to_vec();
+    /// assert_eq!(STD.decode_to("aGVsbG8=", &mut v), Ok(5));
+    /// assert_eq!(v, b"hi hello");
+    ///
+    /// let mut s = String::new();
+    /// STD.decode_to("aGVsbG8=", &mut s).unwrap();
+    /// assert_eq!(s, "hello");
+    /// assert!(STD.decode_to("/w==", &mut s).is_err());
+    /// assert_eq!(s, "hello");
+    /// ```
+    #[inline]
+    fn decode_to<I, O>(self, input: &I, output: &mut O) -> Result<usize, O::DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+    {
+        let input = input.as_ref();
+        let len = crate::decode::checked_decoded_len(self, input)?;
+        output.append_decoded(len, |buffer| {
+            crate::decode::decode_uninit(self, input, buffer)
+        })
+    }
+
+    /// Decode the provided input, appending the decoded output to any
+    /// [OutputBuffer](trait.OutputBuffer.html) like `decode_to`. Unlike
+    /// `decode_to` this returns an error, before writing anything, if the
+    /// output would exceed the capacity of a fixed size buffer. The buffer's
+    /// error type must be able to report that, as `DecodeSliceError` does.
+    #[inline]
+    fn try_decode_to<I, O>(self, input: &I, output: &mut O) -> Result<usize, O::DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        O: OutputBuffer + ?Sized,
+        O::DecodeError: From<BufferTooSmall>,
+    {
+        let input = input.as_ref();
+        let len = crate::decode::checked_decoded_len(self, input)?;
+        match output.spare_capacity() {
+            Some(spare) if spare < len => Err(BufferTooSmall::new(len, spare).into()),
+            _ => self.decode_to(input, output),
         }
-        let num_decoded_bytes = crate::decode::decode_slice(self, input, buffer.as_mut_slice())?;
-        Ok(&buffer[..num_decoded_bytes])
     }
 
     /// Decode the provided input into the provided output slice. The slice must
-    /// be large enough to contain the decoded output and panics if it's not. Use
-    /// `input.len() * 6 / 8 + 1` as a conservative estimate. It returns the
-    /// number of bytes of decoded output written to the output slice. This
-    /// method allows for the most control over memory placement, but
-    /// `decode_with_buffer` is typically more ergonomic and just as performant.
+    /// be large enough to contain the decoded output and panics, before
+    /// writing anything, if it's not. The panic message states the required
+    /// and provided sizes. Use `maximum_decoded_output_len` as a conservative
+    /// estimate. It returns the number of bytes of decoded output written to
+    /// the output slice. This method allows for the most control over memory
+    /// placement, but `decode_with_buffer` is typically more ergonomic and just
+    /// as performant.
     #[inline]
     fn decode_slice<I>(self, input: &I, output: &mut [u8]) -> Result<usize, DecodeError>
     where
         I: AsRef<[u8]> + ?Sized,
     {
-        crate::decode::decode_slice(self, input.as_ref(), output)
+        self.decode_to(input, &mut &mut *output)
+    }
+
+    /// Decode the provided input into a fixed size array, such as a key, IV
+    /// or signature. Returns `DecodeError::UnexpectedDecodedLength` if the
+    /// input is not the encoding of exactly `N` bytes. The length is checked
+    /// before the input is decoded.
+    ///
+    /// ```
+    /// use radix64::{Config, DecodeError, STD};
+    ///
+    /// let key: [u8; 5] = STD.decode_exact("aGVsbG8=").unwrap();
+    /// assert_eq!(&key, b"hello");
+    /// assert_eq!(
+    ///     STD.decode_exact::<4, _>("aGVsbG8="),
+    ///     Err(DecodeError::UnexpectedDecodedLength { expected: 4, actual: 5 })
+    /// );
+    /// ```
+    #[inline]
+    fn decode_exact<const N: usize, I>(self, input: &I) -> Result<[u8; N], DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::decode::decode_exact(self, input.as_ref())
+    }
+
+    /// Decode the provided input into the provided output slice, returning the
+    /// number of bytes written. Unlike `decode_slice` this returns an error,
+    /// before writing anything, if the slice is too small. The error holds the
+    /// required length so the caller can provide a larger slice and retry.
+    ///
+    /// ```
+    /// use radix64::{Config, DecodeSliceError, STD};
+    ///
+    /// let mut output = [0; 4];
+    /// let required = match STD.try_decode_slice("aGVsbG8=", &mut output) {
+    ///     Err(DecodeSliceError::BufferTooSmall(err)) => err.required(),
+    ///     other => panic!("unexpected result: {:?}", other),
+    /// };
+    /// let mut output = vec![0; required];
+    /// assert_eq!(STD.try_decode_slice("aGVsbG8=", &mut output), Ok(5));
+    /// assert_eq!(output, b"hello");
+    /// ```
+    #[inline]
+    fn try_decode_slice<I>(self, input: &I, output: &mut [u8]) -> Result<usize, DecodeSliceError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::decode::try_decode_slice(self, input.as_ref(), output)
+    }
+
+    /// Decode the provided input into the provided uninitialized output slice,
+    /// returning the initialized prefix of the slice holding the decoded
+    /// output. This behaves like `decode_slice`, including panicking if the
+    /// slice is too small, but allows decoding into memory that has not been
+    /// initialized, such as a ring buffer or a slot in an arena, without
+    /// zeroing it first.
+    ///
+    /// ```
+    /// use radix64::{Config, STD};
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut output = [MaybeUninit::uninit(); 5];
+    /// assert_eq!(STD.decode_slice_uninit("aGVsbG8=", &mut output).unwrap(), b"hello");
+    /// ```
+    #[inline]
+    fn decode_slice_uninit<'o, I>(
+        self,
+        input: &I,
+        output: &'o mut [MaybeUninit<u8>],
+    ) -> Result<&'o mut [u8], DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        let num_decoded_bytes = crate::decode::decode_uninit(self, input.as_ref(), output)?;
+        // The decoders initialized the bytes they report as written.
+        Ok(unsafe { crate::uninit::assume_init_mut(&mut output[..num_decoded_bytes]) })
+    }
+
+    /// Encode an integer as a short ID, such as a database key in a URL. The
+    /// big-endian bytes of the integer are encoded without their leading zero
+    /// bytes, so small values produce short strings. Zero is encoded as a
+    /// single zero byte. Use with `URL_SAFE_NO_PAD` for IDs that need no
+    /// escaping.
+    ///
+    /// ```
+    /// use radix64::{Config, URL_SAFE_NO_PAD};
+    ///
+    /// assert_eq!(URL_SAFE_NO_PAD.encode_u64(0), "AA");
+    /// assert_eq!(URL_SAFE_NO_PAD.encode_u64(1000), "A-g");
+    /// assert_eq!(URL_SAFE_NO_PAD.encode_u64(u64::MAX), "__________8");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_u64(self, value: u64) -> String {
+        crate::int::encode_uint(self, &value.to_be_bytes())
+    }
+
+    /// Encode a 128-bit integer as a short ID. See `encode_u64`.
+    ///
+    /// ```
+    /// use radix64::{Config, URL_SAFE_NO_PAD};
+    ///
+    /// assert_eq!(URL_SAFE_NO_PAD.encode_u128(1 << 64), "AQAAAAAAAAAA");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_u128(self, value: u128) -> String {
+        crate::int::encode_uint(self, &value.to_be_bytes())
+    }
+
+    /// Decode an integer encoded by `encode_u64`. Input that decodes to more
+    /// than 8 bytes, or that has leading zero bytes, is rejected so each
+    /// integer has exactly one encoding.
+    ///
+    /// ```
+    /// use radix64::{Config, DecodeIntError, URL_SAFE_NO_PAD};
+    ///
+    /// assert_eq!(URL_SAFE_NO_PAD.decode_u64("A-g"), Ok(1000));
+    /// assert_eq!(URL_SAFE_NO_PAD.decode_u64("AAPo"), Err(DecodeIntError::LeadingZero));
+    /// ```
+    #[inline]
+    fn decode_u64<I>(self, input: &I) -> Result<u64, DecodeIntError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::int::decode_uint(self, input.as_ref()).map(u64::from_be_bytes)
+    }
+
+    /// Decode an integer encoded by `encode_u128`. See `decode_u64`.
+    #[inline]
+    fn decode_u128<I>(self, input: &I) -> Result<u128, DecodeIntError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::int::decode_uint(self, input.as_ref()).map(u128::from_be_bytes)
+    }
+
+    /// The exact length of the output produced by encoding `input_len` bytes,
+    /// including any padding and line separators. Panics if the length does
+    /// not fit in a usize.
+    ///
+    /// The builtin configs also provide this as a `const fn`, allowing it to
+    /// size arrays.
+    ///
+    /// ```
+    /// use radix64::{STD, STD_NO_PAD};
+    ///
+    /// const ENCODED_DIGEST_LEN: usize = STD.encoded_output_len(32);
+    /// let buf = [0; ENCODED_DIGEST_LEN];
+    /// assert_eq!(buf.len(), 44);
+    /// assert_eq!(STD_NO_PAD.encoded_output_len(32), 43);
+    /// ```
+    #[inline]
+    fn encoded_output_len(self, input_len: usize) -> usize {
+        let encoded_len = crate::encode::encoded_len(input_len, self.padding_byte().is_some());
+        match self.line_wrap() {
+            Some(wrap) => {
+                crate::encode::wrapped_len(encoded_len, wrap.interval(), wrap.separator().len())
+            }
+            None => encoded_len,
+        }
+    }
+
+    /// The exact length of the output produced by encoding `input_len` bytes
+    /// and splitting the encoded output into lines of `width` characters,
+    /// separated by line endings of `line_ending_len` bytes. This sizes
+    /// buffers for MIME and PEM style output wrapped by the caller. Any line
+    /// wrapping configured on the config itself is ignored.
+    ///
+    /// No line ending follows the final line, matching the output of a
+    /// `CustomConfig` with line wrapping. Add `line_ending_len` for formats
+    /// that terminate every line. Panics if `width` is zero or the length does
+    /// not fit in a usize.
+    ///
+    /// The builtin configs also provide this as a `const fn`.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// // 60 bytes encode to 80 characters: a line of 76 and a line of 4.
+    /// assert_eq!(STD.encoded_output_len_wrapped(60, 76, 2), 82);
+    /// // 57 bytes encode to exactly one line.
+    /// assert_eq!(STD.encoded_output_len_wrapped(57, 76, 2), 76);
+    /// ```
+    #[inline]
+    fn encoded_output_len_wrapped(
+        self,
+        input_len: usize,
+        width: usize,
+        line_ending_len: usize,
+    ) -> usize {
+        let encoded_len = crate::encode::encoded_len(input_len, self.padding_byte().is_some());
+        crate::encode::wrapped_len(encoded_len, width, line_ending_len)
+    }
+
+    /// The maximum number of bytes produced by decoding `input_len` bytes of
+    /// input. Decoding may produce fewer bytes when the input contains padding
+    /// or line separators.
+    ///
+    /// The builtin configs also provide this as a `const fn`, allowing it to
+    /// size arrays.
+    ///
+    /// ```
+    /// use radix64::STD;
+    ///
+    /// let mut buf = [0; STD.maximum_decoded_output_len(8)];
+    /// assert_eq!(STD.decode_slice("aGVsbG8=", &mut buf), Ok(5));
+    /// ```
+    #[inline]
+    fn maximum_decoded_output_len(self, input_len: usize) -> usize {
+        crate::decode::max_decoded_len(input_len)
+    }
+
+    /// The exact number of bytes produced by decoding the provided input. The
+    /// length is determined from the amount of input, excluding any line
+    /// separators, and the padding at its end, which allows allocating
+    /// exactly enough space for `decode_slice`. An error is returned if the
+    /// input has an invalid length or padding. The remaining characters are
+    /// not examined, so decoding may still fail with `InvalidByte` or
+    /// `InvalidTrailingBits`.
+    ///
+    /// ```
+    /// use radix64::{DecodeError, STD, STD_NO_PAD};
+    ///
+    /// assert_eq!(STD.decoded_len("aGVsbG8="), Ok(5));
+    /// assert_eq!(STD_NO_PAD.decoded_len("aGVsbG8"), Ok(5));
+    /// assert!(STD.decoded_len("aGVsbG8").is_err());
+    ///
+    /// let mut output = vec![0; STD.decoded_len("aGVsbG8=")?];
+    /// STD.decode_slice("aGVsbG8=", &mut output)?;
+    /// assert_eq!(output, b"hello");
+    /// # Ok::<(), DecodeError>(())
+    /// ```
+    #[inline]
+    fn decoded_len<I>(self, input: &I) -> Result<usize, DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+    {
+        crate::decode::decoded_len(self, input.as_ref())
     }
 }
 
@@ -314,19 +1697,19 @@ macro_rules! define_block_iter {
         output_stride = $output_stride:expr
     ) => {
         /// An iterator that accepts an input slice and output slice. It yields
-        /// (&[u8; $input_chunk_size], &mut [u8; $output_chunk_size]). Each yield
-        /// advances the input $input_stride bytes and the output $output_stride
-        /// bytes.
+        /// (&[u8; $input_chunk_size], &mut [MaybeUninit<u8>; $output_chunk_size]).
+        /// Each yield advances the input $input_stride bytes and the output
+        /// $output_stride bytes.
         struct $name<'a, 'b> {
             input: &'a [u8],
-            output: &'b mut [u8],
+            output: &'b mut [core::mem::MaybeUninit<u8>],
             input_index: usize,
             output_index: usize,
         }
 
         impl<'a, 'b> $name<'a, 'b> {
             #[inline]
-            fn new(input: &'a [u8], output: &'b mut [u8]) -> Self {
+            fn new(input: &'a [u8], output: &'b mut [core::mem::MaybeUninit<u8>]) -> Self {
                 $name {
                     input,
                     output,
@@ -338,9 +1721,14 @@ macro_rules! define_block_iter {
             #[inline]
             fn next_chunk(
                 &mut self,
-            ) -> Option<(&[u8; $input_chunk_size], &mut [u8; $output_chunk_size])> {
-                if self.input_index + $input_chunk_size <= self.input.len()
-                    && self.output_index + $output_chunk_size <= self.output.len()
+            ) -> Option<(
+                &[u8; $input_chunk_size],
+                &mut [core::mem::MaybeUninit<u8>; $output_chunk_size],
+            )> {
+                // The indices never exceed the slice lengths, so subtracting
+                // cannot overflow even when usize is only 16 bits.
+                if self.input.len() - self.input_index >= $input_chunk_size
+                    && self.output.len() - self.output_index >= $output_chunk_size
                 {
                     use arrayref::{array_mut_ref, array_ref};
                     let input = array_ref!(self.input, self.input_index, $input_chunk_size);
@@ -353,14 +1741,6 @@ macro_rules! define_block_iter {
                 }
             }
 
-            #[allow(dead_code)]
-            fn step_back(&mut self) {
-                if self.input_index > 0 {
-                    self.input_index -= $input_stride;
-                    self.output_index -= $output_stride;
-                }
-            }
-
             #[inline]
             fn remaining(self) -> (usize, usize) {
                 (self.input_index, self.output_index)
@@ -370,39 +1750,69 @@ macro_rules! define_block_iter {
 }
 
 // mod definitions need to appear after the macro definition.
+#[cfg(all(feature = "avx2", any(target_arch = "x86", target_arch = "x86_64")))]
+pub mod arch;
+pub(crate) mod backend;
+#[cfg(feature = "alloc")]
+pub mod basic_auth;
+#[cfg(feature = "unstable-block")]
+pub mod block;
+#[cfg(feature = "clap")]
+pub mod cli;
+#[cfg(feature = "alloc")]
+pub mod compat;
 pub mod configs;
+#[cfg(feature = "alloc")]
+pub mod crypt;
 pub(crate) mod decode;
 pub(crate) mod display;
+#[cfg(feature = "alloc")]
+pub(crate) mod dyn_config;
 pub(crate) mod encode;
+#[cfg(feature = "alloc")]
+pub mod encoded_word;
+#[cfg(feature = "alloc")]
+pub mod ext;
+#[cfg(feature = "alloc")]
+pub mod filename;
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+pub(crate) mod fixed;
+#[cfg(feature = "alloc")]
+pub mod folded;
+#[cfg(feature = "digest")]
+pub(crate) mod hashing;
+pub(crate) mod int;
+#[cfg(feature = "std")]
 pub mod io;
-pub(crate) mod tables;
+#[doc(hidden)]
+pub mod literal;
+#[cfg(feature = "alloc")]
+pub(crate) mod normalize;
+#[cfg(feature = "tokio")]
+pub mod offload;
+#[cfg(all(feature = "std", any(unix, windows)))]
+pub mod os_str;
+pub(crate) mod output;
+#[cfg(feature = "rayon")]
+pub(crate) mod parallel;
+#[cfg(feature = "alloc")]
+pub(crate) mod percent;
+#[cfg(feature = "bytemuck")]
+pub(crate) mod pod;
+#[cfg(feature = "std")]
+pub(crate) mod pool;
+pub mod raw;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+pub mod ssh;
+pub mod tables;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub(crate) mod u6;
-
-use std::ops::Bound;
-use std::ops::RangeBounds;
-
-// Copy the data in slice within the src range, to the index specified by dest.
-// This is just a stop-gap until slice::copy_within is stabilized.
-pub(crate) fn copy_in_place<T: Copy, R: RangeBounds<usize>>(slice: &mut [T], src: R, dest: usize) {
-    let src_start = match src.start_bound() {
-        Bound::Included(&n) => n,
-        Bound::Excluded(&n) => n.checked_add(1).expect("range bound overflows usize"),
-        Bound::Unbounded => 0,
-    };
-    let src_end = match src.end_bound() {
-        Bound::Included(&n) => n.checked_add(1).expect("range bound overflows usize"),
-        Bound::Excluded(&n) => n,
-        Bound::Unbounded => slice.len(),
-    };
-    assert!(src_start <= src_end, "src end is before src start");
-    assert!(src_end <= slice.len(), "src is out of bounds");
-    let count = src_end - src_start;
-    assert!(dest <= slice.len() - count, "dest is out of bounds");
-    unsafe {
-        core::ptr::copy(
-            slice.get_unchecked(src_start),
-            slice.get_unchecked_mut(dest),
-            count,
-        );
-    }
-}
+pub(crate) mod uninit;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub mod web;
+pub(crate) mod wrap;
+#[cfg(feature = "alloc")]
+pub(crate) mod wrapper;
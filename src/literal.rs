@@ -0,0 +1,92 @@
+//! Macros for encoding and decoding literals at compile time.
+
+/// Decode a base64 literal at compile time, producing a `&'static [u8]`.
+///
+/// The literal may be a str or a byte string. A config may be given as the
+/// first argument, otherwise [STD](constant.STD.html) is used. Only the
+/// builtin configs are supported, since decoding is done by their
+/// `decode_const` methods. Invalid input is a compile error.
+///
+/// ```
+/// use radix64::{b64, URL_SAFE_NO_PAD};
+///
+/// const HELLO: &[u8] = b64!("aGVsbG8=");
+/// assert_eq!(HELLO, b"hello");
+/// assert_eq!(b64!(URL_SAFE_NO_PAD, b"-_-_"), [0xfb, 0xff, 0xbf]);
+/// ```
+///
+/// Malformed literals are rejected when compiling.
+/// ```compile_fail
+/// let _ = radix64::b64!("aGVsbG8");
+/// ```
+#[macro_export]
+macro_rules! b64 {
+    ($input:expr) => {
+        $crate::b64!($crate::STD, $input)
+    };
+    ($config:expr, $input:expr) => {{
+        const INPUT: &[u8] = $crate::literal::Literal($input).bytes();
+        const LEN: usize = match $config.decoded_len_const(INPUT) {
+            Ok(len) => len,
+            Err(_) => panic!("invalid base64 length or padding"),
+        };
+        const DECODED: [u8; LEN] = $config.decode_const(INPUT);
+        const OUTPUT: &[u8] = &DECODED;
+        OUTPUT
+    }};
+}
+
+/// Encode a literal at compile time, producing a `&'static str`.
+///
+/// The literal may be a str or a byte string. A config may be given as the
+/// first argument, otherwise [STD](constant.STD.html) is used. Only the
+/// builtin configs are supported, since encoding is done by their
+/// `encode_const` methods.
+///
+/// ```
+/// use radix64::{b64_encode, URL_SAFE_NO_PAD};
+///
+/// const HELLO: &str = b64_encode!("hello");
+/// assert_eq!(HELLO, "aGVsbG8=");
+/// assert_eq!(b64_encode!(URL_SAFE_NO_PAD, b"\xfb\xff\xbf"), "-_-_");
+/// ```
+#[macro_export]
+macro_rules! b64_encode {
+    ($input:expr) => {
+        $crate::b64_encode!($crate::STD, $input)
+    };
+    ($config:expr, $input:expr) => {{
+        const INPUT: &[u8] = $crate::literal::Literal($input).bytes();
+        const ENCODED: [u8; $config.encoded_output_len(INPUT.len())] = $config.encode_const(INPUT);
+        const OUTPUT: &str = match ::core::str::from_utf8(&ENCODED) {
+            Ok(output) => output,
+            Err(_) => panic!("encoded output is ascii"),
+        };
+        OUTPUT
+    }};
+}
+
+/// Converts the literals accepted by the macros to bytes in a const context.
+#[doc(hidden)]
+pub struct Literal<T>(pub T);
+
+impl Literal<&'static str> {
+    #[doc(hidden)]
+    pub const fn bytes(self) -> &'static [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl Literal<&'static [u8]> {
+    #[doc(hidden)]
+    pub const fn bytes(self) -> &'static [u8] {
+        self.0
+    }
+}
+
+impl<const N: usize> Literal<&'static [u8; N]> {
+    #[doc(hidden)]
+    pub const fn bytes(self) -> &'static [u8] {
+        self.0
+    }
+}
@@ -0,0 +1,91 @@
+//! Converting loosely formatted base64 into the canonical form of a config.
+use crate::decode::{decode_using_table, DecodeError, INVALID_VALUE};
+use crate::tables::{STD_DECODE, URL_SAFE_DECODE};
+use crate::u6::U6;
+use crate::Config;
+use alloc::{string::String, vec::Vec};
+
+/// Convert base64 input into the canonical encoding for the target config.
+///
+/// The input may use any mix of the standard (`+/`) and URL safe (`-_`)
+/// alphabets of [RFC 4648](https://tools.ietf.org/html/rfc4648), may contain
+/// ascii whitespace anywhere, and may be padded or unpadded. The output is
+/// exactly what `target.encode` would produce for the decoded data: it uses the
+/// target's alphabet, padding, and line wrapping, and any non-zero discarded
+/// bits of the final character are cleared. The conversion is done one
+/// character at a time without decoding to an intermediate buffer.
+///
+/// An error is returned if the input contains any other characters, if
+/// anything other than whitespace follows padding, or if the number of
+/// characters is not a valid length for base64.
+///
+/// ```
+/// use radix64::{normalize, STD, URL_SAFE_NO_PAD};
+///
+/// let messy = " -_8/\n+/8A YQ\n";
+/// assert_eq!(normalize(messy, URL_SAFE_NO_PAD).unwrap(), "-_8_-_8AYQ");
+/// assert_eq!(normalize(messy, STD).unwrap(), "+/8/+/8AYQ==");
+/// ```
+pub fn normalize<I, C>(input: &I, target: C) -> Result<String, DecodeError>
+where
+    I: AsRef<[u8]> + ?Sized,
+    C: Config,
+{
+    let input = input.as_ref();
+    let mut output = Vec::with_capacity(crate::encode::max_encoded_len(target, input.len()));
+    let mut last_value = 0;
+    let mut padding_seen = false;
+    for &b in input {
+        if b.is_ascii_whitespace() {
+            continue;
+        }
+        if b == b'=' {
+            padding_seen = true;
+            continue;
+        }
+        if padding_seen {
+            return Err(DecodeError::InvalidByte(b));
+        }
+        let value = match decode_using_table(STD_DECODE, b) {
+            INVALID_VALUE => decode_using_table(URL_SAFE_DECODE, b),
+            value => value,
+        };
+        if value == INVALID_VALUE {
+            return Err(DecodeError::InvalidByte(b));
+        }
+        output.push(target.encode_u6(U6::from_low_six_bits(value)));
+        last_value = value;
+    }
+
+    // Clear the bits of the final character that do not contribute to the
+    // decoded data.
+    let discarded_bits_mask = match output.len() % 4 {
+        0 => 0,
+        1 => {
+            return Err(DecodeError::InvalidLength {
+                length: output.len(),
+                remainder: 1,
+                padding_expected: false,
+            })
+        }
+        2 => 0b0000_1111,
+        _ => 0b0000_0011,
+    };
+    if let Some(last) = output.last_mut() {
+        *last = target.encode_u6(U6::from_low_six_bits(last_value & !discarded_bits_mask));
+    }
+
+    if let Some(padding) = target.padding_byte() {
+        while !output.len().is_multiple_of(4) {
+            output.push(padding);
+        }
+    }
+    if let Some(wrap) = target.line_wrap() {
+        let encoded_len = output.len();
+        output.resize(encoded_len + wrap.separators_len(encoded_len), 0);
+        wrap.insert_separators(&mut output, encoded_len);
+    }
+    // The target alphabet, padding, and separators are all ascii.
+    debug_assert!(output.iter().all(u8::is_ascii));
+    Ok(unsafe { String::from_utf8_unchecked(output) })
+}
@@ -0,0 +1,67 @@
+//! Encoding and decoding from async contexts without stalling the executor.
+//!
+//! Encoding or decoding multiple megabytes of data can take long enough to
+//! noticeably delay other tasks sharing the executor thread. The functions in
+//! this module run large payloads on tokio's blocking thread pool using
+//! `tokio::task::spawn_blocking` while payloads smaller than
+//! [OFFLOAD_THRESHOLD](constant.OFFLOAD_THRESHOLD.html) are handled inline,
+//! where the cost of moving them to another thread would outweigh the work.
+//!
+//! These functions must be called from within a tokio runtime.
+//!
+//! ```
+//! # #[tokio::main]
+//! # async fn main() {
+//! use radix64::{offload, STD};
+//!
+//! let encoded = offload::encode(STD, vec![0xff; 1 << 20]).await;
+//! let decoded = offload::decode(STD, encoded).await.unwrap();
+//! assert_eq!(decoded, vec![0xff; 1 << 20]);
+//! # }
+//! ```
+use crate::{Config, DecodeError};
+use alloc::{string::String, vec::Vec};
+use std::panic;
+
+/// Payloads of at least this many bytes are encoded or decoded on the blocking
+/// thread pool.
+pub const OFFLOAD_THRESHOLD: usize = 64 * 1024;
+
+/// Encode the provided input, offloading the work to the blocking thread pool
+/// if the input is at least `OFFLOAD_THRESHOLD` bytes.
+pub async fn encode<C, I>(config: C, input: I) -> String
+where
+    C: Config + Send + 'static,
+    I: AsRef<[u8]> + Send + 'static,
+{
+    if input.as_ref().len() < OFFLOAD_THRESHOLD {
+        return config.encode(&input);
+    }
+    run_blocking(move || config.encode(&input)).await
+}
+
+/// Decode the provided input, offloading the work to the blocking thread pool
+/// if the input is at least `OFFLOAD_THRESHOLD` bytes.
+pub async fn decode<C, I>(config: C, input: I) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config + Send + 'static,
+    I: AsRef<[u8]> + Send + 'static,
+{
+    if input.as_ref().len() < OFFLOAD_THRESHOLD {
+        return config.decode(&input);
+    }
+    run_blocking(move || config.decode(&input)).await
+}
+
+async fn run_blocking<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(output) => output,
+        // Propagate a panic from the blocking task to the caller as if the
+        // work had been done inline.
+        Err(err) => panic::resume_unwind(err.into_panic()),
+    }
+}
@@ -0,0 +1,120 @@
+//! Lossless encoding of `OsStr` and `Path` values.
+//!
+//! Operating system strings are not necessarily valid unicode, so converting
+//! them to a `str` before encoding can lose information. The functions here
+//! encode the platform's native representation instead: the raw bytes on Unix
+//! and the UTF-16 code units, as little-endian byte pairs, on Windows. The
+//! output uses the [URL_SAFE_NO_PAD](../constant.URL_SAFE_NO_PAD.html)
+//! alphabet, making it suitable for embedding arbitrary file names in URLs,
+//! manifests, and database columns.
+//!
+//! Because the representations differ, an encoded value can only be decoded
+//! on the same family of platform that encoded it. Decoding a value produced
+//! on the other family returns an error or a different name.
+//!
+//! ```
+//! use radix64::os_str;
+//! use std::path::Path;
+//!
+//! let path = Path::new("reports/2019 summary.txt");
+//! let encoded = os_str::encode(path);
+//! assert_eq!(os_str::decode_path(&encoded).unwrap(), path);
+//! ```
+use crate::{DecodeError, URL_SAFE_NO_PAD};
+use alloc::{string::String, vec::Vec};
+use core::{error, fmt};
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+
+/// Errors that can occur when decoding an `OsString`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OsStrDecodeError {
+    /// The input is not valid base64.
+    Decode(DecodeError),
+    /// The decoded bytes are not a valid representation of an `OsString` on
+    /// this platform, such as an odd number of bytes on Windows.
+    InvalidRepresentation,
+}
+
+impl fmt::Display for OsStrDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OsStrDecodeError::Decode(ref err) => write!(f, "invalid encoded os string: {}", err),
+            OsStrDecodeError::InvalidRepresentation => {
+                write!(
+                    f,
+                    "decoded bytes are not a valid os string on this platform"
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for OsStrDecodeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            OsStrDecodeError::Decode(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Encode the platform representation of an `OsStr`, or anything that can be
+/// viewed as one such as a `Path`.
+pub fn encode<S>(s: &S) -> String
+where
+    S: AsRef<OsStr> + ?Sized,
+{
+    URL_SAFE_NO_PAD.encode(&to_bytes(s.as_ref()))
+}
+
+/// Decode an `OsString` produced by `encode`.
+pub fn decode<I>(input: &I) -> Result<OsString, OsStrDecodeError>
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    let bytes = URL_SAFE_NO_PAD
+        .decode(input)
+        .map_err(OsStrDecodeError::Decode)?;
+    from_bytes(bytes)
+}
+
+/// Decode a `PathBuf` produced by `encode`.
+pub fn decode_path<I>(input: &I) -> Result<PathBuf, OsStrDecodeError>
+where
+    I: AsRef<[u8]> + ?Sized,
+{
+    decode(input).map(PathBuf::from)
+}
+
+#[cfg(unix)]
+fn to_bytes(s: &OsStr) -> alloc::borrow::Cow<'_, [u8]> {
+    use std::os::unix::ffi::OsStrExt;
+    alloc::borrow::Cow::Borrowed(s.as_bytes())
+}
+
+#[cfg(unix)]
+fn from_bytes(bytes: Vec<u8>) -> Result<OsString, OsStrDecodeError> {
+    use std::os::unix::ffi::OsStringExt;
+    Ok(OsString::from_vec(bytes))
+}
+
+#[cfg(windows)]
+fn to_bytes(s: &OsStr) -> alloc::borrow::Cow<'_, [u8]> {
+    use std::os::windows::ffi::OsStrExt;
+    alloc::borrow::Cow::Owned(s.encode_wide().flat_map(u16::to_le_bytes).collect())
+}
+
+#[cfg(windows)]
+fn from_bytes(bytes: Vec<u8>) -> Result<OsString, OsStrDecodeError> {
+    use std::os::windows::ffi::OsStringExt;
+    if bytes.len() % 2 != 0 {
+        return Err(OsStrDecodeError::InvalidRepresentation);
+    }
+    let wide: Vec<u16> = bytes
+        .chunks(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    Ok(OsString::from_wide(&wide))
+}
@@ -0,0 +1,209 @@
+use crate::uninit::as_uninit_mut;
+use crate::DecodeError;
+#[cfg(feature = "alloc")]
+use crate::DecodeUtf8Error;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::mem::MaybeUninit;
+
+/// A destination for encoded or decoded output, used by
+/// [Config::encode_to](trait.Config.html#method.encode_to) and
+/// [Config::decode_to](trait.Config.html#method.decode_to).
+///
+/// It's implemented for `Vec<u8>` and `String`, which have output appended
+/// to them, and for `&mut [u8]`, which has output written to the front of
+/// the slice and is then advanced past it, the same as `std::io::Write`.
+/// Other buffers, such as a `SmallVec`, can implement it to be used the same
+/// way.
+///
+/// The output is written through `&mut [MaybeUninit<u8>]`, so growable
+/// buffers can hand over their spare capacity without initializing it first.
+/// Only initialized bytes are ever written, so an initialized buffer can be
+/// viewed as `[MaybeUninit<u8>]` to be written to.
+///
+/// ```
+/// use radix64::{OutputBuffer, STD};
+/// use std::mem::MaybeUninit;
+///
+/// struct Fixed {
+///     buf: [u8; 64],
+///     len: usize,
+/// }
+///
+/// impl Fixed {
+///     fn spare(&mut self, len: usize) -> &mut [MaybeUninit<u8>] {
+///         let spare = &mut self.buf[self.len..self.len + len];
+///         // Only initialized bytes are written to the spare bytes.
+///         unsafe { &mut *(spare as *mut [u8] as *mut [MaybeUninit<u8>]) }
+///     }
+/// }
+///
+/// impl OutputBuffer for Fixed {
+///     type DecodeError = radix64::DecodeError;
+///
+///     fn append_encoded<F: FnOnce(&mut [MaybeUninit<u8>])>(&mut self, len: usize, encode: F) {
+///         encode(self.spare(len));
+///         self.len += len;
+///     }
+///
+///     fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, Self::DecodeError>
+///     where
+///         F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, radix64::DecodeError>,
+///     {
+///         let written = decode(self.spare(max_len))?;
+///         self.len += written;
+///         Ok(written)
+///     }
+/// }
+///
+/// let mut output = Fixed { buf: [0; 64], len: 0 };
+/// STD.encode_to("hello", &mut output);
+/// assert_eq!(&output.buf[..output.len], b"aGVsbG8=");
+/// ```
+pub trait OutputBuffer {
+    /// The error returned when decoding into this buffer. Buffers that
+    /// require UTF-8, such as `String`, report decoded output that isn't
+    /// valid UTF-8 through it.
+    type DecodeError: From<DecodeError>;
+
+    /// Append `len` bytes of encoded output, which `encode` writes into the
+    /// provided slice of exactly `len` bytes. Every byte of the slice is
+    /// initialized when `encode` returns. Encoded output is always ascii.
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]);
+
+    /// Append decoded output, which `decode` writes to the front of the
+    /// provided slice of `max_len` bytes, returning the number of bytes
+    /// written. Only those bytes, which are initialized when `decode`
+    /// returns, are kept. Nothing is appended if `decode` returns an error.
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, Self::DecodeError>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>;
+
+    /// The number of bytes that can be appended before reaching a fixed
+    /// capacity, or `None` if the buffer grows as needed. The default returns
+    /// `None`.
+    fn spare_capacity(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OutputBuffer for Vec<u8> {
+    type DecodeError = DecodeError;
+
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]),
+    {
+        let start = self.len();
+        self.reserve(len);
+        encode(&mut self.spare_capacity_mut()[..len]);
+        // encode initialized all len bytes.
+        unsafe { self.set_len(start + len) };
+    }
+
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, DecodeError>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+    {
+        let start = self.len();
+        self.reserve(max_len);
+        let written = decode(&mut self.spare_capacity_mut()[..max_len])?;
+        // decode initialized the written bytes.
+        unsafe { self.set_len(start + written) };
+        Ok(written)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OutputBuffer for String {
+    type DecodeError = DecodeUtf8Error;
+
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]),
+    {
+        // Take ownership of the bytes so a panic while encoding leaves the
+        // string empty rather than holding invalid UTF-8.
+        let mut bytes = core::mem::take(self).into_bytes();
+        let start = bytes.len();
+        bytes.append_encoded(len, encode);
+        // The encoded output is always ascii, as described in
+        // Config::encode_with_buffer.
+        debug_assert!(bytes[start..].is_ascii());
+        *self = unsafe { String::from_utf8_unchecked(bytes) };
+    }
+
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, DecodeUtf8Error>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+    {
+        let mut bytes = core::mem::take(self).into_bytes();
+        let start = bytes.len();
+        let result = match bytes.append_decoded(max_len, decode) {
+            Ok(_) if core::str::from_utf8(&bytes[start..]).is_err() => {
+                let decoded = bytes.split_off(start);
+                Err(String::from_utf8(decoded).unwrap_err().into())
+            }
+            result => result.map_err(DecodeUtf8Error::from),
+        };
+        // Everything before start came from the string, and the decoded bytes
+        // after it were either checked above or removed.
+        *self = unsafe { String::from_utf8_unchecked(bytes) };
+        result
+    }
+}
+
+impl OutputBuffer for &mut [u8] {
+    type DecodeError = DecodeError;
+
+    fn append_encoded<F>(&mut self, len: usize, encode: F)
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]),
+    {
+        let slice = core::mem::take(self);
+        if slice.len() < len {
+            output_slice_too_small(len, slice.len());
+        }
+        let (head, tail) = slice.split_at_mut(len);
+        // encode only writes initialized bytes.
+        encode(unsafe { as_uninit_mut(head) });
+        *self = tail;
+    }
+
+    fn append_decoded<F>(&mut self, max_len: usize, decode: F) -> Result<usize, DecodeError>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> Result<usize, DecodeError>,
+    {
+        let slice = core::mem::take(self);
+        let provided = slice.len();
+        if provided < max_len {
+            output_slice_too_small(max_len, provided);
+        }
+        // decode only writes initialized bytes.
+        match decode(unsafe { as_uninit_mut(slice) }) {
+            Ok(written) => {
+                *self = &mut slice[written..];
+                Ok(written)
+            }
+            Err(err) => {
+                *self = slice;
+                Err(err)
+            }
+        }
+    }
+
+    fn spare_capacity(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+#[cold]
+fn output_slice_too_small(required: usize, provided: usize) -> ! {
+    panic!(
+        "output slice too small: {} bytes required, {} provided",
+        required, provided
+    )
+}
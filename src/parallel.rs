@@ -0,0 +1,72 @@
+use crate::{Config, DecodeError};
+use alloc::{string::String, vec, vec::Vec};
+use rayon::prelude::*;
+
+// The number of input bytes encoded by each task. A multiple of 3 so every
+// chunk but the last encodes to whole 4 byte chunks without padding.
+const ENCODE_CHUNK_LEN: usize = 3 * 64 * 1024;
+
+// The number of input bytes decoded by each task. A multiple of 4 so every
+// chunk but the last decodes to whole 3 byte chunks.
+const DECODE_CHUNK_LEN: usize = 4 * 64 * 1024;
+
+// Wrapped output can't be split on arbitrary chunk boundaries because every
+// line must be the same length, so configs with line wrapping are encoded and
+// decoded on the calling thread.
+pub(crate) fn encode_parallel<C>(config: C, input: &[u8]) -> String
+where
+    C: Config + Sync,
+{
+    if input.len() <= ENCODE_CHUNK_LEN || config.line_wrap().is_some() {
+        return config.encode(input);
+    }
+    let mut output = vec![0; config.encoded_output_len(input.len())];
+    let output_chunk_len = ENCODE_CHUNK_LEN / 3 * 4;
+    input
+        .par_chunks(ENCODE_CHUNK_LEN)
+        .zip(output.par_chunks_mut(output_chunk_len))
+        .for_each(|(input, output)| {
+            crate::encode::encode_slice(config, input, output);
+        });
+    // The builtin alphabets are all ascii and the CustomConfigBuilder ensures
+    // any custom alphabets only contain ascii characters as well.
+    debug_assert!(output.iter().all(u8::is_ascii));
+    unsafe { String::from_utf8_unchecked(output) }
+}
+
+// Each chunk but the last must decode to exactly 3/4 of its length. Padding
+// or an error in any chunk falls back to decoding the entire input on the
+// calling thread, so the result, including which error is returned, is
+// identical to `decode`.
+pub(crate) fn decode_parallel<C>(config: C, input: &[u8]) -> Result<Vec<u8>, DecodeError>
+where
+    C: Config + Sync,
+{
+    if input.len() <= DECODE_CHUNK_LEN || config.line_wrap().is_some() {
+        return config.decode(input);
+    }
+    let mut output = vec![0; crate::decode::max_decoded_len(input.len())];
+    let output_chunk_len = DECODE_CHUNK_LEN / 4 * 3;
+    let last_chunk_idx = (input.len() - 1) / DECODE_CHUNK_LEN;
+    let last_chunk_len = input
+        .par_chunks(DECODE_CHUNK_LEN)
+        .zip(output.par_chunks_mut(output_chunk_len))
+        .enumerate()
+        .map(
+            |(idx, (input, output))| match crate::decode::decode_slice(config, input, output) {
+                Ok(decoded_len) if idx == last_chunk_idx || decoded_len == output_chunk_len => {
+                    Some(decoded_len)
+                }
+                _ => None,
+            },
+        )
+        .collect::<Option<Vec<usize>>>()
+        .and_then(|decoded_lens| decoded_lens.last().cloned());
+    match last_chunk_len {
+        Some(last_chunk_len) => {
+            output.truncate(last_chunk_idx * output_chunk_len + last_chunk_len);
+            Ok(output)
+        }
+        None => config.decode(input),
+    }
+}
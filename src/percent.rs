@@ -0,0 +1,116 @@
+//! Support for decoding base64 that has been percent-encoded, as commonly
+//! happens to values in query strings and cookies.
+use crate::decode::{decode_segments_uninit, DecodeError};
+use crate::Config;
+use core::mem::MaybeUninit;
+
+// Every possible byte value. Percent-escapes are resolved to a one byte slice
+// of this table so the unescaped input can be presented as a series of slices
+// without allocating.
+static BYTE_VALUES: [u8; 256] = byte_values();
+
+const fn byte_values() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+// Decode into memory that may be uninitialized, returning the number of bytes
+// written. Those bytes are initialized when this returns.
+pub(crate) fn decode_uninit<C>(
+    config: C,
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+) -> Result<usize, DecodeError>
+where
+    C: Config,
+{
+    validate_escapes(input)?;
+    let segments = PercentDecodedSegments { input };
+    match config.line_wrap() {
+        Some(wrap) => {
+            let separator = wrap.separator();
+            decode_segments_uninit(
+                config,
+                segments.flat_map(move |segment| crate::wrap::split_separators(segment, separator)),
+                output,
+            )
+        }
+        None => decode_segments_uninit(config, segments, output),
+    }
+}
+
+// Ensure every '%' is followed by two hex digits.
+fn validate_escapes(mut input: &[u8]) -> Result<(), DecodeError> {
+    while let Some(pos) = input.iter().position(|&b| b == b'%') {
+        match input.get(pos + 1..pos + 3) {
+            Some(&[hi, lo]) if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {}
+            _ => return Err(DecodeError::InvalidByte(b'%')),
+        }
+        input = &input[pos + 3..];
+    }
+    Ok(())
+}
+
+fn hex_value(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        b'A'..=b'F' => b - b'A' + 10,
+        _ => unreachable!("escapes are validated before decoding"),
+    }
+}
+
+// Yields runs of unescaped input, and each escaped byte as its own segment.
+#[derive(Clone)]
+struct PercentDecodedSegments<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Iterator for PercentDecodedSegments<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        match self.input {
+            [] => None,
+            [b'%', hi, lo, rest @ ..] => {
+                let byte = usize::from(hex_value(*hi) << 4 | hex_value(*lo));
+                self.input = rest;
+                Some(&BYTE_VALUES[byte..=byte])
+            }
+            input => {
+                let end = input.iter().position(|&b| b == b'%').unwrap_or(input.len());
+                let (segment, rest) = input.split_at(end);
+                self.input = rest;
+                Some(segment)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn segments() {
+        let segments: Vec<_> = PercentDecodedSegments {
+            input: b"ab%3D%2bc%2F",
+        }
+        .collect();
+        assert_eq!(segments, vec![&b"ab"[..], b"=", b"+", b"c", b"/"]);
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        for &input in &[&b"%"[..], b"AB%3", b"%3G", b"%%41"] {
+            assert_eq!(validate_escapes(input), Err(DecodeError::InvalidByte(b'%')));
+        }
+        assert_eq!(validate_escapes(b"%3D%3d"), Ok(()));
+    }
+}
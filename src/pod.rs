@@ -0,0 +1,73 @@
+use crate::{Config, DecodeError};
+use alloc::{vec, vec::Vec};
+use bytemuck::Pod;
+use core::{error, fmt};
+
+/// Errors that can occur when decoding a slice of plain old data values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PodDecodeError {
+    /// The input is not valid base64.
+    Decode(DecodeError),
+    /// The decoded data is not a whole number of values.
+    InvalidLength {
+        /// The number of decoded bytes.
+        decoded_len: usize,
+        /// The size of each value in bytes.
+        value_size: usize,
+    },
+}
+
+impl fmt::Display for PodDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PodDecodeError::Decode(ref err) => err.fmt(f),
+            PodDecodeError::InvalidLength {
+                decoded_len,
+                value_size,
+            } => write!(
+                f,
+                "decoded {} bytes, which is not a multiple of the {} byte value size",
+                decoded_len, value_size
+            ),
+        }
+    }
+}
+
+impl error::Error for PodDecodeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            PodDecodeError::Decode(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecodeError> for PodDecodeError {
+    fn from(err: DecodeError) -> Self {
+        PodDecodeError::Decode(err)
+    }
+}
+
+// Decode directly into a Vec<T>, which provides the alignment of T, with
+// enough values to hold the maximum decoded length of the input.
+pub(crate) fn decode_pod_vec<C, T>(config: C, input: &[u8]) -> Result<Vec<T>, PodDecodeError>
+where
+    C: Config,
+    T: Pod,
+{
+    let value_size = core::mem::size_of::<T>();
+    assert!(value_size > 0, "cannot decode zero-sized values");
+    let max_len = crate::decode::max_decoded_len(input.len());
+    let mut output = vec![T::zeroed(); max_len.div_ceil(value_size)];
+    let decoded_len =
+        crate::decode::decode_slice(config, input, bytemuck::cast_slice_mut(&mut output))?;
+    if decoded_len % value_size != 0 {
+        return Err(PodDecodeError::InvalidLength {
+            decoded_len,
+            value_size,
+        });
+    }
+    output.truncate(decoded_len / value_size);
+    Ok(output)
+}
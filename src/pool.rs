@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use std::sync::{Mutex, PoisonError};
+
+/// A pool of scratch buffers that can be shared between threads.
+///
+/// Buffers are checked out for use with methods like
+/// [encode_with_buffer](trait.Config.html#method.encode_with_buffer) and
+/// [decode_with_buffer](trait.Config.html#method.decode_with_buffer), and
+/// returned to the pool, cleared but with their capacity intact, when the
+/// [PooledBuffer](struct.PooledBuffer.html) is dropped. Once a buffer has
+/// grown to fit the largest input seen, later requests reuse it without
+/// allocating.
+///
+/// ```
+/// use radix64::{BufferPool, STD};
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let pool = Arc::new(BufferPool::new(16));
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let pool = Arc::clone(&pool);
+///         thread::spawn(move || {
+///             let mut buffer = pool.checkout();
+///             assert_eq!(STD.encode_with_buffer("hello", &mut buffer), "aGVsbG8=");
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert!(pool.idle() <= 4);
+/// ```
+pub struct BufferPool {
+    buffers: Mutex<Vec<Vec<u8>>>,
+    max_idle: usize,
+    max_capacity: usize,
+}
+
+impl BufferPool {
+    /// Create an empty pool that keeps at most `max_idle` buffers that are
+    /// not checked out. Buffers returned while the pool is full are freed.
+    pub fn new(max_idle: usize) -> Self {
+        BufferPool {
+            buffers: Mutex::new(Vec::new()),
+            max_idle,
+            max_capacity: usize::MAX,
+        }
+    }
+
+    /// Free returned buffers whose capacity exceeds `max_capacity` rather
+    /// than keeping them, so an occasional large input does not pin its
+    /// memory in the pool.
+    pub fn with_max_capacity(mut self, max_capacity: usize) -> Self {
+        self.max_capacity = max_capacity;
+        self
+    }
+
+    /// Check out an empty buffer, reusing an idle one if available.
+    pub fn checkout(&self) -> PooledBuffer<'_> {
+        let buffer = self.lock().pop().unwrap_or_default();
+        PooledBuffer { pool: self, buffer }
+    }
+
+    /// The number of buffers held by the pool that are not checked out.
+    pub fn idle(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn checkin(&self, mut buffer: Vec<u8>) {
+        if buffer.capacity() == 0 || buffer.capacity() > self.max_capacity {
+            return;
+        }
+        buffer.clear();
+        let mut buffers = self.lock();
+        if buffers.len() < self.max_idle {
+            buffers.push(buffer);
+        }
+    }
+
+    // The buffers are always left in a valid state, so a panic while the lock
+    // was held does not need to be propagated.
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Vec<u8>>> {
+        self.buffers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for BufferPool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BufferPool")
+            .field("idle", &self.idle())
+            .field("max_idle", &self.max_idle)
+            .field("max_capacity", &self.max_capacity)
+            .finish()
+    }
+}
+
+/// A buffer checked out from a [BufferPool](struct.BufferPool.html). It
+/// dereferences to a `Vec<u8>` and is returned to the pool when dropped.
+pub struct PooledBuffer<'a> {
+    pool: &'a BufferPool,
+    buffer: Vec<u8>,
+}
+
+impl<'a> PooledBuffer<'a> {
+    /// Take ownership of the buffer. It will not be returned to the pool.
+    pub fn detach(mut self) -> Vec<u8> {
+        core::mem::take(&mut self.buffer)
+    }
+}
+
+impl<'a> Deref for PooledBuffer<'a> {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.buffer
+    }
+}
+
+impl<'a> DerefMut for PooledBuffer<'a> {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.buffer
+    }
+}
+
+impl<'a> Drop for PooledBuffer<'a> {
+    fn drop(&mut self) {
+        self.pool.checkin(core::mem::take(&mut self.buffer));
+    }
+}
+
+impl<'a> fmt::Debug for PooledBuffer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("PooledBuffer").field(&self.buffer).finish()
+    }
+}
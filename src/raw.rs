@@ -0,0 +1,107 @@
+//! Low level primitives for packing bytes into 6-bit symbols.
+//!
+//! These are the building blocks used by the rest of the crate. They work on
+//! a single chunk at a time and leave framing, such as where chunks begin and
+//! end, padding of the final chunk, and line separators, to the caller. They
+//! suit protocols that interleave base64 symbols with other data. Most users
+//! should prefer the methods on [Config](../trait.Config.html).
+//!
+//! ```
+//! use radix64::raw;
+//! use radix64::STD;
+//!
+//! let mut encoded = [0; 4];
+//! raw::encode_chunk(STD, *b"abc", &mut encoded);
+//! assert_eq!(&encoded, b"YWJj");
+//!
+//! let mut decoded = [0; 3];
+//! raw::decode_chunk(STD, encoded, &mut decoded).unwrap();
+//! assert_eq!(&decoded, b"abc");
+//!
+//! // The final chunk of input may be shorter than 3 bytes.
+//! let mut encoded = [0; 4];
+//! assert_eq!(raw::encode_partial_chunk(STD, b"ab", &mut encoded), 4);
+//! assert_eq!(&encoded, b"YWI=");
+//! let mut decoded = [0; 2];
+//! assert_eq!(raw::decode_partial_chunk(STD, b"YWI", &mut decoded), Ok(2));
+//! assert_eq!(&decoded, b"ab");
+//! ```
+use crate::decode::INVALID_VALUE;
+use crate::{Config, DecodeError};
+
+pub use crate::u6::U6;
+
+/// Encode six bits as a single symbol of the config's alphabet.
+///
+/// ```
+/// use radix64::raw::{self, U6};
+/// use radix64::{STD, URL_SAFE};
+///
+/// assert_eq!(raw::encode_u6(STD, U6::from_low_six_bits(63)), b'/');
+/// assert_eq!(raw::encode_u6(URL_SAFE, U6::from_low_six_bits(63)), b'_');
+/// ```
+#[inline]
+pub fn encode_u6<C: Config>(config: C, input: U6) -> u8 {
+    config.encode_u6(input)
+}
+
+/// Decode a single symbol of the config's alphabet into six bits. Returns
+/// `None` if the byte is not part of the alphabet, including the padding
+/// byte.
+///
+/// ```
+/// use radix64::raw;
+/// use radix64::STD;
+///
+/// assert_eq!(raw::decode_u8(STD, b'/').map(u8::from), Some(63));
+/// assert!(raw::decode_u8(STD, b'=').is_none());
+/// ```
+#[inline]
+pub fn decode_u8<C: Config>(config: C, input: u8) -> Option<U6> {
+    match config.decode_u8(input) {
+        INVALID_VALUE => None,
+        decoded => Some(U6::from_low_six_bits(decoded)),
+    }
+}
+
+/// Encode 3 bytes of input as 4 symbols.
+#[inline]
+pub fn encode_chunk<C: Config>(config: C, input: [u8; 3], output: &mut [u8; 4]) {
+    crate::encode::encode_chunk(config, input, output)
+}
+
+/// Decode 4 symbols into 3 bytes of output. Returns
+/// `DecodeError::InvalidByte` if any symbol is not part of the config's
+/// alphabet, in which case the output is left unchanged.
+#[inline]
+pub fn decode_chunk<C: Config>(
+    config: C,
+    input: [u8; 4],
+    output: &mut [u8; 3],
+) -> Result<(), DecodeError> {
+    crate::decode::decode_chunk(config, input, output).map_err(DecodeError::InvalidByte)
+}
+
+/// Encode the final 0, 1 or 2 bytes of input, followed by padding if the
+/// config uses it. Returns the number of bytes written to output, which is at
+/// most 4. Panics if the input is longer than 2 bytes or the output is too
+/// small.
+#[inline]
+pub fn encode_partial_chunk<C: Config>(config: C, input: &[u8], output: &mut [u8]) -> usize {
+    assert!(input.len() < 3, "a partial chunk is at most 2 bytes");
+    crate::encode::encode_partial_chunk(config, input, output)
+}
+
+/// Decode the final 0, 2 or 3 symbols of input, which must have any padding
+/// already removed. Returns the number of bytes written to output, which is
+/// at most 2. The discarded trailing bits of the last symbol must be zero.
+/// Panics if the input is longer than 3 symbols or the output is too small.
+#[inline]
+pub fn decode_partial_chunk<C: Config>(
+    config: C,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, DecodeError> {
+    assert!(input.len() < 4, "a partial chunk is at most 3 symbols");
+    crate::decode::decode_partial_chunk(config, input, output)
+}
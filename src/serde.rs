@@ -0,0 +1,248 @@
+//! Helpers for using base64 with [serde](https://docs.rs/serde/1).
+//!
+//! # Fields
+//!
+//! Each builtin config has a module for use with `#[serde(with = "...")]`,
+//! which serializes a byte field, such as a `Vec<u8>` or `[u8; N]`, as a
+//! base64 string. Deserializing a fixed size array fails unless the string
+//! decodes to exactly its length.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Upload {
+//!     #[serde(with = "radix64::serde::std")]
+//!     data: Vec<u8>,
+//!     #[serde(with = "radix64::serde::url_safe_no_pad")]
+//!     digest: [u8; 4],
+//! }
+//!
+//! let upload = Upload { data: b"my message".to_vec(), digest: [0xfb, 0xff, 0, 1] };
+//! let json = serde_json::to_string(&upload).unwrap();
+//! assert_eq!(json, r#"{"data":"bXkgbWVzc2FnZQ==","digest":"-_8AAQ"}"#);
+//! assert_eq!(serde_json::from_str::<Upload>(&json).unwrap(), upload);
+//! ```
+//!
+//! # Typed fields
+//!
+//! [Base64](struct.Base64.html), re-exported here from the crate root,
+//! carries the config in its type, so a field declared as
+//! `Base64<Vec<u8>, UrlSafe>` is always encoded with that config without any
+//! attributes.
+//!
+//! ```
+//! use radix64::{configs::UrlSafe, serde::Base64};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Token {
+//!     nonce: Base64<Vec<u8>, UrlSafe>,
+//! }
+//!
+//! let token: Token = serde_json::from_str(r#"{"nonce":"-_8A"}"#).unwrap();
+//! assert_eq!(*token.nonce, [0xfb, 0xff, 0x00]);
+//! assert_eq!(serde_json::to_string(&token).unwrap(), r#"{"nonce":"-_8A"}"#);
+//! ```
+//!
+//! # Streaming large fields
+//!
+//! Deserializing a base64 field into a `Vec<u8>` holds the entire decoded
+//! payload in memory. [DecodeToWriter](struct.DecodeToWriter.html) instead
+//! decodes a string a block at a time into any `std::io::Write`, such as a
+//! file or a hasher, so documents with very large embedded attachments can be
+//! ingested with a fixed amount of memory for the decoded data. It requires
+//! the `std` feature.
+//!
+//! The encoded string itself is still provided by the deserializer. Formats
+//! that deserialize from a slice, such as `serde_json::from_slice`, lend the
+//! string without copying it, while readers buffer the string before handing
+//! it over.
+//!
+//! ```
+//! use radix64::{serde::DecodeToWriter, STD};
+//! use serde::de::DeserializeSeed;
+//!
+//! let mut de = serde_json::Deserializer::from_str(r#""bXkgbWVzc2FnZQ==""#);
+//! let mut sink = Vec::new();
+//! let written = DecodeToWriter::new(STD, &mut sink).deserialize(&mut de).unwrap();
+//! assert_eq!(written, 10);
+//! assert_eq!(sink, b"my message");
+//! ```
+//!
+//! Within a struct, use `MapAccess::next_value_seed` with a DecodeToWriter in
+//! a hand written `Deserialize` implementation to route a single field to the
+//! writer.
+pub use crate::wrapper::Base64;
+
+use crate::Config;
+#[cfg(feature = "std")]
+use crate::DecodeError;
+use ::alloc::vec::Vec;
+use ::core::convert::TryFrom;
+use ::core::fmt;
+use ::core::marker::PhantomData;
+#[cfg(feature = "std")]
+use ::serde::de::DeserializeSeed;
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::Serializer;
+#[cfg(feature = "std")]
+use ::std::io;
+
+macro_rules! define_with_modules {
+    ($( $name:ident => $config:ident ),+) => {$(
+        #[doc = concat!("Serialize and deserialize byte fields using the [", stringify!($config), "](../../constant.", stringify!($config), ".html) config.")]
+        pub mod $name {
+            /// Serialize the bytes as a base64 string.
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: AsRef<[u8]> + ?Sized,
+                S: ::serde::Serializer,
+            {
+                super::serialize_with(crate::$config, value.as_ref(), serializer)
+            }
+
+            /// Deserialize a base64 string into any type that can be
+            /// converted from the decoded `Vec<u8>`.
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: ::core::convert::TryFrom<::alloc::vec::Vec<u8>>,
+                D: ::serde::Deserializer<'de>,
+            {
+                super::deserialize_with(crate::$config, deserializer)
+            }
+        }
+    )+};
+}
+
+define_with_modules!(
+    std => STD,
+    std_no_pad => STD_NO_PAD,
+    url_safe => URL_SAFE,
+    url_safe_no_pad => URL_SAFE_NO_PAD,
+    crypt => CRYPT,
+    fast => FAST
+);
+
+fn serialize_with<C, S>(config: C, value: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    C: Config,
+    S: Serializer,
+{
+    serializer.collect_str(&crate::Display::new(config, &value))
+}
+
+fn deserialize_with<'de, C, T, D>(config: C, deserializer: D) -> Result<T, D::Error>
+where
+    C: Config,
+    T: TryFrom<Vec<u8>>,
+    D: Deserializer<'de>,
+{
+    struct FieldVisitor<C, T>(C, PhantomData<fn() -> T>);
+
+    impl<C, T> FieldVisitor<C, T>
+    where
+        C: Config,
+        T: TryFrom<Vec<u8>>,
+    {
+        fn decode<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+            let decoded = self.0.decode(v).map_err(E::custom)?;
+            let decoded_len = decoded.len();
+            T::try_from(decoded)
+                .map_err(|_| E::invalid_length(decoded_len, &"a field of the decoded length"))
+        }
+    }
+
+    impl<C, T> Visitor<'_> for FieldVisitor<C, T>
+    where
+        C: Config,
+        T: TryFrom<Vec<u8>>,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a base64 string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+            self.decode(v.as_bytes())
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+            self.decode(v)
+        }
+    }
+
+    deserializer.deserialize_str(FieldVisitor(config, PhantomData))
+}
+
+/// A `DeserializeSeed` that decodes a base64 string into a writer, producing
+/// the number of decoded bytes written.
+///
+/// Decoding errors and errors from the writer are reported as errors of the
+/// deserializer. Some of the decoded data may have been written before an
+/// error is detected.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct DecodeToWriter<C, W> {
+    config: C,
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<C, W> DecodeToWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    /// Create a DecodeToWriter that decodes using the provided config and
+    /// writes the decoded data to the writer.
+    pub fn new(config: C, writer: W) -> Self {
+        DecodeToWriter { config, writer }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, C, W> DeserializeSeed<'de> for DecodeToWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    type Value = u64;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, W> Visitor<'_> for DecodeToWriter<C, W>
+where
+    C: Config,
+    W: io::Write,
+{
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a base64 string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<u64, E> {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E: de::Error>(mut self, mut v: &[u8]) -> Result<u64, E> {
+        crate::io::copy_decode(self.config, &mut v, &mut self.writer).map_err(|err| {
+            match err
+                .get_ref()
+                .and_then(|err| err.downcast_ref::<DecodeError>())
+            {
+                Some(decode_err) => E::custom(decode_err),
+                None => E::custom(format_args!("failed to write decoded data: {}", err)),
+            }
+        })
+    }
+}
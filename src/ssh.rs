@@ -0,0 +1,241 @@
+//! Parsing and emitting OpenSSH public keys.
+//!
+//! A public key is written on a single line as `algorithm base64-blob
+//! comment`, as found in `id_ed25519.pub` and `authorized_keys` files. The blob
+//! is the key in the SSH wire format described in
+//! [RFC 4253](https://tools.ietf.org/html/rfc4253#section-6.6): a sequence of
+//! fields, each prefixed with its length as a big-endian `u32`, the first of
+//! which is the algorithm name. Blobs are encoded using the
+//! [STD](../constant.STD.html) config and decoding is strict, rejecting
+//! missing padding and non-zero trailing bits.
+//!
+//! ```
+//! use radix64::ssh::PublicKey;
+//!
+//! let line = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4f alice@example";
+//! let key = PublicKey::parse(line).unwrap();
+//! assert_eq!(key.algorithm(), "ssh-ed25519");
+//! assert_eq!(key.comment(), Some("alice@example"));
+//! let fields: Vec<&[u8]> = key.fields().collect();
+//! assert_eq!(fields[1], &(0..32).collect::<Vec<u8>>()[..]);
+//! assert_eq!(key.to_string(), line);
+//! ```
+use crate::{DecodeError, STD};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::convert::TryFrom;
+use core::{error, fmt};
+
+/// Errors that can occur when parsing OpenSSH public keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SshKeyError {
+    /// The line is not of the form `algorithm base64-blob [comment]`.
+    Malformed,
+    /// The blob is not valid base64.
+    Decode(DecodeError),
+    /// The blob is not a sequence of length-prefixed fields beginning with the
+    /// algorithm name.
+    InvalidBlob,
+    /// The algorithm named on the line differs from the one in the blob.
+    AlgorithmMismatch,
+}
+
+impl fmt::Display for SshKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SshKeyError::Malformed => write!(f, "malformed public key line"),
+            SshKeyError::Decode(ref err) => write!(f, "invalid public key blob: {}", err),
+            SshKeyError::InvalidBlob => write!(f, "invalid public key wire format"),
+            SshKeyError::AlgorithmMismatch => {
+                write!(f, "public key algorithm does not match its blob")
+            }
+        }
+    }
+}
+
+impl error::Error for SshKeyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            SshKeyError::Decode(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// An OpenSSH public key, consisting of the key blob in wire format and an
+/// optional comment. Displaying a PublicKey produces its single line form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PublicKey {
+    blob: Vec<u8>,
+    comment: Option<String>,
+}
+
+impl PublicKey {
+    /// Create a PublicKey from a blob in wire format and an optional comment.
+    /// Returns an error if the blob is not a valid sequence of fields.
+    ///
+    /// ```
+    /// use radix64::ssh::{self, PublicKey};
+    ///
+    /// let blob = ssh::encode_blob(&["ssh-ed25519".as_bytes(), &[0; 32]]);
+    /// let key = PublicKey::new(radix64::STD.decode(&blob).unwrap(), None).unwrap();
+    /// assert_eq!(key.to_string(), format!("ssh-ed25519 {}", blob));
+    /// ```
+    pub fn new(blob: Vec<u8>, comment: Option<String>) -> Result<Self, SshKeyError> {
+        algorithm_of(&blob)?;
+        Ok(PublicKey { blob, comment })
+    }
+
+    /// Parse a public key from its single line form. Surrounding whitespace
+    /// is ignored and the comment may contain spaces.
+    pub fn parse(line: &str) -> Result<Self, SshKeyError> {
+        let line = line.trim();
+        let (algorithm, rest) = split_token(line);
+        let (encoded, comment) = split_token(rest);
+        if algorithm.is_empty() || encoded.is_empty() {
+            return Err(SshKeyError::Malformed);
+        }
+        let blob = decode_blob(encoded)?;
+        if algorithm_of(&blob)? != algorithm {
+            return Err(SshKeyError::AlgorithmMismatch);
+        }
+        Ok(PublicKey {
+            blob,
+            comment: if comment.is_empty() {
+                None
+            } else {
+                Some(comment.to_owned())
+            },
+        })
+    }
+
+    /// The name of the key algorithm, such as `ssh-ed25519`.
+    pub fn algorithm(&self) -> &str {
+        algorithm_of(&self.blob).expect("blob is validated on construction")
+    }
+
+    /// The key blob in wire format.
+    pub fn blob(&self) -> &[u8] {
+        &self.blob
+    }
+
+    /// The comment following the key, if any.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// An iterator over the fields of the blob, without their length
+    /// prefixes. The first field is the algorithm name.
+    pub fn fields(&self) -> Fields<'_> {
+        Fields { blob: &self.blob }
+    }
+
+    /// Consume the PublicKey, returning the blob and the comment.
+    pub fn into_parts(self) -> (Vec<u8>, Option<String>) {
+        (self.blob, self.comment)
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.algorithm(),
+            crate::Display::new(STD, &self.blob)
+        )?;
+        if let Some(comment) = &self.comment {
+            write!(f, " {}", comment)?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the fields of a public key blob. Created by
+/// [PublicKey::fields](struct.PublicKey.html#method.fields).
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    blob: &'a [u8],
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let (field, rest) = split_field(self.blob)?;
+        self.blob = rest;
+        Some(field)
+    }
+}
+
+/// Encode the fields in wire format, each prefixed with its length, and
+/// return the base64 encoded blob.
+///
+/// # Panics
+///
+/// Panics if a field is longer than `u32::MAX` bytes.
+pub fn encode_blob<I>(fields: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut blob = Vec::new();
+    for field in fields {
+        let field = field.as_ref();
+        let len = u32::try_from(field.len()).expect("field too long for the wire format");
+        blob.extend_from_slice(&len.to_be_bytes());
+        blob.extend_from_slice(field);
+    }
+    STD.encode(&blob)
+}
+
+/// Decode a base64 blob and verify that it is a sequence of length-prefixed
+/// fields beginning with the algorithm name. Returns the decoded blob.
+///
+/// ```
+/// use radix64::ssh;
+///
+/// let blob = ssh::decode_blob("AAAAC3NzaC1lZDI1NTE5").unwrap();
+/// assert_eq!(&blob[4..], b"ssh-ed25519");
+/// assert!(ssh::decode_blob("AAAAC3NzaC1lZDI1").is_err());
+/// ```
+pub fn decode_blob(encoded: &str) -> Result<Vec<u8>, SshKeyError> {
+    let blob = STD.decode(encoded).map_err(SshKeyError::Decode)?;
+    algorithm_of(&blob)?;
+    Ok(blob)
+}
+
+// Split the first whitespace separated token from the rest of the input.
+fn split_token(input: &str) -> (&str, &str) {
+    match input.find(|c: char| c.is_ascii_whitespace()) {
+        Some(idx) => (&input[..idx], input[idx..].trim_start()),
+        None => (input, ""),
+    }
+}
+
+// Split the first length-prefixed field from the rest of the blob, returning
+// None if the blob is empty or truncated.
+fn split_field(blob: &[u8]) -> Option<(&[u8], &[u8])> {
+    if blob.len() < 4 {
+        return None;
+    }
+    let (len, rest) = blob.split_at(4);
+    // A length that doesn't fit in a usize can't fit in the blob either.
+    let len = usize::try_from(u32::from_be_bytes([len[0], len[1], len[2], len[3]])).ok()?;
+    if len > rest.len() {
+        return None;
+    }
+    Some(rest.split_at(len))
+}
+
+// Validate the framing of the blob and return the algorithm name.
+fn algorithm_of(blob: &[u8]) -> Result<&str, SshKeyError> {
+    let (algorithm, mut rest) = split_field(blob).ok_or(SshKeyError::InvalidBlob)?;
+    while !rest.is_empty() {
+        rest = split_field(rest).ok_or(SshKeyError::InvalidBlob)?.1;
+    }
+    if algorithm.is_empty() || !algorithm.iter().all(u8::is_ascii_graphic) {
+        return Err(SshKeyError::InvalidBlob);
+    }
+    Ok(core::str::from_utf8(algorithm).expect("ascii is valid utf-8"))
+}
@@ -1,5 +1,24 @@
-use crate::decode::INVALID_VALUE;
+//! The tables used by the builtin configs.
+//!
+//! These are the same tables the builtin configs use to encode and decode, and
+//! are part of the format of each config. They allow FFI layers and code
+//! generators to embed the alphabets without reconstructing them.
+//!
+//! ```
+//! use radix64::tables::{INVALID_VALUE, STD_DECODE, STD_ENCODE};
+//!
+//! assert_eq!(STD_ENCODE[62], b'+');
+//! assert_eq!(STD_DECODE[usize::from(b'+')], 62);
+//! assert_eq!(STD_DECODE[usize::from(b'=')], INVALID_VALUE);
+//! ```
 
+/// The value in the decode tables for bytes that are not part of the
+/// alphabet. Padding characters are not part of the alphabet.
+pub const INVALID_VALUE: u8 = 255;
+
+/// The encode table of [STD](../constant.STD.html) and
+/// [STD_NO_PAD](../constant.STD_NO_PAD.html), mapping each 6-bit value to its
+/// ascii character.
 #[rustfmt::skip]
 pub const STD_ENCODE: &[u8; 64] = &[
     65,            // input   0 (0x00) => 'A' (0x41)
@@ -67,6 +86,9 @@ pub const STD_ENCODE: &[u8; 64] = &[
     43,            // input  62 (0x3E) => '+' (0x2B)
     47,            // input  63 (0x3F) => '/' (0x2F)
 ];
+/// The decode table of [STD](../constant.STD.html) and
+/// [STD_NO_PAD](../constant.STD_NO_PAD.html), mapping each byte to its 6-bit
+/// value or [INVALID_VALUE](constant.INVALID_VALUE.html).
 #[rustfmt::skip]
 pub const STD_DECODE: &[u8; 256] = &[
     INVALID_VALUE, // input   0 (0x00)
@@ -326,6 +348,9 @@ pub const STD_DECODE: &[u8; 256] = &[
     INVALID_VALUE, // input 254 (0xFE)
     INVALID_VALUE, // input 255 (0xFF)
 ];
+/// The encode table of [URL_SAFE](../constant.URL_SAFE.html) and
+/// [URL_SAFE_NO_PAD](../constant.URL_SAFE_NO_PAD.html), mapping each 6-bit
+/// value to its ascii character.
 #[rustfmt::skip]
 pub const URL_SAFE_ENCODE: &[u8; 64] = &[
     65,            // input   0 (0x00) => 'A' (0x41)
@@ -393,6 +418,9 @@ pub const URL_SAFE_ENCODE: &[u8; 64] = &[
     45,            // input  62 (0x3E) => '-' (0x2D)
     95,            // input  63 (0x3F) => '_' (0x5F)
 ];
+/// The decode table of [URL_SAFE](../constant.URL_SAFE.html) and
+/// [URL_SAFE_NO_PAD](../constant.URL_SAFE_NO_PAD.html), mapping each byte to
+/// its 6-bit value or [INVALID_VALUE](constant.INVALID_VALUE.html).
 #[rustfmt::skip]
 pub const URL_SAFE_DECODE: &[u8; 256] = &[
     INVALID_VALUE, // input   0 (0x00)
@@ -652,6 +680,8 @@ pub const URL_SAFE_DECODE: &[u8; 256] = &[
     INVALID_VALUE, // input 254 (0xFE)
     INVALID_VALUE, // input 255 (0xFF)
 ];
+/// The encode table of [CRYPT](../constant.CRYPT.html), mapping each 6-bit
+/// value to its ascii character.
 #[rustfmt::skip]
 pub const CRYPT_ENCODE: &[u8; 64] = &[
     46,            // input   0 (0x00) => '.' (0x2E)
@@ -719,6 +749,8 @@ pub const CRYPT_ENCODE: &[u8; 64] = &[
     121,           // input  62 (0x3E) => 'y' (0x79)
     122,           // input  63 (0x3F) => 'z' (0x7A)
 ];
+/// The decode table of [CRYPT](../constant.CRYPT.html), mapping each byte to
+/// its 6-bit value or [INVALID_VALUE](constant.INVALID_VALUE.html).
 #[rustfmt::skip]
 pub const CRYPT_DECODE: &[u8; 256] = &[
     INVALID_VALUE, // input   0 (0x00)
@@ -978,6 +1010,8 @@ pub const CRYPT_DECODE: &[u8; 256] = &[
     INVALID_VALUE, // input 254 (0xFE)
     INVALID_VALUE, // input 255 (0xFF)
 ];
+/// The encode table of [FAST](../constant.FAST.html), mapping each 6-bit
+/// value to its ascii character.
 #[rustfmt::skip]
 pub const FAST_ENCODE: &[u8; 64] = &[
     62,            // input   0 (0x00) => '>' (0x3E)
@@ -1045,6 +1079,8 @@ pub const FAST_ENCODE: &[u8; 64] = &[
     124,           // input  62 (0x3E) => '|' (0x7C)
     125,           // input  63 (0x3F) => '}' (0x7D)
 ];
+/// The decode table of [FAST](../constant.FAST.html), mapping each byte to
+/// its 6-bit value or [INVALID_VALUE](constant.INVALID_VALUE.html).
 #[rustfmt::skip]
 pub const FAST_DECODE: &[u8; 256] = &[
     INVALID_VALUE, // input   0 (0x00)
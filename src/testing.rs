@@ -0,0 +1,134 @@
+//! Helpers for verifying that the SIMD backends agree with the portable scalar
+//! implementation.
+//!
+//! The builtin configs are encoded and decoded with the
+//! [active backend](../fn.active_backend.html), while a `CustomConfig` always
+//! uses the scalar implementation. The functions in this module run a config
+//! and an equivalent `CustomConfig` on the same input and panic if the results
+//! differ, which lets downstream wrappers and packagers on new architectures
+//! check the kernels selected on their host.
+//!
+//! ```
+//! radix64::testing::self_check();
+//! ```
+//!
+//! To check a backend other than the automatically selected one, select it
+//! with [set_backend](../fn.set_backend.html) first.
+use crate::u6::U6;
+use crate::{Config, CustomConfig, CRYPT, FAST, STD, STD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD};
+use alloc::vec::Vec;
+
+/// The test vectors from
+/// [RFC 4648 section 10](https://tools.ietf.org/html/rfc4648#section-10) as
+/// pairs of input and its encoding using the [STD](../constant.STD.html)
+/// config.
+pub const RFC4648_VECTORS: &[(&[u8], &str)] = &[
+    (b"", ""),
+    (b"f", "Zg=="),
+    (b"fo", "Zm8="),
+    (b"foo", "Zm9v"),
+    (b"foob", "Zm9vYg=="),
+    (b"fooba", "Zm9vYmE="),
+    (b"foobar", "Zm9vYmFy"),
+];
+
+/// Build a `CustomConfig` that encodes and decodes exactly like the provided
+/// config using the scalar implementation.
+pub fn scalar_reference<C: Config>(config: C) -> CustomConfig {
+    let alphabet: Vec<u8> = (0..64)
+        .map(|i| config.encode_u6(U6::from_low_six_bits(i)))
+        .collect();
+    let mut builder =
+        CustomConfig::with_alphabet(&alphabet).decode_padding(config.decode_padding());
+    builder = match config.padding_byte() {
+        Some(padding) => builder.with_padding(padding),
+        None => builder.no_padding(),
+    };
+    let line_wrap = config.line_wrap();
+    if let Some(line_wrap) = &line_wrap {
+        builder = builder.with_line_wrap(line_wrap.interval(), line_wrap.separator());
+    }
+    builder
+        .build()
+        .expect("every config can be expressed as a CustomConfig")
+}
+
+/// Encode the input with the config and its scalar reference, panicking if
+/// the encoded output differs.
+pub fn assert_encode_agrees<C: Config>(config: C, input: &[u8]) {
+    let reference = scalar_reference(config);
+    let encoded = config.encode(input);
+    let expected = reference.encode(input);
+    assert_eq!(
+        encoded,
+        expected,
+        "{} backend encoding of {:?} differs from the scalar implementation",
+        crate::active_backend(),
+        input
+    );
+}
+
+/// Decode the input with the config and its scalar reference, panicking if
+/// either the decoded output or the error differs. The input does not need to
+/// be valid base64.
+pub fn assert_decode_agrees<C: Config>(config: C, input: &[u8]) {
+    let reference = scalar_reference(config);
+    let decoded = config.decode(input);
+    let expected = reference.decode(input);
+    assert_eq!(
+        decoded,
+        expected,
+        "{} backend decoding of {:?} differs from the scalar implementation",
+        crate::active_backend(),
+        input
+    );
+}
+
+/// Check that the config agrees with its scalar reference when encoding the
+/// input, decoding the encoded output back to the input, and decoding the
+/// input itself as base64.
+pub fn assert_backends_agree<C: Config>(config: C, input: &[u8]) {
+    assert_encode_agrees(config, input);
+    let encoded = config.encode(input);
+    assert_decode_agrees(config, encoded.as_bytes());
+    assert_eq!(
+        config.decode(&encoded).as_ref().map(Vec::as_slice),
+        Ok(input),
+        "{} backend failed to round trip {:?}",
+        crate::active_backend(),
+        input
+    );
+    assert_decode_agrees(config, input);
+}
+
+/// Run the RFC 4648 test vectors and a deterministic set of inputs through
+/// every builtin config on the active backend, panicking on the first result
+/// that differs from the scalar implementation.
+///
+/// The inputs cover every length up to several SIMD blocks, as well as
+/// encoded data with a single invalid byte at each position.
+pub fn self_check() {
+    for &(input, encoded) in RFC4648_VECTORS {
+        assert_eq!(STD.encode(input), encoded);
+        assert_eq!(STD.decode(encoded).as_ref().map(Vec::as_slice), Ok(input));
+    }
+    self_check_config(STD);
+    self_check_config(STD_NO_PAD);
+    self_check_config(URL_SAFE);
+    self_check_config(URL_SAFE_NO_PAD);
+    self_check_config(CRYPT);
+    self_check_config(FAST);
+}
+
+fn self_check_config<C: Config>(config: C) {
+    let input: Vec<u8> = (0..200u32).map(|i| (i * 167 + i / 7) as u8).collect();
+    for len in 0..=input.len() {
+        assert_backends_agree(config, &input[..len]);
+    }
+    let encoded = config.encode(&input).into_bytes();
+    for idx in 0..encoded.len() {
+        let mut invalid = encoded.clone();
+        invalid[idx] = b'\xff';
+        assert_decode_agrees(config, &invalid);
+    }
+}
@@ -3,22 +3,24 @@
 pub struct U6(u8);
 
 impl U6 {
+    /// Create a U6 from the six least significant bits of `x`, discarding the
+    /// two most significant bits.
     #[inline]
     pub const fn from_low_six_bits(x: u8) -> U6 {
         U6(x & 0x3f)
     }
 }
 
-impl Into<usize> for U6 {
+impl From<U6> for usize {
     #[inline]
-    fn into(self) -> usize {
-        <Self as Into<u8>>::into(self).into()
+    fn from(x: U6) -> usize {
+        u8::from(x).into()
     }
 }
 
-impl Into<u8> for U6 {
+impl From<U6> for u8 {
     #[inline]
-    fn into(self) -> u8 {
-        self.0
+    fn from(x: U6) -> u8 {
+        x.0
     }
 }
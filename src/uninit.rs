@@ -0,0 +1,37 @@
+//! Helpers for writing output into memory that may be uninitialized.
+//!
+//! The encoding and decoding kernels write their output through
+//! `&mut [MaybeUninit<u8>]`, so they can fill uninitialized buffers without
+//! ever creating a `&mut [u8]` over uninitialized bytes. Initialized buffers
+//! are handed to them with `as_uninit_mut`, and an uninitialized buffer is
+//! only viewed as `&mut [u8]` for the prefix the kernels report as written.
+use core::mem::MaybeUninit;
+
+/// View an initialized slice as possibly uninitialized memory.
+///
+/// # Safety
+///
+/// Only initialized bytes may be written through the returned slice, so that
+/// the original slice remains initialized.
+#[inline]
+pub(crate) unsafe fn as_uninit_mut(slice: &mut [u8]) -> &mut [MaybeUninit<u8>] {
+    &mut *(slice as *mut [u8] as *mut [MaybeUninit<u8>])
+}
+
+/// View a slice of written bytes as initialized.
+///
+/// # Safety
+///
+/// Every byte of the slice must have been initialized.
+#[inline]
+pub(crate) unsafe fn assume_init_mut(slice: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    &mut *(slice as *mut [MaybeUninit<u8>] as *mut [u8])
+}
+
+/// Copy `src` to the front of `dst`. Panics if `dst` is shorter than `src`.
+#[inline]
+pub(crate) fn write_slice(dst: &mut [MaybeUninit<u8>], src: &[u8]) {
+    for (dst, &src) in dst[..src.len()].iter_mut().zip(src) {
+        *dst = MaybeUninit::new(src);
+    }
+}
@@ -0,0 +1,44 @@
+//! Integration with web frameworks.
+//!
+//! With the `axum` or `actix-web` feature enabled, [Base64](../struct.Base64.html)
+//! can be used as an extractor that decodes the request body and as a
+//! response whose body is the encoded value, sent as `text/plain`. Request
+//! bodies that are not valid base64 are rejected with `400 Bad Request`.
+//!
+//! Path and query parameters are decoded by deserializing them as a
+//! `Base64`, which the `serde` feature (enabled by both web features)
+//! supports. Parameters that fail to deserialize are rejected by the
+//! framework: axum responds with `400 Bad Request`, while actix-web responds
+//! to invalid query parameters with `400 Bad Request` and to invalid path
+//! parameters with `404 Not Found` unless configured otherwise with
+//! `PathConfig`.
+//!
+//! ```
+//! # #[cfg(feature = "axum")]
+//! # {
+//! use axum::{extract::Path, routing::post, Router};
+//! use radix64::{configs::UrlSafeNoPad, Base64};
+//!
+//! // Accepts a url safe base64 key in the path and a standard base64 body,
+//! // responding with the body xored with the key as standard base64.
+//! async fn xor(
+//!     Path(key): Path<Base64<Vec<u8>, UrlSafeNoPad>>,
+//!     body: Base64<Vec<u8>>,
+//! ) -> Base64<Vec<u8>> {
+//!     let mut data = body.into_inner();
+//!     for (b, k) in data.iter_mut().zip(key.iter().cycle()) {
+//!         *b ^= k;
+//!     }
+//!     Base64::new(data)
+//! }
+//!
+//! let app: Router = Router::new().route("/xor/{key}", post(xor));
+//! # }
+//! ```
+#[cfg(feature = "actix-web")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+
+#[cfg(feature = "axum")]
+pub use self::axum::Base64Rejection;
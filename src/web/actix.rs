@@ -0,0 +1,42 @@
+use crate::{Base64, Config};
+use actix_web::body::BoxBody;
+use actix_web::dev::Payload;
+use actix_web::http::header::ContentType;
+use actix_web::web::Bytes;
+use actix_web::{error, FromRequest, HttpRequest, HttpResponse, Responder};
+use alloc::{boxed::Box, format, vec::Vec};
+use core::future::Future;
+use core::pin::Pin;
+
+impl<T, C> FromRequest for Base64<T, C>
+where
+    Vec<u8>: Into<T>,
+    C: Config + Default,
+{
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let body = Bytes::from_request(req, payload);
+        Box::pin(async move {
+            let body = body.await?;
+            Base64::decode(&body).map_err(|err| {
+                error::ErrorBadRequest(format!("invalid base64 request body: {}", err))
+            })
+        })
+    }
+}
+
+impl<T, C> Responder for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+{
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        HttpResponse::Ok()
+            .content_type(ContentType::plaintext())
+            .body(self.encode())
+    }
+}
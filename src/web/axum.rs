@@ -0,0 +1,75 @@
+use crate::{Base64, Config, DecodeError};
+use alloc::{string::ToString, vec::Vec};
+use axum_core::extract::rejection::BytesRejection;
+use axum_core::extract::{FromRequest, Request};
+use axum_core::response::{IntoResponse, Response};
+use bytes::Bytes;
+use core::{error, fmt};
+use http::StatusCode;
+
+/// Rejection used when extracting a [Base64](../struct.Base64.html) from a
+/// request body with axum.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Base64Rejection {
+    /// The request body could not be read.
+    Body(BytesRejection),
+    /// The request body is not valid base64.
+    Decode(DecodeError),
+}
+
+impl fmt::Display for Base64Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Base64Rejection::Body(ref rejection) => fmt::Display::fmt(rejection, f),
+            Base64Rejection::Decode(ref err) => write!(f, "invalid base64 request body: {}", err),
+        }
+    }
+}
+
+impl error::Error for Base64Rejection {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Base64Rejection::Body(ref rejection) => Some(rejection),
+            Base64Rejection::Decode(ref err) => Some(err),
+        }
+    }
+}
+
+impl IntoResponse for Base64Rejection {
+    fn into_response(self) -> Response {
+        match self {
+            Base64Rejection::Body(rejection) => rejection.into_response(),
+            Base64Rejection::Decode(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+            }
+        }
+    }
+}
+
+impl<S, T, C> FromRequest<S> for Base64<T, C>
+where
+    S: Send + Sync,
+    Vec<u8>: Into<T>,
+    C: Config + Default,
+{
+    type Rejection = Base64Rejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(Base64Rejection::Body)?;
+        Base64::decode(&body).map_err(Base64Rejection::Decode)
+    }
+}
+
+impl<T, C> IntoResponse for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+{
+    fn into_response(self) -> Response {
+        // A String response is sent as text/plain; charset=utf-8.
+        self.encode().into_response()
+    }
+}
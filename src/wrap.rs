@@ -0,0 +1,376 @@
+//! Support for inserting line separators into encoded output at a fixed
+//! interval and skipping them when decoding.
+use crate::uninit::write_slice;
+use core::fmt;
+use core::mem::MaybeUninit;
+
+/// The maximum length of a line separator.
+pub(crate) const MAX_SEPARATOR_LEN: usize = 8;
+
+/// A separator that is inserted after every `interval` bytes of encoded output.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LineWrap {
+    interval: usize,
+    separator: [u8; MAX_SEPARATOR_LEN],
+    separator_len: usize,
+}
+
+impl LineWrap {
+    /// Create a new LineWrap. The caller is responsible for ensuring that the
+    /// interval is non-zero and the separator is between 1 and
+    /// MAX_SEPARATOR_LEN bytes.
+    pub(crate) fn new(interval: usize, separator: &[u8]) -> Self {
+        debug_assert!(interval > 0);
+        debug_assert!(!separator.is_empty() && separator.len() <= MAX_SEPARATOR_LEN);
+        let mut sep = [0; MAX_SEPARATOR_LEN];
+        sep[..separator.len()].copy_from_slice(separator);
+        LineWrap {
+            interval,
+            separator: sep,
+            separator_len: separator.len(),
+        }
+    }
+
+    /// The number of encoded bytes in each line.
+    #[inline]
+    pub(crate) fn interval(&self) -> usize {
+        self.interval
+    }
+
+    /// The separator bytes.
+    #[inline]
+    pub(crate) fn separator(&self) -> &[u8] {
+        &self.separator[..self.separator_len]
+    }
+
+    /// The number of bytes the separators add to `encoded_len` bytes of
+    /// unwrapped output. No separator follows the final line.
+    #[inline]
+    pub(crate) fn separators_len(&self, encoded_len: usize) -> usize {
+        encoded_len.saturating_sub(1) / self.interval * self.separator_len
+    }
+
+    /// Insert separators into the first `encoded_len` bytes of `buf`, shifting
+    /// the encoded data towards the end of `buf`. Returns the length of the
+    /// wrapped output. Panics if `buf` is not large enough to hold it.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn insert_separators(&self, buf: &mut [u8], encoded_len: usize) -> usize {
+        // Only initialized bytes are written.
+        self.insert_separators_uninit(unsafe { crate::uninit::as_uninit_mut(buf) }, encoded_len)
+    }
+
+    /// The same as `insert_separators`, for a buffer whose first
+    /// `encoded_len` bytes are initialized. The wrapped output is initialized
+    /// when this returns.
+    pub(crate) fn insert_separators_uninit(
+        &self,
+        buf: &mut [MaybeUninit<u8>],
+        encoded_len: usize,
+    ) -> usize {
+        let num_separators = encoded_len.saturating_sub(1) / self.interval;
+        let wrapped_len = encoded_len + self.separators_len(encoded_len);
+        assert!(
+            wrapped_len <= buf.len(),
+            "output buffer too small for line wrapped output"
+        );
+        // Working backwards, move each line to its final position and write
+        // the separator preceding it.
+        let mut src_end = encoded_len;
+        let mut dst_end = wrapped_len;
+        let mut line_len = encoded_len - num_separators * self.interval;
+        for _ in 0..num_separators {
+            let src_start = src_end - line_len;
+            let dst_start = dst_end - line_len;
+            buf.copy_within(src_start..src_end, dst_start);
+            write_slice(
+                &mut buf[dst_start - self.separator_len..dst_start],
+                self.separator(),
+            );
+            src_end = src_start;
+            dst_end = dst_start - self.separator_len;
+            line_len = self.interval;
+        }
+        debug_assert_eq!(src_end, dst_end);
+        wrapped_len
+    }
+}
+
+impl fmt::Debug for LineWrap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LineWrap")
+            .field("interval", &self.interval)
+            .field("separator", &self.separator())
+            .finish()
+    }
+}
+
+/// Tracks the position within the current line for output that is produced
+/// incrementally. Separators are emitted lazily, before the first byte of the
+/// next line, so that output never ends with a separator.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct LineState {
+    column: usize,
+    separator_bytes_written: usize,
+}
+
+impl LineState {
+    /// Returns the portion of the separator that still needs to be written
+    /// before any more encoded data, or None if encoded data can be written.
+    #[inline]
+    pub(crate) fn pending_separator<'a>(&self, wrap: &'a LineWrap) -> Option<&'a [u8]> {
+        if self.column == wrap.interval {
+            Some(&wrap.separator()[self.separator_bytes_written..])
+        } else {
+            None
+        }
+    }
+
+    /// Record that `n` bytes of the pending separator were written.
+    #[inline]
+    pub(crate) fn separator_written(&mut self, wrap: &LineWrap, n: usize) {
+        self.separator_bytes_written += n;
+        if self.separator_bytes_written == wrap.separator_len {
+            self.column = 0;
+            self.separator_bytes_written = 0;
+        }
+    }
+
+    /// The maximum number of encoded bytes that can be written before a
+    /// separator is required.
+    #[inline]
+    pub(crate) fn remaining_in_line(&self, wrap: &LineWrap) -> usize {
+        wrap.interval - self.column
+    }
+
+    /// Record that `n` bytes of encoded data were written.
+    #[inline]
+    pub(crate) fn data_written(&mut self, n: usize) {
+        self.column += n;
+    }
+
+    /// Write `data` to the formatter, inserting separators as needed.
+    pub(crate) fn write_fmt<W>(&mut self, wrap: &LineWrap, mut data: &str, f: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        while !data.is_empty() {
+            if let Some(separator) = self.pending_separator(wrap) {
+                // Separators are validated to be ascii.
+                debug_assert!(separator.iter().all(u8::is_ascii));
+                f.write_str(unsafe { core::str::from_utf8_unchecked(separator) })?;
+                self.separator_written(wrap, separator.len());
+            }
+            let n = core::cmp::min(self.remaining_in_line(wrap), data.len());
+            f.write_str(&data[..n])?;
+            self.data_written(n);
+            data = &data[n..];
+        }
+        Ok(())
+    }
+}
+
+/// Split the input on occurrences of separator, yielding the non-empty slices
+/// in between.
+pub(crate) fn split_separators<'a>(
+    input: &'a [u8],
+    separator: &'a [u8],
+) -> impl Iterator<Item = &'a [u8]> + Clone + 'a {
+    SplitSeparators { input, separator }.filter(|segment| !segment.is_empty())
+}
+
+#[derive(Clone)]
+struct SplitSeparators<'a> {
+    input: &'a [u8],
+    separator: &'a [u8],
+}
+
+impl<'a> Iterator for SplitSeparators<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.input.is_empty() {
+            return None;
+        }
+        match find_separator(self.input, self.separator) {
+            Some(start) => {
+                let segment = &self.input[..start];
+                self.input = &self.input[start + self.separator.len()..];
+                Some(segment)
+            }
+            None => {
+                let segment = self.input;
+                self.input = &[];
+                Some(segment)
+            }
+        }
+    }
+}
+
+/// Split the concatenation of the segments on occurrences of separator,
+/// yielding the non-empty slices in between. A separator may straddle any
+/// number of segments.
+#[cfg(feature = "alloc")]
+pub(crate) fn split_segment_separators<'a, 's, S>(
+    segments: S,
+    separator: &'s [u8],
+) -> impl Iterator<Item = &'a [u8]> + Clone + 's
+where
+    'a: 's,
+    S: Iterator<Item = &'a [u8]> + Clone + 's,
+{
+    SplitSegmentSeparators {
+        segment: &[],
+        segments,
+        separator,
+    }
+    .filter(|segment| !segment.is_empty())
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+struct SplitSegmentSeparators<'a, 's, S> {
+    segment: &'a [u8],
+    segments: S,
+    separator: &'s [u8],
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 's, S> Iterator for SplitSegmentSeparators<'a, 's, S>
+where
+    S: Iterator<Item = &'a [u8]> + Clone,
+{
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        while self.segment.is_empty() {
+            self.segment = self.segments.next()?;
+        }
+        if let Some(start) = find_separator(self.segment, self.separator) {
+            let segment = &self.segment[..start];
+            self.segment = &self.segment[start + self.separator.len()..];
+            return Some(segment);
+        }
+        // Every separator that begins in the final bytes of the segment and
+        // continues into the following segments starts after any separator
+        // found within the segment.
+        let first_start = self.segment.len().saturating_sub(self.separator.len() - 1);
+        for start in first_start..self.segment.len() {
+            let (head, rest) = self.separator.split_at(self.segment.len() - start);
+            if self.segment[start..] != *head {
+                continue;
+            }
+            if let Some((segment, segments)) = strip_prefix(self.segments.clone(), rest) {
+                let before = &self.segment[..start];
+                self.segment = segment;
+                self.segments = segments;
+                return Some(before);
+            }
+        }
+        Some(core::mem::take(&mut self.segment))
+    }
+}
+
+// If the concatenation of the segments begins with the non-empty prefix,
+// return the remainder of the segment that the prefix ends in along with the
+// segments that follow it.
+#[cfg(feature = "alloc")]
+fn strip_prefix<'a, S>(mut segments: S, mut prefix: &[u8]) -> Option<(&'a [u8], S)>
+where
+    S: Iterator<Item = &'a [u8]>,
+{
+    loop {
+        let segment = segments.next()?;
+        let n = core::cmp::min(segment.len(), prefix.len());
+        if segment[..n] != prefix[..n] {
+            return None;
+        }
+        if n == prefix.len() {
+            return Some((&segment[n..], segments));
+        }
+        prefix = &prefix[n..];
+    }
+}
+
+// The position of the first occurrence of separator within input.
+fn find_separator(input: &[u8], separator: &[u8]) -> Option<usize> {
+    let first = separator[0];
+    let mut idx = 0;
+    while let Some(pos) = input[idx..].iter().position(|&b| b == first) {
+        let start = idx + pos;
+        if input[start..].starts_with(separator) {
+            return Some(start);
+        }
+        idx = start + 1;
+    }
+    None
+}
+
+/// Remove every occurrence of separator from buf, moving the remaining bytes to
+/// the front. Returns the number of bytes remaining.
+pub(crate) fn remove_separators(buf: &mut [u8], separator: &[u8]) -> usize {
+    let mut read_idx = 0;
+    let mut write_idx = 0;
+    while let Some(pos) = find_separator(&buf[read_idx..], separator) {
+        buf.copy_within(read_idx..read_idx + pos, write_idx);
+        write_idx += pos;
+        read_idx += pos + separator.len();
+    }
+    buf.copy_within(read_idx.., write_idx);
+    write_idx + buf.len() - read_idx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn insert_separators() {
+        let wrap = LineWrap::new(4, b"\r\n");
+        for &(input, expected) in &[
+            ("", ""),
+            ("ABC", "ABC"),
+            ("ABCD", "ABCD"),
+            ("ABCDE", "ABCD\r\nE"),
+            ("ABCDEFGH", "ABCD\r\nEFGH"),
+            ("ABCDEFGHIJ", "ABCD\r\nEFGH\r\nIJ"),
+        ] {
+            let mut buf = vec![0; 32];
+            buf[..input.len()].copy_from_slice(input.as_bytes());
+            let n = wrap.insert_separators(&mut buf, input.len());
+            assert_eq!(expected.as_bytes(), &buf[..n]);
+            assert_eq!(input.len() + wrap.separators_len(input.len()), n);
+        }
+    }
+
+    #[test]
+    fn remove() {
+        let mut buf = *b"AB\r\nCD\r\r\n\r\nE\r";
+        let n = remove_separators(&mut buf, b"\r\n");
+        assert_eq!(&buf[..n], b"ABCD\rE\r");
+    }
+
+    #[test]
+    fn split() {
+        let segments: Vec<_> = split_separators(b"AB\r\nCD\r\r\n\r\nE\r", b"\r\n").collect();
+        assert_eq!(segments, vec![&b"AB"[..], b"CD\r", b"E\r"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn split_segments() {
+        let input = b"AB\r\nCD\r\r\n\r\nE\r";
+        for size in 1..input.len() {
+            let joined: Vec<u8> = split_segment_separators(input.chunks(size), b"\r\n")
+                .flatten()
+                .cloned()
+                .collect();
+            assert_eq!(joined, b"ABCD\rE\r");
+        }
+        let segments: [&[u8]; 5] = [b"AB\r", b"", b"\nC", b"D\r", b"\rE"];
+        let segments: Vec<_> =
+            split_segment_separators(segments.iter().cloned(), b"\r\n").collect();
+        assert_eq!(segments, vec![&b"AB"[..], b"C", b"D\r", b"\rE"]);
+    }
+}
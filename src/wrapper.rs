@@ -0,0 +1,267 @@
+use crate::configs::Std;
+use crate::Config;
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// A value that is represented as base64 when serialized or received over
+/// the web, encoded and decoded using the config type `C`.
+///
+/// With the `serde` feature enabled, Base64 serializes the wrapped bytes as a
+/// base64 string and deserializes from one. With the `axum` or `actix-web`
+/// features it can also be used as a request extractor that decodes the
+/// request body, and as a response whose body is the encoded value.
+///
+/// The `borsh` and `rkyv` features implement those binary formats in the same
+/// way: the value is stored as the encoded string, and deserializing it
+/// returns an error unless the string decodes with the config. An archived
+/// rkyv value is an `ArchivedString` that can be read without
+/// deserializing.
+///
+/// ```
+/// use radix64::{configs::UrlSafeNoPad, Base64};
+///
+/// let token: Base64<Vec<u8>, UrlSafeNoPad> = Base64::new(vec![0xfb, 0xff]);
+/// assert_eq!(token.to_string(), "-_8");
+/// assert_eq!(token.into_inner(), [0xfb, 0xff]);
+/// ```
+pub struct Base64<T, C = Std> {
+    value: T,
+    // fn() -> C keeps Base64 Send and Sync regardless of C.
+    config: PhantomData<fn() -> C>,
+}
+
+impl<T, C> Base64<T, C> {
+    /// Wrap the provided value.
+    pub fn new(value: T) -> Self {
+        Base64 {
+            value,
+            config: PhantomData,
+        }
+    }
+
+    /// Consume the Base64, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, C> Base64<T, C>
+where
+    C: Config + Default,
+{
+    /// Decode the input using the config, converting the decoded bytes into
+    /// the wrapped type.
+    pub fn decode<I>(input: &I) -> Result<Self, crate::DecodeError>
+    where
+        I: AsRef<[u8]> + ?Sized,
+        Vec<u8>: Into<T>,
+    {
+        C::default()
+            .decode(input)
+            .map(|decoded| Base64::new(decoded.into()))
+    }
+}
+
+impl<T, C> Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+{
+    /// Encode the wrapped value using the config.
+    pub fn encode(&self) -> String {
+        C::default().encode(&self.value)
+    }
+}
+
+impl<T, C> From<T> for Base64<T, C> {
+    fn from(value: T) -> Self {
+        Base64::new(value)
+    }
+}
+
+impl<T, C> Deref for Base64<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, C> DerefMut for Base64<T, C> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T, C> AsRef<[u8]> for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.value.as_ref()
+    }
+}
+
+impl<T: Clone, C> Clone for Base64<T, C> {
+    fn clone(&self) -> Self {
+        Base64::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, C> Copy for Base64<T, C> {}
+
+impl<T: Default, C> Default for Base64<T, C> {
+    fn default() -> Self {
+        Base64::new(T::default())
+    }
+}
+
+impl<T: PartialEq, C> PartialEq for Base64<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, C> Eq for Base64<T, C> {}
+
+impl<T: core::hash::Hash, C> core::hash::Hash for Base64<T, C> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T: fmt::Debug, C> fmt::Debug for Base64<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Base64").field(&self.value).finish()
+    }
+}
+
+/// Displays the encoded value.
+impl<T, C> fmt::Display for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&crate::Display::new(C::default(), &self.value), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, C> serde::Serialize for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.encode())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, C> serde::Deserialize<'de> for Base64<T, C>
+where
+    Vec<u8>: Into<T>,
+    C: Config + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<T, C>(PhantomData<fn() -> (T, C)>);
+
+        impl<T, C> serde::de::Visitor<'_> for Visitor<T, C>
+        where
+            Vec<u8>: Into<T>,
+            C: Config + Default,
+        {
+            type Value = Base64<T, C>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a base64 string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Base64::decode(v).map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Base64::decode(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor(PhantomData))
+    }
+}
+
+// Base64 is written to binary formats as the encoded string, so decoding it
+// again on deserialize rejects any value that is not valid for the config.
+#[cfg(feature = "borsh")]
+impl<T, C> borsh::BorshSerialize for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+{
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        borsh::BorshSerialize::serialize(&self.encode(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T, C> borsh::BorshDeserialize for Base64<T, C>
+where
+    Vec<u8>: Into<T>,
+    C: Config + Default,
+{
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let encoded = <String as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        Base64::decode(&encoded)
+            .map_err(|err| borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, C> rkyv::Archive for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+{
+    type Archived = rkyv::string::ArchivedString;
+    type Resolver = rkyv::string::StringResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        rkyv::string::ArchivedString::resolve_from_str(&self.encode(), resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, C, S> rkyv::Serialize<S> for Base64<T, C>
+where
+    T: AsRef<[u8]>,
+    C: Config + Default,
+    S: rkyv::rancor::Fallible + rkyv::ser::Writer + ?Sized,
+    S::Error: rkyv::rancor::Source,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        rkyv::string::ArchivedString::serialize_from_str(&self.encode(), serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, C, D> rkyv::Deserialize<Base64<T, C>, D> for rkyv::string::ArchivedString
+where
+    T: AsRef<[u8]>,
+    Vec<u8>: Into<T>,
+    C: Config + Default,
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<Base64<T, C>, D::Error> {
+        Base64::decode(self.as_str()).map_err(<D::Error as rkyv::rancor::Source>::new)
+    }
+}
@@ -48,3 +48,104 @@ test_cfg!(
     base64_cfg = URL_SAFE_NO_PAD
 );
 test_cfg!(crypt, radix_cfg = CRYPT, base64_cfg = CRYPT);
+
+mod compat {
+    use proptest::prelude::{any, proptest};
+    use radix64::compat;
+
+    proptest! {
+        #[test]
+        fn functions_match_base64(input in any::<Vec<u8>>()) {
+            assert_eq!(compat::encode(&input), base64::encode(&input));
+            assert_eq!(
+                compat::encode_config(&input, compat::URL_SAFE_NO_PAD),
+                base64::encode_config(&input, base64::URL_SAFE_NO_PAD)
+            );
+
+            let mut radix64_buf = String::from("prefix");
+            let mut base64_buf = radix64_buf.clone();
+            compat::encode_config_buf(&input, compat::CRYPT, &mut radix64_buf);
+            base64::encode_config_buf(&input, base64::CRYPT, &mut base64_buf);
+            assert_eq!(radix64_buf, base64_buf);
+
+            let size = compat::encoded_size(input.len(), compat::URL_SAFE).unwrap();
+            let mut output = vec![0; size];
+            assert_eq!(compat::encode_config_slice(&input, compat::URL_SAFE, &mut output), size);
+            assert_eq!(output, base64::encode_config(&input, base64::URL_SAFE).into_bytes());
+
+            let encoded = base64::encode_config(&input, base64::STANDARD_NO_PAD);
+            assert_eq!(compat::decode(&base64::encode(&input)), Ok(input.clone()));
+            assert_eq!(compat::decode_config(&encoded, compat::STANDARD_NO_PAD), Ok(input.clone()));
+            let mut buffer = vec![1];
+            compat::decode_config_buf(&encoded, compat::STANDARD_NO_PAD, &mut buffer).unwrap();
+            assert_eq!(&buffer[1..], &input[..]);
+            let mut output = vec![0; input.len()];
+            assert_eq!(
+                compat::decode_config_slice(&encoded, compat::STANDARD_NO_PAD, &mut output),
+                Ok(input.len())
+            );
+            assert_eq!(output, input);
+        }
+    }
+
+    #[test]
+    fn encoded_size_reports_overflow() {
+        assert_eq!(compat::encoded_size(usize::MAX, compat::STANDARD), None);
+        assert_eq!(compat::encoded_size(5, compat::STANDARD), Some(8));
+        assert_eq!(compat::encoded_size(5, compat::STANDARD_NO_PAD), Some(7));
+    }
+}
+
+mod engine {
+    use proptest::prelude::{any, proptest};
+    use radix64::compat::prelude::*;
+
+    fn roundtrip<E: Engine>(engine: &E, input: &[u8]) -> Vec<u8> {
+        let mut encoded = String::from("prefix");
+        engine.encode_string(input, &mut encoded);
+        assert_eq!(&encoded["prefix".len()..], engine.encode(input));
+        let mut output = vec![0; input.len()];
+        assert_eq!(
+            engine.decode_slice(&encoded["prefix".len()..], &mut output),
+            Ok(input.len())
+        );
+        engine.decode(&encoded["prefix".len()..]).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn engines_match_base64(input in any::<Vec<u8>>()) {
+            assert_eq!(BASE64_STANDARD.encode(&input), base64::encode(&input));
+            assert_eq!(roundtrip(&BASE64_STANDARD, &input), input);
+            assert_eq!(roundtrip(&BASE64_URL_SAFE_NO_PAD, &input), input);
+            let custom = radix64::CustomConfig::with_alphabet(
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            )
+            .build()
+            .unwrap();
+            assert_eq!(Engine::encode(&custom, &input), base64::encode(&input));
+            assert_eq!(roundtrip(&custom, &input), input);
+        }
+    }
+
+    #[test]
+    fn encode_slice_reports_small_buffer() {
+        let mut output = [0; 7];
+        assert_eq!(
+            Engine::encode_slice(&BASE64_STANDARD, "hello", &mut output)
+                .unwrap_err()
+                .required(),
+            8
+        );
+        let mut vec = vec![1];
+        BASE64_STANDARD_NO_PAD
+            .decode_vec("aGVsbG8", &mut vec)
+            .unwrap();
+        assert_eq!(vec, b"\x01hello");
+        let mut output = [0; 5];
+        assert_eq!(
+            BASE64_URL_SAFE.decode_slice_unchecked("aGVsbG8=", &mut output),
+            Ok(5)
+        );
+    }
+}
@@ -62,6 +62,53 @@ macro_rules! tests_for_configs {
                 use crate::*;
                 use proptest::prelude::{any, proptest};
                 use proptest::collection::vec;
+
+                // Inputs that span multiple of the blocks handed to the
+                // PipelinedEncodeWriter's encoder thread.
+                #[test]
+                fn pipelined_encode_writer_large_input() {
+                    use radix64::io::PipelinedEncodeWriter;
+                    use std::io::Write;
+                    for &len in &[0, 1, 49_151, 49_152, 49_153, 98_306, 150_001] {
+                        let input: Vec<u8> = (0..len).map(|i| (i * 31 + i / 7) as u8).collect();
+                        let encoded = $cfg.encode(&input);
+                        for &write_size in &[1_000, 49_152, 70_001] {
+                            let mut writer = PipelinedEncodeWriter::new($cfg, Vec::new());
+                            for chunk in input.chunks(write_size) {
+                                writer.write_all(chunk).expect("write failed");
+                            }
+                            let writer_encoded = writer.finish().expect("finish failed");
+                            assert_eq!(encoded.as_bytes(), writer_encoded.as_slice());
+                        }
+                    }
+                }
+
+                // Inputs that span multiple of the blocks used by copy_encode
+                // and copy_decode.
+                #[test]
+                fn copy_encode_and_decode_large_input() {
+                    use radix64::io::{copy_decode, copy_encode};
+                    for &len in &[0, 1, 49_151, 49_152, 49_153, 98_306, 150_001] {
+                        let input: Vec<u8> = (0..len).map(|i| (i * 31 + i / 7) as u8).collect();
+                        let mut encoded = Vec::new();
+                        let n = copy_encode($cfg, &mut input.as_slice(), &mut encoded).unwrap();
+                        assert_eq!(n, encoded.len() as u64);
+                        assert_eq!(encoded, $cfg.encode(&input).into_bytes());
+                        let mut decoded = Vec::new();
+                        let n = copy_decode($cfg, &mut encoded.as_slice(), &mut decoded).unwrap();
+                        assert_eq!(n, decoded.len() as u64);
+                        assert_eq!(decoded, input);
+                        for &suffix in &[&b"*"[..], b"A", b"=", b"AA=="] {
+                            let extended = [&encoded[..], suffix].concat();
+                            let mut decoded = Vec::new();
+                            let res = copy_decode($cfg, &mut extended.as_slice(), &mut decoded)
+                                .map(|_| decoded)
+                                .map_err(|err| *err.into_inner().unwrap().downcast::<radix64::DecodeError>().unwrap());
+                            assert_eq!(res, $cfg.decode(&extended));
+                        }
+                    }
+                }
+
                 proptest! {
                     #[test]
                     fn roundtrip(input in any::<Vec<u8>>()) {
@@ -70,6 +117,44 @@ macro_rules! tests_for_configs {
                         assert_eq!(input, decoded);
                     }
 
+                    #[test]
+                    fn slices_match_contiguous(input in any::<Vec<u8>>(), split in any::<proptest::sample::Index>()) {
+                        let at = split.index(input.len() + 1);
+                        let encoded = $cfg.encode(&input);
+                        assert_eq!($cfg.encode_slices(&input[..at], &input[at..]), encoded);
+                        let at = split.index(encoded.len() + 1);
+                        let decoded = $cfg.decode_slices(&encoded[..at], &encoded[at..]);
+                        assert_eq!(decoded.expect("decode failed"), input);
+                    }
+
+                    #[test]
+                    fn decode_slices_errors_match_decode(input in any::<Vec<u8>>(), split in any::<proptest::sample::Index>()) {
+                        let at = split.index(input.len() + 1);
+                        assert_eq!($cfg.decode_slices(&input[..at], &input[at..]), $cfg.decode(&input));
+                    }
+
+                    #[test]
+                    fn decode_split_matches_decode(input in any::<Vec<u8>>(), garbage in any::<Vec<u8>>(), sizes in vec(1..20usize, 1..10)) {
+                        let split = |input: &[u8]| -> Vec<Vec<u8>> {
+                            let mut rest = input;
+                            let mut segments = Vec::new();
+                            for &size in sizes.iter().cycle() {
+                                if rest.is_empty() {
+                                    break;
+                                }
+                                let (segment, tail) = rest.split_at(std::cmp::min(size, rest.len()));
+                                segments.push(segment.to_vec());
+                                rest = tail;
+                            }
+                            segments
+                        };
+                        let encoded = $cfg.encode(&input);
+                        let segments = split(encoded.as_bytes());
+                        assert_eq!(Ok(input), $cfg.decode_split(segments.iter().map(Vec::as_slice)));
+                        let segments = split(&garbage);
+                        assert_eq!($cfg.decode(&garbage), custom_configs::$cfg.decode_split(segments.iter().map(Vec::as_slice)));
+                    }
+
                     #[test]
                     fn custom_can_be_decoded_by_builtin(input in any::<Vec<u8>>()) {
                         let encoded = custom_configs::$cfg.encode(&input);
@@ -92,6 +177,52 @@ macro_rules! tests_for_configs {
                         assert_eq!(encoded, encoded_buf);
                     }
 
+                    #[test]
+                    fn encode_slice_uninit_matches_encode(input in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        let mut output = vec![std::mem::MaybeUninit::uninit(); $cfg.encoded_output_len(input.len())];
+                        assert_eq!(encoded, $cfg.encode_slice_uninit(&input, &mut output));
+                    }
+
+                    #[test]
+                    fn encode_with_str_buffer_matches_encode(input in any::<Vec<u8>>(), initial in any::<String>()) {
+                        let encoded = $cfg.encode(&input);
+                        let mut buf = initial;
+                        assert_eq!(encoded, $cfg.encode_with_str_buffer(&input, &mut buf));
+                        assert_eq!(encoded, buf);
+                    }
+
+                    #[test]
+                    fn encode_append_matches_encode(input in any::<Vec<u8>>(), initial in any::<String>()) {
+                        let mut buf = initial.clone();
+                        $cfg.encode_append(&input, &mut buf);
+                        assert_eq!(initial + &$cfg.encode(&input), buf);
+                    }
+
+                    #[test]
+                    fn decode_prefix_stops_at_document_end(input in any::<Vec<u8>>(), rest in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        let followed = [encoded.as_bytes(), b";", &rest].concat();
+                        assert_eq!($cfg.decode_prefix(&followed), Ok((input.clone(), encoded.len())));
+                        assert_eq!(custom_configs::$cfg.decode_prefix(&followed), Ok((input, encoded.len())));
+                    }
+
+                    #[test]
+                    fn decode_prefix_rest_returns_following_input(input in any::<Vec<u8>>(), rest in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        let followed = [encoded.as_bytes(), b";", &rest].concat();
+                        let expected_rest = &followed[encoded.len()..];
+                        assert_eq!($cfg.decode_prefix_rest(&followed), Ok((input.clone(), expected_rest)));
+                        assert_eq!(custom_configs::$cfg.decode_prefix_rest(&followed), Ok((input, expected_rest)));
+                    }
+
+                    #[test]
+                    fn encode_arc_and_rc_match_encode(input in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        assert_eq!(&*$cfg.encode_arc(&input), encoded.as_str());
+                        assert_eq!(&*$cfg.encode_rc(&input), encoded.as_str());
+                    }
+
                     #[test]
                     fn encode_slice_matches_encode(input in any::<Vec<u8>>()) {
                         let mut encoded_vec = vec![0; input.len() * 4 / 3 + 3];
@@ -108,6 +239,224 @@ macro_rules! tests_for_configs {
                         assert_eq!(encoded, display);
                     }
 
+                    #[test]
+                    fn decode_utf16_matches_decode(input in vec(0..128u8, 0..200)) {
+                        let utf16: Vec<u16> = input.iter().map(|&b| u16::from(b)).collect();
+                        assert_eq!($cfg.decode_utf16(&utf16), $cfg.decode(&input));
+                        let encoded = $cfg.encode(&input);
+                        let utf16: Vec<u16> = encoded.encode_utf16().collect();
+                        assert_eq!($cfg.decode_utf16(&utf16).expect("decode failed"), input);
+                    }
+
+                    #[test]
+                    fn encode_writer_finish_message(messages in vec(any::<Vec<u8>>(), 0..5)) {
+                        use std::io::Write;
+                        let mut writer = EncodeWriter::new($cfg, Vec::new());
+                        let mut expected = Vec::new();
+                        for message in &messages {
+                            writer.write_all(message).unwrap();
+                            writer.finish_message(b"\n").unwrap();
+                            expected.extend_from_slice($cfg.encode(message).as_bytes());
+                            expected.push(b'\n');
+                        }
+                        assert_eq!(writer.finish().unwrap(), expected);
+                    }
+
+                    #[cfg(feature = "digest")]
+                    #[test]
+                    fn hashing_adapters_match_digest(input in any::<Vec<u8>>()) {
+                        use radix64::io::{DecodeReader, EncodeWriter, HashingDecodeReader, HashingEncodeWriter};
+                        use sha2::{Digest, Sha256};
+                        use std::io::{Read, Write};
+                        let mut writer = HashingEncodeWriter::new(EncodeWriter::new($cfg, Vec::new()), Sha256::new());
+                        writer.write_all(&input).unwrap();
+                        let (encoded, hash) = writer.finish().unwrap();
+                        assert_eq!(encoded, $cfg.encode(&input).into_bytes());
+                        assert_eq!(hash, Sha256::digest(&input));
+                        let mut reader = HashingDecodeReader::new(DecodeReader::new($cfg, encoded.as_slice()), Sha256::new());
+                        let mut decoded = Vec::new();
+                        reader.read_to_end(&mut decoded).unwrap();
+                        assert_eq!(decoded, input);
+                        assert_eq!(reader.finalize(), Sha256::digest(&input));
+                    }
+
+                    #[test]
+                    fn copy_decode_error_matches_decode(input in any::<String>(), prefix_len in 0..10usize) {
+                        use radix64::io::copy_decode;
+                        let input = $cfg.encode(&vec![0; prefix_len]) + &input;
+                        let res = copy_decode($cfg, &mut input.as_bytes(), &mut Vec::new())
+                            .map_err(|err| *err.into_inner().unwrap().downcast::<radix64::DecodeError>().unwrap());
+                        assert_eq!(res.map(|_| ()), $cfg.decode(&input).map(|_| ()));
+                    }
+
+                    #[test]
+                    fn decode_str_matches_decode(input in any::<String>(), prefix in any::<Vec<u8>>()) {
+                        assert_eq!($cfg.decode_str(&input), $cfg.decode(&input));
+                        let input = $cfg.encode(&prefix) + &input;
+                        assert_eq!($cfg.decode_str(&input), $cfg.decode(&input));
+                    }
+
+                    #[test]
+                    fn output_len_helpers_match(input in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        assert_eq!($cfg.encoded_output_len(input.len()), encoded.len());
+                        assert_eq!(Config::encoded_output_len($cfg, input.len()), encoded.len());
+                        assert!(input.len() <= $cfg.maximum_decoded_output_len(encoded.len()));
+                        assert!(input.len() + 3 > $cfg.maximum_decoded_output_len(encoded.len()));
+                    }
+
+                    #[test]
+                    fn decoded_len_matches_decode(input in any::<Vec<u8>>(), garbage in any::<String>()) {
+                        assert_eq!(Ok(input.len()), $cfg.decoded_len(&$cfg.encode(&input)));
+                        if let Ok(decoded) = $cfg.decode(&garbage) {
+                            assert_eq!(Ok(decoded.len()), $cfg.decoded_len(&garbage));
+                        }
+                    }
+
+                    #[test]
+                    fn to_writer_matches_encode_and_decode(input in any::<Vec<u8>>(), garbage in any::<Vec<u8>>()) {
+                        let mut encoded = Vec::new();
+                        let n = $cfg.encode_to_writer(&input, &mut encoded).expect("encode failed");
+                        assert_eq!(n, encoded.len() as u64);
+                        assert_eq!(encoded, $cfg.encode(&input).into_bytes());
+                        let mut decoded = Vec::new();
+                        let n = custom_configs::$cfg.decode_to_writer(&encoded, &mut decoded).expect("decode failed");
+                        assert_eq!(n, decoded.len() as u64);
+                        assert_eq!(decoded, input);
+                        let res = $cfg.decode_to_writer(&garbage, &mut Vec::new())
+                            .map_err(|err| *err.into_inner().unwrap().downcast::<radix64::DecodeError>().unwrap());
+                        assert_eq!(res.is_ok(), $cfg.decode(&garbage).is_ok());
+                    }
+
+                    #[test]
+                    fn encode_to_fmt_matches_encode(input in any::<Vec<u8>>(), initial in any::<String>()) {
+                        let mut output = initial.clone();
+                        $cfg.encode_to_fmt(&input, &mut output).expect("encode failed");
+                        assert_eq!(initial + &$cfg.encode(&input), output);
+                    }
+
+                    #[test]
+                    fn encode_iter_matches_encode(input in vec(any::<u8>(), 0..300)) {
+                        let encoded = $cfg.encode(&input);
+                        let mut chars = $cfg.encode_iter(&input);
+                        assert_eq!(encoded.len(), chars.len());
+                        assert_eq!(encoded, chars.by_ref().collect::<String>());
+                        assert_eq!(None, chars.next());
+                        let bytes = custom_configs::$cfg.encode_bytes_iter(&input);
+                        assert_eq!(encoded.len(), bytes.len());
+                        assert_eq!(encoded.into_bytes(), bytes.collect::<Vec<u8>>());
+                    }
+
+                    #[test]
+                    fn decoded_len_const_matches_decoded_len(input in any::<Vec<u8>>(), garbage in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        assert_eq!($cfg.decoded_len(&encoded), $cfg.decoded_len_const(encoded.as_bytes()));
+                        assert_eq!($cfg.decoded_len(&garbage), $cfg.decoded_len_const(&garbage));
+                    }
+
+                    #[test]
+                    fn decode_exact_matches_decode(input in vec(any::<u8>(), 16)) {
+                        let encoded = $cfg.encode(&input);
+                        let decoded: [u8; 16] = $cfg.decode_exact(&encoded).unwrap();
+                        assert_eq!(&decoded[..], &input[..]);
+                        assert_eq!(
+                            $cfg.decode_exact::<15, _>(&encoded),
+                            Err(radix64::DecodeError::UnexpectedDecodedLength { expected: 15, actual: 16 })
+                        );
+                    }
+
+                    #[test]
+                    fn int_roundtrip(a in any::<u64>(), b in any::<u128>()) {
+                        let encoded = $cfg.encode_u64(a);
+                        assert_eq!($cfg.decode_u64(&encoded), Ok(a));
+                        assert_eq!(custom_configs::$cfg.decode_u64(&encoded), Ok(a));
+                        let encoded = $cfg.encode_u128(b);
+                        assert_eq!($cfg.decode_u128(&encoded), Ok(b));
+                        assert_eq!(encoded, custom_configs::$cfg.encode_u128(b));
+                    }
+
+                    #[test]
+                    fn raw_chunks_match_encode(input in any::<Vec<u8>>()) {
+                        use radix64::raw;
+                        let mut encoded = Vec::new();
+                        let mut chunks = input.chunks_exact(3);
+                        for chunk in &mut chunks {
+                            let mut output = [0; 4];
+                            raw::encode_chunk($cfg, [chunk[0], chunk[1], chunk[2]], &mut output);
+                            encoded.extend_from_slice(&output);
+                        }
+                        let mut output = [0; 4];
+                        let n = raw::encode_partial_chunk($cfg, chunks.remainder(), &mut output);
+                        encoded.extend_from_slice(&output[..n]);
+                        assert_eq!(encoded, $cfg.encode(&input).into_bytes());
+
+                        let unpadded_len = STD_NO_PAD.encoded_output_len(input.len());
+                        let mut decoded = Vec::new();
+                        let mut chunks = encoded[..unpadded_len].chunks_exact(4);
+                        for chunk in &mut chunks {
+                            let mut output = [0; 3];
+                            raw::decode_chunk($cfg, [chunk[0], chunk[1], chunk[2], chunk[3]], &mut output).unwrap();
+                            decoded.extend_from_slice(&output);
+                        }
+                        let mut output = [0; 2];
+                        let n = raw::decode_partial_chunk($cfg, chunks.remainder(), &mut output).unwrap();
+                        decoded.extend_from_slice(&output[..n]);
+                        assert_eq!(decoded, input);
+                    }
+
+                    #[test]
+                    fn output_buffers_match_encode_and_decode(input in any::<Vec<u8>>(), garbage in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        let mut s = String::from("x");
+                        assert_eq!($cfg.encode_to(&input, &mut s), encoded.len());
+                        assert_eq!(&s[1..], encoded);
+                        let mut v = vec![1];
+                        $cfg.encode_to(&input, &mut v);
+                        assert_eq!(&v[1..], encoded.as_bytes());
+                        let mut buf = vec![0; encoded.len()];
+                        let mut remaining = &mut buf[..];
+                        $cfg.encode_to(&input, &mut remaining);
+                        assert!(remaining.is_empty());
+                        assert_eq!(buf, encoded.as_bytes());
+
+                        let mut v = vec![1];
+                        assert_eq!($cfg.decode_to(&encoded, &mut v), Ok(input.len()));
+                        assert_eq!(&v[1..], &input[..]);
+                        let mut buf = vec![0; input.len()];
+                        let mut remaining = &mut buf[..];
+                        assert_eq!($cfg.decode_to(&encoded, &mut remaining), Ok(input.len()));
+                        assert!(remaining.is_empty());
+                        assert_eq!(buf, input);
+
+                        let mut v = vec![1];
+                        match $cfg.decode(&garbage) {
+                            Ok(decoded) => {
+                                assert_eq!($cfg.decode_to(&garbage, &mut v), Ok(decoded.len()));
+                                assert_eq!(&v[1..], &decoded[..]);
+                            }
+                            Err(err) => {
+                                assert_eq!($cfg.decode_to(&garbage, &mut v), Err(err));
+                                assert_eq!(v, [1]);
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn extension_traits_match_config(input in any::<Vec<u8>>()) {
+                        use radix64::ext::{FromBase64, ToBase64};
+                        let encoded = input.to_base64($cfg);
+                        assert_eq!(encoded, $cfg.encode(&input));
+                        assert_eq!(encoded.from_base64($cfg).expect("decode failed"), input);
+                        assert_eq!(encoded.as_str().from_base64($cfg), $cfg.decode(&encoded));
+                    }
+
+                    #[test]
+                    fn display_from_reader_matches_encode(input in vec(any::<u8>(), 0..3000)) {
+                        let encoded = $cfg.encode(&input);
+                        let display = radix64::DisplayFromReader::new($cfg, input.as_slice()).to_string();
+                        assert_eq!(encoded, display);
+                    }
+
                     #[test]
                     fn decode_with_buffer_matches_decode(input in any::<Vec<u8>>()) {
                         let encoded = $cfg.encode(&input);
@@ -117,6 +466,183 @@ macro_rules! tests_for_configs {
                         assert_eq!(decoded_buf, decoded_vec.as_slice());
                     }
 
+                    #[test]
+                    fn decode_slice_uninit_matches_decode(input in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        let mut output = vec![std::mem::MaybeUninit::uninit(); $cfg.maximum_decoded_output_len(encoded.len())];
+                        assert_eq!(Ok(input.as_slice()), $cfg.decode_slice_uninit(&encoded, &mut output).map(|decoded| &*decoded));
+                    }
+
+                    #[test]
+                    fn decode_append_matches_decode(input in any::<Vec<u8>>(), initial in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        let mut buf = initial.clone();
+                        $cfg.decode_append(&encoded, &mut buf).expect("decode failed");
+                        assert_eq!([initial.as_slice(), &input].concat(), buf);
+                    }
+
+                    #[test]
+                    fn decode_append_errors_leave_buffer_unchanged(input in any::<Vec<u8>>(), initial in any::<Vec<u8>>()) {
+                        let mut buf = initial.clone();
+                        let result = $cfg.decode_append(&input, &mut buf);
+                        assert_eq!(result, $cfg.decode(&input).map(|_| ()));
+                        if result.is_err() {
+                            assert_eq!(initial, buf);
+                        }
+                    }
+
+                    #[test]
+                    fn decode_in_place_matches_decode(input in vec(any::<u8>(), 0..3000), garbage in any::<Vec<u8>>()) {
+                        let mut encoded = $cfg.encode(&input).into_bytes();
+                        assert_eq!(Ok(input.as_slice()), $cfg.decode_in_place(&mut encoded));
+                        let mut buf = garbage.clone();
+                        assert_eq!($cfg.decode(&garbage), $cfg.decode_in_place(&mut buf).map(<[u8]>::to_vec));
+                    }
+
+                    #[test]
+                    fn decode_utf8_matches_decode(input in any::<String>(), bytes in any::<Vec<u8>>()) {
+                        assert_eq!(Ok(input.clone()), $cfg.decode_utf8(&$cfg.encode(&input)));
+                        let encoded = $cfg.encode(&bytes);
+                        match (std::str::from_utf8(&bytes), $cfg.decode_utf8(&encoded)) {
+                            (Ok(expected), Ok(decoded)) => assert_eq!(expected, decoded),
+                            (Err(_), Err(radix64::DecodeUtf8Error::Utf8(err))) => assert_eq!(bytes, err.into_bytes()),
+                            (expected, decoded) => panic!("expected {:?}, decoded {:?}", expected, decoded),
+                        }
+                    }
+
+                    #[test]
+                    fn custom_based_on_builtin_matches(input in any::<Vec<u8>>()) {
+                        let custom = radix64::CustomConfig::based_on($cfg).build().expect("failed to build custom config");
+                        let encoded = $cfg.encode(&input);
+                        assert_eq!(encoded, custom.encode(&input));
+                        assert_eq!(input, custom.decode(&encoded).expect("decode failed"));
+                    }
+
+                    #[test]
+                    fn normalize_matches_encode(input in any::<Vec<u8>>(), whitespace_interval in 1..10usize) {
+                        let expected = $cfg.encode(&input);
+                        assert_eq!(expected, radix64::normalize(&STD.encode(&input), $cfg).expect("normalize failed"));
+                        let mut messy = String::new();
+                        for (i, c) in URL_SAFE_NO_PAD.encode(&input).chars().enumerate() {
+                            if i % whitespace_interval == 0 {
+                                messy.push_str(" \r\n");
+                            }
+                            messy.push(c);
+                        }
+                        assert_eq!(expected, radix64::normalize(&messy, $cfg).expect("normalize failed"));
+                    }
+
+                    #[test]
+                    fn validate_matches_decode(input in vec(any::<u8>(), 0..3000), garbage in any::<Vec<u8>>()) {
+                        let encoded = $cfg.encode(&input);
+                        assert_eq!(Ok(()), $cfg.validate(&encoded));
+                        assert_eq!($cfg.decode(&garbage).map(|_| ()), $cfg.validate(&garbage));
+                        let corrupted = [encoded.as_bytes(), &garbage].concat();
+                        assert_eq!($cfg.decode(&corrupted).map(|_| ()), $cfg.validate(&corrupted));
+                    }
+
+                    #[test]
+                    fn is_canonical_matches_reencoding(input in any::<String>()) {
+                        let canonical = match $cfg.decode(&input) {
+                            Ok(decoded) => $cfg.encode(&decoded) == input,
+                            Err(_) => false,
+                        };
+                        assert_eq!(canonical, $cfg.is_canonical(&input));
+                    }
+
+                    #[test]
+                    fn encoded_is_canonical(input in any::<Vec<u8>>()) {
+                        assert!($cfg.is_canonical(&$cfg.encode(&input)));
+                    }
+
+                    #[test]
+                    fn encode_chunked_roundtrip(input in any::<Vec<u8>>(), max_chars in 4..64usize) {
+                        let pieces = $cfg.encode_chunked(&input, max_chars);
+                        for piece in &pieces {
+                            assert!(piece.len() <= max_chars);
+                            $cfg.decode(piece).expect("piece is not independently decodable");
+                        }
+                        // Only the final piece can contain padding.
+                        assert_eq!($cfg.encode(&input), pieces.concat());
+                        assert_eq!(input, $cfg.decode_chunked(&pieces).expect("decode failed"));
+                    }
+
+                    #[test]
+                    fn decode_range_matches_slice(input in any::<Vec<u8>>(), start in 0..300usize, len in 0..300usize) {
+                        let encoded = $cfg.encode(&input);
+                        let begin = std::cmp::min(start, input.len());
+                        let end = std::cmp::min(start + len, input.len());
+                        assert_eq!($cfg.decode_range(&encoded, start..start + len).unwrap(), &input[begin..end]);
+                    }
+
+                    #[test]
+                    fn indexed_decoder_matches_slice(input in any::<Vec<u8>>(), offset in 0..300u64, len in 0..300usize) {
+                        use radix64::io::IndexedDecoder;
+                        use std::io::Cursor;
+                        let mut decoder = IndexedDecoder::new($cfg, Cursor::new($cfg.encode(&input)));
+                        let start = std::cmp::min(offset as usize, input.len());
+                        let expected = &input[start..std::cmp::min(start + len, input.len())];
+                        let mut buf = vec![0; expected.len()];
+                        decoder.read_exact_at(offset, &mut buf).expect("read failed");
+                        assert_eq!(expected, buf.as_slice());
+                        if start + len > input.len() {
+                            let mut buf = vec![0; len];
+                            assert!(decoder.read_exact_at(offset, &mut buf).is_err());
+                        }
+                    }
+
+                    #[test]
+                    fn no_pad_and_with_pad(input in any::<Vec<u8>>()) {
+                        use radix64::configs::{NoPad, WithPad};
+                        let unpadded = $cfg.encode(&input).trim_end_matches('=').to_owned();
+                        assert_eq!(unpadded, NoPad($cfg).encode(&input));
+                        assert_eq!(input, NoPad($cfg).decode(&unpadded).expect("decode failed"));
+
+                        let mut padded = unpadded;
+                        while padded.len() % 4 != 0 {
+                            padded.push('!');
+                        }
+                        let with_pad = WithPad::<_, b'!'>::new($cfg);
+                        assert_eq!(padded, with_pad.encode(&input));
+                        assert_eq!(input, with_pad.decode(&padded).expect("decode failed"));
+                    }
+
+                    #[test]
+                    fn encode_into_matches_encode(input in vec(any::<u8>(), 0..4096)) {
+                        let mut output = std::collections::VecDeque::new();
+                        $cfg.encode_into(&input, &mut output);
+                        assert_eq!($cfg.encode(&input).as_bytes(), output.into_iter().collect::<Vec<u8>>().as_slice());
+                    }
+
+                    #[test]
+                    fn decode_into_matches_decode(input in vec(any::<u8>(), 0..4096), suffix in any::<String>()) {
+                        let mut encoded = $cfg.encode(&input);
+                        let mut output = std::collections::VecDeque::new();
+                        $cfg.decode_into(&encoded, &mut output).expect("decode failed");
+                        assert_eq!(input, output.into_iter().collect::<Vec<u8>>());
+
+                        // Errors, including those found after the first block, match decode.
+                        encoded.push_str(&suffix);
+                        let mut output = Vec::new();
+                        let res = $cfg.decode_into(&encoded, &mut output).map(|_| output);
+                        assert_eq!($cfg.decode(&encoded), res);
+                    }
+
+                    #[test]
+                    fn decode_percent_encoded_matches_decode(input in any::<Vec<u8>>(), lowercase in any::<bool>()) {
+                        let encoded = $cfg.encode(&input);
+                        let mut escaped = String::new();
+                        for c in encoded.chars() {
+                            match c {
+                                '=' | '+' | '/' if lowercase => escaped += &format!("%{:02x}", c as u8),
+                                '=' | '+' | '/' => escaped += &format!("%{:02X}", c as u8),
+                                c => escaped.push(c),
+                            }
+                        }
+                        let decoded = $cfg.decode_percent_encoded(&escaped).expect("decode failed");
+                        assert_eq!(input, decoded);
+                    }
+
                     #[test]
                     fn decode_slice_matches_decode(input in any::<Vec<u8>>()) {
                         let encoded = $cfg.encode(&input);
@@ -186,7 +712,7 @@ macro_rules! tests_for_configs {
                         {
                             let mut writer = EncodeWriter::new($cfg, Cursor::new(&mut writer_encoded));
                             for b in input {
-                                writer.write(&[b][..]).expect("write failed");
+                                writer.write_all(&[b][..]).expect("write failed");
                                 // invoking flush is not necessary, but nice to
                                 // exercise that codepath somewhere.
                                 writer.flush().expect("flush failed");
@@ -211,6 +737,29 @@ macro_rules! tests_for_configs {
                         assert_eq!(encoded.as_bytes(), writer_encoded.as_slice());
                     }
 
+                    #[test]
+                    fn pipelined_encode_writer_matches((input, flaky_behavior) in vec_and_flaky_writer_behavior()) {
+                        use radix64::io::PipelinedEncodeWriter;
+                        use std::io::Write;
+                        let encoded = $cfg.encode(&input);
+                        let mut writer_encoded = Vec::new();
+                        {
+                            let flaky_writer = FlakyWriter::new(&mut writer_encoded, flaky_behavior.into_iter());
+                            let mut writer = PipelinedEncodeWriter::new($cfg, flaky_writer);
+                            write_all_with_retries(&mut writer, &input);
+                            // flush sends all full chunks through the encoder
+                            // thread, exercising a block smaller than the maximum.
+                            while writer.flush().is_err() {}
+                            loop {
+                                writer = match writer.finish() {
+                                    Ok(_) => break,
+                                    Err(finish_err) => finish_err.into_encode_writer(),
+                                }
+                            }
+                        }
+                        assert_eq!(encoded.as_bytes(), writer_encoded.as_slice());
+                    }
+
                     // read a vector from a DecodeReader, ensuring that it matches the encoded input.
                     // The reads are done with varying buffer sizes to try and
                     // catch edge cases around chunking.
@@ -224,6 +773,57 @@ macro_rules! tests_for_configs {
                         assert_eq!(input, decoded);
                     }
 
+                    // A DecodeReader with a limit only succeeds when the
+                    // decoded data fits within the limit and never returns
+                    // more than the limit.
+                    #[test]
+                    fn decode_reader_max_decoded_bytes((input, buffer_sizes) in vec_and_buffer_sizes(), limit in 0..128u64) {
+                        use radix64::io::DecodeReader;
+                        use std::io::{Cursor, Read};
+                        let encoded = $cfg.encode(&input);
+                        let mut reader = DecodeReader::new($cfg, Cursor::new(encoded)).with_max_decoded_bytes(limit);
+                        let mut decoded = Vec::new();
+                        let mut buffer_sizes = buffer_sizes.iter().cycle();
+                        let res = loop {
+                            let mut buf = vec![0; *buffer_sizes.next().unwrap()];
+                            match reader.read(&mut buf) {
+                                Ok(0) => break Ok(()),
+                                Ok(n) => decoded.extend_from_slice(&buf[..n]),
+                                Err(err) => break Err(err),
+                            }
+                        };
+                        assert_eq!(res.is_ok(), input.len() as u64 <= limit);
+                        let expected_len = std::cmp::min(input.len() as u64, limit) as usize;
+                        assert_eq!(&input[..expected_len], decoded.as_slice());
+                    }
+
+                    // A DecodeReader that is reset partway through a document
+                    // decodes the next document as if newly created.
+                    #[test]
+                    fn decode_reader_reset_with((first, second) in (any::<Vec<u8>>(), any::<Vec<u8>>()), read_len in 0..8usize) {
+                        use radix64::io::DecodeReader;
+                        use std::io::{Cursor, Read};
+                        let mut reader = DecodeReader::new($cfg, Cursor::new($cfg.encode(&first))).with_max_decoded_bytes(first.len() as u64);
+                        let mut buf = vec![0; read_len];
+                        let n = reader.read(&mut buf).expect("read failed");
+                        assert_eq!(&first[..n], &buf[..n]);
+                        reader.reset_with(Cursor::new($cfg.encode(&second)));
+                        let mut decoded = Vec::new();
+                        let res = reader.read_to_end(&mut decoded);
+                        assert_eq!(res.is_ok(), second.len() <= first.len());
+                        let expected_len = std::cmp::min(first.len(), second.len());
+                        assert_eq!(&second[..expected_len], decoded.as_slice());
+                    }
+
+                    #[test]
+                    fn decode_reader_eager_fill((input, buffer_sizes) in vec_and_buffer_sizes()) {
+                        use radix64::io::DecodeReader;
+                        let encoded = $cfg.encode(&input);
+                        let reader = DecodeReader::new($cfg, OneByteReader(encoded.as_bytes())).with_eager_fill();
+                        let decoded = read_to_end_using_varying_buffer_sizes(reader, buffer_sizes.iter().cloned()).expect("failed to read to the end of input");
+                        assert_eq!(input, decoded);
+                    }
+
                     // ensure that padding in the middle of the input stream is not silently accepted.
                     // The buffer sizes to use are randomly chosen between 1 and 5.
                     #[test]
@@ -354,9 +954,8 @@ where
     W: io::Write,
 {
     while !input.is_empty() {
-        match writer.write(input) {
-            Ok(n) => input = &input[n..],
-            Err(_) => {}
+        if let Ok(n) = writer.write(input) {
+            input = &input[n..];
         }
     }
 }
@@ -423,4 +1022,1955 @@ where
     }
 }
 
-tests_for_configs!(STD, STD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD, CRYPT, FAST);
+// Held by every test that reads or overrides the process wide backend, so
+// one test can't switch the backend while another depends on it.
+static BACKEND_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+fn lock_backend() -> std::sync::MutexGuard<'static, ()> {
+    BACKEND_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+#[test]
+fn active_backend_respects_features() {
+    use radix64::Backend;
+    let _guard = lock_backend();
+    let backend = radix64::active_backend();
+    if cfg!(not(all(
+        feature = "avx2",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))) {
+        assert_eq!(backend, Backend::Scalar);
+    }
+    // Statically enabled AVX2 selects a backend at compile time, even when the
+    // wider AVX-512 VBMI kernels are also compiled in.
+    if cfg!(all(
+        feature = "avx2",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    )) {
+        assert!(backend == Backend::Avx2 || backend == Backend::Avx512Vbmi);
+        assert!(!Backend::Scalar.is_supported());
+    }
+    assert_eq!(backend.to_string(), backend.name());
+}
+
+// Tests that don't take the backend lock may run while the backend is
+// overridden. Every backend produces identical results so that does not
+// affect them.
+#[test]
+fn backend_override() {
+    use radix64::Backend;
+    let _guard = lock_backend();
+    let input: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+    let encoded = STD.encode(&input);
+    for &backend in &[
+        Backend::Scalar,
+        Backend::Avx2,
+        Backend::Avx512Vbmi,
+        Backend::Ssse3,
+    ] {
+        match radix64::set_backend(backend) {
+            Ok(()) => {
+                assert_eq!(radix64::active_backend(), backend);
+                assert_eq!(encoded, STD.encode(&input));
+                assert_eq!(input, STD.decode(&encoded).expect("decode failed"));
+            }
+            Err(err) => {
+                assert!(!backend.is_supported());
+                assert_eq!(err.backend(), backend);
+            }
+        }
+    }
+    radix64::reset_backend();
+}
+
+// Changing the backend while the AVX-512 VBMI kernels are running must not
+// stop them from finishing the input with AVX2.
+#[test]
+fn set_backend_during_encode() {
+    use radix64::Backend;
+    let _guard = lock_backend();
+    if radix64::set_backend(Backend::Avx512Vbmi).is_err() || !Backend::Scalar.is_supported() {
+        return;
+    }
+    let input: Vec<u8> = (0..200u32).map(|i| (i * 7) as u8).collect();
+    let encoded = STD.encode(&input);
+    let codec = std::thread::spawn(move || {
+        for _ in 0..20_000 {
+            assert_eq!(encoded, STD.encode(&input));
+            assert_eq!(input, STD.decode(&encoded).expect("decode failed"));
+        }
+    });
+    while !codec.is_finished() {
+        radix64::set_backend(Backend::Avx512Vbmi).unwrap();
+        radix64::set_backend(Backend::Scalar).unwrap();
+    }
+    let result = codec.join();
+    radix64::reset_backend();
+    result.unwrap();
+}
+
+#[test]
+fn fast_decodes_with_every_backend() {
+    use radix64::Backend;
+    let _guard = lock_backend();
+    let input: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+    let encoded = FAST.encode(&input);
+    let mut invalid = encoded.clone().into_bytes();
+    invalid[500] = b'~';
+    for &backend in &[
+        Backend::Scalar,
+        Backend::Avx2,
+        Backend::Avx512Vbmi,
+        Backend::Ssse3,
+    ] {
+        if radix64::set_backend(backend).is_ok() {
+            assert_eq!(input, FAST.decode(&encoded).expect("decode failed"));
+            assert_eq!(
+                FAST.decode(&invalid),
+                Err(radix64::DecodeError::InvalidByte(b'~'))
+            );
+        }
+    }
+    radix64::reset_backend();
+}
+
+#[test]
+fn custom_based_on_replace() {
+    use radix64::configs::CustomConfigError;
+    use radix64::CustomConfig;
+    let cfg = CustomConfig::based_on(URL_SAFE_NO_PAD)
+        .replace(b'-', b'+')
+        .replace(b'_', b'/')
+        .with_padding(b'=')
+        .build()
+        .expect("failed to build custom config");
+    let input = b"\xfb\xff\xfe";
+    assert_eq!(STD.encode(&input[..]), cfg.encode(&input[..]));
+    assert_eq!(STD.encode(&input[..2]), cfg.encode(&input[..2]));
+
+    match CustomConfig::based_on(STD).replace(b'-', b'.').build() {
+        Err(CustomConfigError::NotInAlphabet(b'-')) => {}
+        res => panic!("unexpected result: {:?}", res),
+    }
+    match CustomConfig::based_on(STD).replace(b'+', b'/').build() {
+        Err(CustomConfigError::DuplicateValue(b'/')) => {}
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+#[should_panic(expected = "part of the alphabet")]
+fn with_pad_rejects_alphabet_byte() {
+    radix64::configs::WithPad::<_, b'A'>::new(STD);
+}
+
+#[test]
+fn basic_auth_roundtrip() {
+    use radix64::basic_auth::{self, BasicAuthError};
+
+    let header = basic_auth::encode("caf\u{e9}", "p:a:s:s").unwrap();
+    assert_eq!(header, "Basic Y2Fmw6k6cDphOnM6cw==");
+    assert_eq!(
+        basic_auth::decode(&header).unwrap(),
+        ("caf\u{e9}".to_owned(), "p:a:s:s".to_owned())
+    );
+    assert_eq!(
+        basic_auth::decode(&basic_auth::encode("", "").unwrap()).unwrap(),
+        (String::new(), String::new())
+    );
+    assert_eq!(
+        basic_auth::encode("us:er", "pass"),
+        Err(BasicAuthError::ColonInUserId)
+    );
+
+    assert_eq!(
+        basic_auth::decode("\tBASIC\tdXNlcjpwYXNz "),
+        Ok(("user".to_owned(), "pass".to_owned()))
+    );
+    assert_eq!(
+        basic_auth::decode("Basic"),
+        Err(BasicAuthError::UnsupportedScheme)
+    );
+    assert_eq!(
+        basic_auth::decode("Basically dXNlcjpwYXNz"),
+        Err(BasicAuthError::UnsupportedScheme)
+    );
+    assert_eq!(
+        basic_auth::decode("Basic dXNlcnBhc3M="),
+        Err(BasicAuthError::MissingColon)
+    );
+    assert_eq!(
+        basic_auth::decode("Basic /w=="),
+        Err(BasicAuthError::InvalidUtf8)
+    );
+    assert!(matches!(
+        basic_auth::decode("Basic dXNlcjpwYXNz="),
+        Err(BasicAuthError::Decode(_))
+    ));
+}
+
+#[test]
+fn encoded_word_split_and_decode() {
+    use radix64::encoded_word::{self, EncodedWordError};
+
+    let text = "\u{1f600} Gr\u{fc}\u{df}e aus K\u{f6}ln ".repeat(10);
+    let words: Vec<_> = encoded_word::encode_words(&text).collect();
+    assert!(words.len() > 1);
+    let mut joined = Vec::new();
+    for word in &words {
+        assert!(word.len() <= 75, "{} is too long", word);
+        let decoded = encoded_word::decode_word(word).unwrap().into_data();
+        joined.extend_from_slice(std::str::from_utf8(&decoded).unwrap().as_bytes());
+    }
+    assert_eq!(joined, text.as_bytes());
+    assert_eq!(
+        encoded_word::decode(&encoded_word::encode(&text)).unwrap(),
+        text
+    );
+    assert_eq!(encoded_word::encode(""), "");
+
+    assert_eq!(encoded_word::decode("a =?b?= c").unwrap(), "a =?b?= c");
+    assert_eq!(
+        encoded_word::decode("=?UTF-8?Q?caf=C3=A9?="),
+        Err(EncodedWordError::UnsupportedEncoding('Q'))
+    );
+    assert_eq!(
+        encoded_word::decode("=?KOI8-R?B?6Q==?="),
+        Err(EncodedWordError::UnsupportedCharset("KOI8-R".to_owned()))
+    );
+    assert_eq!(
+        encoded_word::decode("=?UTF-8?B?6Q==?="),
+        Err(EncodedWordError::InvalidUtf8)
+    );
+    assert_eq!(
+        encoded_word::decode_word("=?UTF-8?B?6Q=?="),
+        Err(EncodedWordError::Decode(
+            radix64::DecodeError::InvalidLength {
+                length: 3,
+                remainder: 3,
+                padding_expected: true,
+            }
+        ))
+    );
+}
+
+#[test]
+fn builtin_tables_match_configs() {
+    use radix64::tables::*;
+
+    fn check<C: Config>(config: C, encode: &[u8; 64], decode: &[u8; 256]) {
+        for (value, &c) in encode.iter().enumerate() {
+            let encoded = config.encode(&[(value as u8) << 2]);
+            assert_eq!(encoded.as_bytes()[0], c);
+            assert_eq!(usize::from(decode[usize::from(c)]), value);
+        }
+        let valid = decode.iter().filter(|&&v| v != INVALID_VALUE).count();
+        assert_eq!(valid, 64);
+    }
+    check(STD, STD_ENCODE, STD_DECODE);
+    check(STD_NO_PAD, STD_ENCODE, STD_DECODE);
+    check(URL_SAFE, URL_SAFE_ENCODE, URL_SAFE_DECODE);
+    check(URL_SAFE_NO_PAD, URL_SAFE_ENCODE, URL_SAFE_DECODE);
+    check(CRYPT, CRYPT_ENCODE, CRYPT_DECODE);
+    check(FAST, FAST_ENCODE, FAST_DECODE);
+}
+
+#[test]
+fn decode_any_reports_matching_config() {
+    use radix64::{decode_any, DecodeError, DynConfig};
+
+    let custom = radix64::CustomConfig::with_alphabet(
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789.,",
+    )
+    .no_padding()
+    .build()
+    .unwrap();
+    let configs: Vec<&dyn DynConfig> = vec![&STD, &URL_SAFE_NO_PAD, &custom];
+    assert_eq!(decode_any(&configs, "aGk=").unwrap(), (b"hi".to_vec(), 0));
+    assert_eq!(decode_any(&configs, "aGk").unwrap(), (b"hi".to_vec(), 1));
+    assert_eq!(decode_any(&configs, ".,8").unwrap(), (vec![0xfb, 0xff], 2));
+    // The error from the first config is reported.
+    assert_eq!(
+        decode_any(&configs, "!"),
+        Err(DecodeError::InvalidLength {
+            length: 1,
+            remainder: 1,
+            padding_expected: true
+        })
+    );
+    assert_eq!(
+        decode_any(&configs[1..], "a!"),
+        Err(DecodeError::InvalidByte(b'!'))
+    );
+}
+
+#[test]
+fn folded_content_lines() {
+    use radix64::folded;
+
+    for len in 0..200 {
+        let input: Vec<u8> = (0..len).map(|i| (i * 7) as u8).collect();
+        for name in &["ATTACH", "PHOTO;ENCODING=b;TYPE=JPEG", &"X".repeat(80)] {
+            let line = folded::encode_property(name, &input);
+            let mut lines = line.split("\r\n");
+            let first = lines.next().unwrap();
+            assert!(first.len() <= 75 || first.len() == name.len() + 1);
+            assert!(lines.all(|line| line.len() <= 75 && line.starts_with(' ')));
+            let unfolded = folded::unfold(&line);
+            assert_eq!(unfolded, format!("{}:{}", name, STD.encode(&input)));
+            assert_eq!(folded::decode(&line[name.len() + 1..]).unwrap(), input);
+        }
+    }
+    assert!(matches!(
+        folded::unfold("aGVsbG8="),
+        std::borrow::Cow::Borrowed(_)
+    ));
+    assert_eq!(folded::decode("aGVs\n bG8=").unwrap(), b"hello");
+    // A line break that is not followed by whitespace is not a fold.
+    assert!(folded::decode("aGVs\r\nbG8=").is_err());
+}
+
+#[test]
+fn ssh_public_keys() {
+    use radix64::ssh::{self, PublicKey, SshKeyError};
+
+    let blob = ssh::encode_blob(&["ssh-ed25519".as_bytes(), &[7; 32]]);
+    let line = format!("  ssh-ed25519 {}  laptop key \n", blob);
+    let key = PublicKey::parse(&line).unwrap();
+    assert_eq!(key.algorithm(), "ssh-ed25519");
+    assert_eq!(key.comment(), Some("laptop key"));
+    assert_eq!(
+        key.fields().collect::<Vec<_>>(),
+        vec!["ssh-ed25519".as_bytes(), &[7; 32]]
+    );
+    assert_eq!(key.to_string(), format!("ssh-ed25519 {} laptop key", blob));
+    let (raw, comment) = key.into_parts();
+    assert_eq!(comment.as_deref(), Some("laptop key"));
+    let key = PublicKey::new(raw, None).unwrap();
+    assert_eq!(key.to_string(), format!("ssh-ed25519 {}", blob));
+    assert_eq!(PublicKey::parse(&key.to_string()), Ok(key));
+
+    assert_eq!(PublicKey::parse("ssh-ed25519"), Err(SshKeyError::Malformed));
+    assert_eq!(
+        PublicKey::parse(&format!("ssh-rsa {}", blob)),
+        Err(SshKeyError::AlgorithmMismatch)
+    );
+    // Unpadded blobs and non-zero trailing bits are rejected.
+    assert!(matches!(
+        PublicKey::parse("ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAAQA"),
+        Err(SshKeyError::Decode(_))
+    ));
+    assert!(PublicKey::parse("ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAAQA=").is_ok());
+    assert!(matches!(
+        ssh::decode_blob("AAAAC3NzaC1lZDI1NTE5AAAAAQB="),
+        Err(SshKeyError::Decode(
+            radix64::DecodeError::InvalidTrailingBits
+        ))
+    ));
+    // Truncated fields and trailing bytes are rejected.
+    let mut truncated = radix64::STD.decode(&blob).unwrap();
+    truncated.pop();
+    assert_eq!(
+        ssh::decode_blob(&radix64::STD.encode(&truncated)),
+        Err(SshKeyError::InvalidBlob)
+    );
+    truncated.extend_from_slice(&[0; 3]);
+    assert_eq!(
+        PublicKey::new(truncated, None),
+        Err(SshKeyError::InvalidBlob)
+    );
+    assert_eq!(ssh::decode_blob(""), Err(SshKeyError::InvalidBlob));
+}
+
+#[test]
+fn filename_roundtrip_and_cap() {
+    use radix64::filename;
+
+    let input: Vec<u8> = (0..=255).collect();
+    let name = filename::encode(&input);
+    assert!(name
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+    assert_eq!(filename::decode(&name).unwrap(), input);
+    for max_len in 0..name.len() + 2 {
+        let capped = filename::encode_capped(&input, max_len);
+        assert_eq!(capped, &name[..std::cmp::min(max_len, name.len())]);
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn os_str_roundtrip_is_lossless() {
+    use radix64::os_str::{self, OsStrDecodeError};
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let name = OsStr::from_bytes(b"caf\xe9/\xff\xfe.txt");
+    assert!(name.to_str().is_none());
+    let encoded = os_str::encode(name);
+    assert!(encoded
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+    assert_eq!(os_str::decode(&encoded).unwrap(), name);
+    assert_eq!(os_str::decode_path(&encoded).unwrap().as_os_str(), name);
+    assert_eq!(os_str::encode(""), "");
+    assert_eq!(
+        os_str::decode("a+b"),
+        Err(OsStrDecodeError::Decode(radix64::DecodeError::InvalidByte(
+            b'+'
+        )))
+    );
+}
+
+#[test]
+fn crypt_salt_length_and_alphabet() {
+    use radix64::crypt::{generate_crypt_salt, CryptScheme};
+
+    for &scheme in &[
+        CryptScheme::Des,
+        CryptScheme::Md5,
+        CryptScheme::Sha256,
+        CryptScheme::Sha512,
+    ] {
+        let salt = generate_crypt_salt(scheme, |buf| {
+            for b in buf.iter_mut() {
+                *b = 0xff;
+            }
+        });
+        assert_eq!(salt, "z".repeat(scheme.salt_len()));
+        let salt = generate_crypt_salt(scheme, |buf| {
+            rand::RngCore::fill_bytes(&mut rand::thread_rng(), buf)
+        });
+        assert_eq!(salt.len(), scheme.salt_len());
+        assert!(salt
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'/'));
+    }
+}
+
+#[test]
+fn const_output_len_helpers() {
+    const ENCODED: [u8; STD.encoded_output_len(32)] = [0; 44];
+    const DECODED: [u8; URL_SAFE_NO_PAD.maximum_decoded_output_len(43)] = [0; 32];
+    assert_eq!(ENCODED.len(), 44);
+    assert_eq!(DECODED.len(), 32);
+}
+
+#[test]
+fn const_encode_matches_encode() {
+    macro_rules! check {
+        ($( $cfg:ident ),+) => {$({
+            const EMPTY: [u8; 0] = $cfg.encode_const(b"");
+            const ONE: [u8; $cfg.encoded_output_len(4)] = $cfg.encode_const(b"\x00\xfb\xff ");
+            const TWO: [u8; $cfg.encoded_output_len(5)] = $cfg.encode_const(b"\x00\xfb\xff h");
+            const THREE: [u8; $cfg.encoded_output_len(36)] = $cfg.encode_const(&[0xa5; 36]);
+            assert_eq!(&EMPTY[..], b"");
+            assert_eq!(&ONE[..], $cfg.encode(b"\x00\xfb\xff ").as_bytes());
+            assert_eq!(&TWO[..], $cfg.encode(b"\x00\xfb\xff h").as_bytes());
+            assert_eq!(&THREE[..], $cfg.encode(&[0xa5; 36]).as_bytes());
+        })+};
+    }
+    check!(STD, STD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD, CRYPT, FAST);
+}
+
+#[test]
+fn buffer_pool_reuses_buffers() {
+    let pool = radix64::BufferPool::new(2).with_max_capacity(64);
+    let mut first = pool.checkout();
+    let mut second = pool.checkout();
+    let mut third = pool.checkout();
+    assert_eq!(STD.encode_with_buffer("hello", &mut first), "aGVsbG8=");
+    assert_eq!(
+        STD.decode_with_buffer("aGVsbG8=", &mut second),
+        Ok(&b"hello"[..])
+    );
+    third.extend_from_slice(&[0; 100]);
+    drop((first, second, third));
+    // The oversized buffer was freed.
+    assert_eq!(pool.idle(), 2);
+
+    let reused = pool.checkout();
+    assert!(reused.is_empty());
+    assert!(reused.capacity() > 0);
+    assert_eq!(pool.idle(), 1);
+    assert_eq!(reused.detach().len(), 0);
+    assert_eq!(pool.idle(), 1);
+}
+
+#[test]
+fn decode_to_string_checks_utf8() {
+    let mut s = String::from("hi ");
+    assert_eq!(STD.decode_to("aGVsbG8=", &mut s), Ok(5));
+    assert_eq!(s, "hi hello");
+    match STD.decode_to("/w==", &mut s) {
+        Err(radix64::DecodeUtf8Error::Utf8(err)) => assert_eq!(err.into_bytes(), [0xff]),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(matches!(
+        STD.decode_to("/w=", &mut s),
+        Err(radix64::DecodeUtf8Error::Decode(_))
+    ));
+    assert_eq!(s, "hi hello");
+}
+
+#[test]
+#[should_panic(expected = "output slice too small: 8 bytes required, 7 provided")]
+fn encode_to_slice_checks_len() {
+    let mut buf = [0; 7];
+    STD.encode_to("hello", &mut &mut buf[..]);
+}
+
+#[test]
+fn encode_array_matches_encode() {
+    let input: [u8; 32] = std::array::from_fn(|i| (i * 37) as u8);
+    let padded: [u8; 44] = STD.encode_array(&input);
+    assert_eq!(&padded[..], STD.encode(&input).as_bytes());
+    let unpadded: [u8; 43] = URL_SAFE_NO_PAD.encode_array(&input);
+    assert_eq!(&unpadded[..], URL_SAFE_NO_PAD.encode(&input).as_bytes());
+    let crypt: [u8; 43] = CRYPT.encode_array(&input);
+    assert_eq!(&crypt[..], CRYPT.encode(&input).as_bytes());
+    let empty: [u8; 0] = STD.encode_array(&[]);
+    assert_eq!(empty, [0u8; 0]);
+}
+
+#[test]
+#[should_panic(expected = "output array length does not match the encoded length")]
+fn encode_array_checks_output_len() {
+    let _: [u8; 43] = STD.encode_array(&[0; 32]);
+}
+
+#[test]
+fn decode_int_rejects_non_canonical() {
+    use radix64::{DecodeError, DecodeIntError};
+    assert_eq!(URL_SAFE_NO_PAD.encode_u64(0), "AA");
+    assert_eq!(URL_SAFE_NO_PAD.encode_u64(255), "_w");
+    assert_eq!(URL_SAFE_NO_PAD.encode_u64(256), "AQA");
+    assert_eq!(URL_SAFE_NO_PAD.decode_u64("AA"), Ok(0));
+    assert_eq!(
+        URL_SAFE_NO_PAD.decode_u64("AAE"),
+        Err(DecodeIntError::LeadingZero)
+    );
+    assert_eq!(
+        URL_SAFE_NO_PAD.decode_u64(""),
+        Err(DecodeIntError::InvalidLength {
+            decoded_len: 0,
+            max_len: 8
+        })
+    );
+    let encoded = URL_SAFE_NO_PAD.encode_u128(u64::MAX as u128 + 1);
+    assert_eq!(
+        URL_SAFE_NO_PAD.decode_u64(&encoded),
+        Err(DecodeIntError::InvalidLength {
+            decoded_len: 9,
+            max_len: 8
+        })
+    );
+    assert!(matches!(
+        URL_SAFE_NO_PAD.decode_u64("A$"),
+        Err(DecodeIntError::Decode(DecodeError::InvalidByte(b'$')))
+    ));
+    assert!(matches!(
+        STD.decode_u64("AQ"),
+        Err(DecodeIntError::Decode(_))
+    ));
+}
+
+#[test]
+fn literal_macros_match_runtime() {
+    use radix64::{b64, b64_encode};
+    macro_rules! check {
+        ($( $cfg:ident ),+) => {$({
+            const ENCODED: &str = b64_encode!($cfg, b"\x00\xfb\xff hello world");
+            assert_eq!(ENCODED, $cfg.encode(b"\x00\xfb\xff hello world"));
+            const DECODED: &[u8] = b64!($cfg, ENCODED);
+            assert_eq!(DECODED, b"\x00\xfb\xff hello world");
+            assert_eq!(b64!($cfg, b64_encode!($cfg, "a")), b"a");
+            assert_eq!(b64!($cfg, b64_encode!($cfg, "ab")), b"ab");
+            assert_eq!(b64!($cfg, ""), b"");
+        })+};
+    }
+    check!(STD, STD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD, CRYPT, FAST);
+}
+
+#[test]
+#[should_panic(expected = "invalid trailing bits in base64 input")]
+fn decode_const_rejects_trailing_bits() {
+    let _: [u8; 1] = STD.decode_const(b"YR==");
+}
+
+#[test]
+#[should_panic(expected = "invalid byte in base64 input")]
+fn decode_const_rejects_invalid_bytes() {
+    let _: [u8; 3] = STD.decode_const(b"YW*j");
+}
+
+#[test]
+#[should_panic(expected = "output array length does not match the encoded length")]
+fn const_encode_checks_output_len() {
+    let _: [u8; 4] = STD_NO_PAD.encode_const(b"a");
+}
+
+#[test]
+fn output_len_helpers_near_usize_max() {
+    // Lengths that overflow a 16-bit usize when computed as len * 4 / 3.
+    let input = vec![0xA5; 0x5556];
+    let mut encoded = vec![0; STD.encoded_output_len(input.len())];
+    assert_eq!(STD.encode_slice(&input, &mut encoded), encoded.len());
+    assert_eq!(encoded, STD.encode(&input).as_bytes());
+    assert_eq!(STD.decode(&encoded).unwrap(), input);
+
+    assert_eq!(
+        STD.maximum_decoded_output_len(usize::MAX),
+        usize::MAX / 4 * 3 + 2
+    );
+    assert_eq!(
+        STD_NO_PAD.encoded_output_len(usize::MAX / 4 * 3),
+        usize::MAX / 4 * 4
+    );
+}
+
+#[test]
+#[should_panic(expected = "encoded output length overflows usize")]
+fn encoded_output_len_overflow_panics() {
+    STD.encoded_output_len(usize::MAX);
+}
+
+#[test]
+fn try_encode_slice_reports_required_len() {
+    for &len in &[0, 1, 2, 3, 100] {
+        let input = vec![0x5a; len];
+        let encoded = STD.encode(&input);
+        for provided in 0..encoded.len() {
+            let mut output = vec![0; provided];
+            let err = STD.try_encode_slice(&input, &mut output).unwrap_err();
+            assert_eq!((err.required(), err.provided()), (encoded.len(), provided));
+            assert!(output.iter().all(|&b| b == 0));
+        }
+        let mut output = vec![0; encoded.len() + 1];
+        assert_eq!(STD.try_encode_slice(&input, &mut output), Ok(encoded.len()));
+        assert_eq!(&output[..encoded.len()], encoded.as_bytes());
+    }
+}
+
+#[test]
+fn try_decode_slice_reports_required_len() {
+    use radix64::{CustomConfig, DecodeError, DecodeSliceError};
+    fn check(result: Result<usize, DecodeSliceError>, required: usize, provided: usize) {
+        match result {
+            Err(DecodeSliceError::BufferTooSmall(err)) => {
+                assert_eq!((err.required(), err.provided()), (required, provided))
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+    let wrapped = CustomConfig::based_on(STD)
+        .with_line_wrap(4, "\r\n")
+        .build()
+        .unwrap();
+    for &len in &[0, 1, 2, 3, 100] {
+        let input = vec![0x5a; len];
+        let encoded = STD.encode(&input);
+        let wrapped_encoded = wrapped.encode(&input);
+        for provided in 0..len {
+            let mut output = vec![0; provided];
+            check(STD.try_decode_slice(&encoded, &mut output), len, provided);
+            check(
+                wrapped.try_decode_slice(&wrapped_encoded, &mut output),
+                len,
+                provided,
+            );
+            assert!(output.iter().all(|&b| b == 0));
+        }
+        let mut output = vec![0; len];
+        assert_eq!(STD.try_decode_slice(&encoded, &mut output), Ok(len));
+        assert_eq!(output, input);
+        let mut output = vec![0; len];
+        assert_eq!(
+            wrapped.try_decode_slice(&wrapped_encoded, &mut output),
+            Ok(len)
+        );
+        assert_eq!(output, input);
+    }
+    // Invalid input is reported even when the output slice is too small.
+    assert_eq!(
+        STD.try_decode_slice("aGVsbG8", &mut []),
+        Err(DecodeSliceError::Decode(DecodeError::InvalidLength {
+            length: 7,
+            remainder: 3,
+            padding_expected: true,
+        }))
+    );
+}
+
+#[test]
+fn decode_in_place_removes_line_separators() {
+    let wrapped = radix64::CustomConfig::based_on(STD)
+        .with_line_wrap(76, "\r\n")
+        .build()
+        .unwrap();
+    let input: Vec<u8> = (0..=255).cycle().take(3000).collect();
+    let mut encoded = wrapped.encode(&input).into_bytes();
+    assert_eq!(wrapped.decode_in_place(&mut encoded), Ok(input.as_slice()));
+}
+
+#[test]
+fn encode_capped_and_writer_limit() {
+    use std::io::Write;
+
+    let wrapped = radix64::CustomConfig::with_alphabet(
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    )
+    .with_padding(b'=')
+    .with_line_wrap(4, "\n")
+    .build()
+    .unwrap();
+    for len in 0..30 {
+        let input = vec![0x42; len];
+        let encoded = wrapped.encode(&input);
+        assert_eq!(
+            wrapped.encode_capped(&input, encoded.len()),
+            Ok(encoded.clone())
+        );
+        if !encoded.is_empty() {
+            let err = wrapped
+                .encode_capped(&input, encoded.len() - 1)
+                .unwrap_err();
+            assert_eq!(err.limit(), encoded.len() as u64 - 1);
+        }
+        assert_eq!(
+            STD_NO_PAD.encode_capped(&input, STD_NO_PAD.encoded_output_len(len)),
+            Ok(STD_NO_PAD.encode(&input))
+        );
+    }
+
+    let mut writer = EncodeWriter::new(&wrapped, Vec::new()).with_max_encoded_bytes(9);
+    writer.write_all(b"abc").unwrap();
+    let err = writer.write(b"defg").unwrap_err();
+    let limit_exceeded = err
+        .get_ref()
+        .unwrap()
+        .downcast_ref::<radix64::io::LimitExceeded>();
+    assert_eq!(limit_exceeded.map(|err| err.limit()), Some(9));
+    // The rejected write consumed nothing, so smaller writes still fit.
+    writer.write_all(b"def").unwrap();
+    writer.finish_message(b"|").unwrap();
+    // The limit applies to each message.
+    writer.write_all(b"ghijkl").unwrap();
+    assert_eq!(writer.finish().unwrap(), b"YWJj\nZGVm|Z2hp\namts");
+}
+
+#[test]
+fn decode_reader_max_decoded_bytes_u64_max() {
+    use radix64::io::DecodeReader;
+    use std::io::Read;
+
+    let encoded = STD.encode("hello world");
+    let mut reader = DecodeReader::new(STD, encoded.as_bytes()).with_max_decoded_bytes(u64::MAX);
+    let mut decoded = Vec::new();
+    reader.read_to_end(&mut decoded).unwrap();
+    assert_eq!(decoded, b"hello world");
+}
+
+#[test]
+fn progress_reported_at_interval() {
+    use radix64::io::{copy_decode_with_progress, copy_encode_with_progress, DecodeReader};
+    use std::io::{Read, Write};
+    use std::sync::{Arc, Mutex};
+
+    fn recorder() -> (
+        Arc<Mutex<Vec<u64>>>,
+        impl FnMut(u64) + Send + Sync + 'static,
+    ) {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let record = reports.clone();
+        (reports, move |n| record.lock().unwrap().push(n))
+    }
+
+    let (reports, callback) = recorder();
+    let mut writer = EncodeWriter::new(STD, Vec::new()).with_progress(1000, callback);
+    for _ in 0..10 {
+        writer.write_all(&[0x42; 300]).unwrap();
+    }
+    let encoded = writer.finish().unwrap();
+    let reported = reports.lock().unwrap().clone();
+    assert_eq!(reported.last(), Some(&3000));
+    assert!(reported[0] >= 1000);
+    assert!(reported
+        .windows(2)
+        .all(|w| w[1] - w[0] >= 1000 || w[1] == 3000));
+
+    let (reports, callback) = recorder();
+    let mut reader = DecodeReader::new(STD, encoded.as_slice()).with_progress(1500, callback);
+    let mut decoded = Vec::new();
+    reader.read_to_end(&mut decoded).unwrap();
+    assert_eq!(decoded, vec![0x42; 3000]);
+    let reported = reports.lock().unwrap().clone();
+    assert_eq!(reported.last(), Some(&4000));
+    assert!(reported[0] >= 1500);
+    assert!(reported
+        .windows(2)
+        .all(|w| w[1] - w[0] >= 1500 || w[1] == 4000));
+
+    let (reports, callback) = recorder();
+    let mut encoded = Vec::new();
+    copy_encode_with_progress(STD, &mut &[0x42; 3000][..], &mut encoded, 1 << 20, callback)
+        .unwrap();
+    assert_eq!(*reports.lock().unwrap(), [3000]);
+
+    let (reports, callback) = recorder();
+    copy_decode_with_progress(STD, &mut encoded.as_slice(), &mut io::sink(), 0, callback).unwrap();
+    assert_eq!(*reports.lock().unwrap(), [4000]);
+}
+
+#[test]
+fn encode_writer_formats_text() {
+    use std::fmt::Write;
+
+    let mut writer = EncodeWriter::new(URL_SAFE, Vec::new());
+    let mut expected = String::new();
+    for id in 0..50 {
+        write!(writer, "{:>4}:{:?};", id, 1.5 * id as f64).unwrap();
+        write!(expected, "{:>4}:{:?};", id, 1.5 * id as f64).unwrap();
+    }
+    assert_eq!(
+        writer.finish().unwrap(),
+        URL_SAFE.encode(&expected).into_bytes()
+    );
+
+    let mut writer = EncodeWriter::new(STD, Vec::new()).with_max_encoded_bytes(4);
+    writer.write_str("abc").unwrap();
+    assert_eq!(write!(writer, "{}", 'd'), Err(std::fmt::Error));
+}
+
+#[test]
+fn decode_reports_first_invalid_byte() {
+    let encoded = STD.encode(&[0x5a; 150]).into_bytes();
+    for idx in 0..encoded.len() - 1 {
+        let mut invalid = encoded.clone();
+        invalid[idx] = b'!';
+        invalid[idx + 1..]
+            .iter_mut()
+            .step_by(5)
+            .for_each(|b| *b = b'*');
+        assert_eq!(
+            STD.decode(&invalid),
+            Err(radix64::DecodeError::InvalidByte(b'!'))
+        );
+        assert_eq!(
+            custom_configs::STD.decode(&invalid),
+            Err(radix64::DecodeError::InvalidByte(b'!'))
+        );
+    }
+}
+
+#[test]
+fn truncated_final_chunk_is_left_over() {
+    use radix64::io::DecodeReader;
+
+    fn check<C: Config>(config: C, data: &[u8]) {
+        let strip = |s: &[u8]| -> Vec<u8> {
+            s.iter()
+                .cloned()
+                .filter(|&b| b != b'\r' && b != b'\n')
+                .collect()
+        };
+        let encoded = config.encode(data);
+        for cut in 0..=encoded.len() {
+            let (head, tail) = encoded.split_at(cut);
+            let (decoded, leftover_len) = config.decode_truncated(head).unwrap();
+            assert_eq!(decoded, &data[..decoded.len()]);
+            let leftover = &head.as_bytes()[head.len() - leftover_len..];
+            assert!(strip(leftover).len() < 4);
+            let rest = [leftover, tail.as_bytes()].concat();
+            assert_eq!(config.decode(&rest).unwrap(), &data[decoded.len()..]);
+
+            let mut reader = DecodeReader::new(config, head.as_bytes()).with_truncated_eof();
+            let streamed =
+                read_to_end_using_varying_buffer_sizes(&mut reader, [1, 2, 5].iter().cloned())
+                    .unwrap();
+            assert_eq!(streamed, decoded);
+            assert_eq!(strip(reader.leftover()), strip(leftover));
+        }
+    }
+
+    let wrapped = radix64::CustomConfig::with_alphabet(
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    )
+    .with_padding(b'=')
+    .with_line_wrap(6, "\r\n")
+    .build()
+    .unwrap();
+    let data: Vec<u8> = (0..40).collect();
+    check(STD, &data);
+    check(URL_SAFE_NO_PAD, &data);
+    check(&wrapped, &data);
+    assert_eq!(STD_NO_PAD.decode_truncated("AAE").unwrap(), (vec![], 3));
+    assert!(STD.decode_truncated("AA!=").is_err());
+}
+
+#[test]
+fn armor_reader_skips_armor_lines() {
+    use radix64::io::ArmorReader;
+    use std::io::{BufReader, Read};
+
+    let data: Vec<u8> = (0..=255).collect();
+    let body = STD.encode(&data);
+    let mut pem = String::new();
+    for (label, line_len) in &[("CERTIFICATE", 64), ("PRIVATE KEY", 76)] {
+        pem.push_str(&format!("\r\n-----BEGIN {}-----\r\n", label));
+        for line in body.as_bytes().chunks(*line_len) {
+            pem.push_str(std::str::from_utf8(line).unwrap());
+            pem.push_str("\r\n\r\n");
+        }
+        pem.push_str(&format!("-----END {}-----\r\n", label));
+    }
+
+    let mut rdr = BufReader::with_capacity(7, pem.as_bytes());
+    for &label in &["CERTIFICATE", "PRIVATE KEY"] {
+        let mut reader = ArmorReader::new(STD, rdr);
+        let mut decoded = Vec::new();
+        io::copy(&mut reader, &mut decoded).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(reader.label(), Some(label));
+        rdr = reader.into_inner();
+    }
+
+    let mut unarmored = ArmorReader::new(STD, "bXkg\n\nbWVzc2FnZQ==\n".as_bytes());
+    let mut decoded = Vec::new();
+    unarmored.read_to_end(&mut decoded).unwrap();
+    assert_eq!(decoded, b"my message");
+    assert_eq!(unarmored.label(), None);
+
+    for &invalid in &[
+        "-----BEGIN A-----\nbXkg\n-----END B-----\n",
+        "-----BEGIN A-----\nbXkg\n",
+        "bXkg\n-----END A-----\n",
+        "-----BEGIN A-----\n-----BEGIN A-----\n",
+    ] {
+        let err = ArmorReader::new(STD, invalid.as_bytes())
+            .read_to_end(&mut Vec::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
+
+#[test]
+fn encoded_output_len_wrapped_matches_wrapped_output() {
+    const PEM_LINE_LEN: usize = STD.encoded_output_len_wrapped(48, 64, 1);
+    assert_eq!(PEM_LINE_LEN, 64);
+    for width in 1..10 {
+        let wrapped = radix64::CustomConfig::with_alphabet(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        )
+        .with_padding(b'=')
+        .with_line_wrap(width, "\r\n")
+        .build()
+        .unwrap();
+        for len in 0..40 {
+            let expected = wrapped.encode(&vec![0; len]).len();
+            assert_eq!(STD.encoded_output_len_wrapped(len, width, 2), expected);
+            assert_eq!(wrapped.encoded_output_len(len), expected);
+            // Wrapping configured on the config is ignored.
+            assert_eq!(
+                wrapped.encoded_output_len_wrapped(len, 100, 2),
+                STD.encoded_output_len(len)
+            );
+            assert_eq!(
+                STD_NO_PAD.encoded_output_len_wrapped(len, width, 0),
+                STD_NO_PAD.encoded_output_len(len)
+            );
+        }
+    }
+}
+
+#[test]
+fn decode_utf16_rejects_non_ascii() {
+    let input: Vec<u16> = "aGVs\u{e9}G8=".encode_utf16().collect();
+    assert_eq!(
+        radix64::STD.decode_utf16(&input),
+        Err(radix64::DecodeError::InvalidByte(0xFF))
+    );
+}
+
+#[test]
+fn display_from_reader_reports_io_error() {
+    use std::fmt::Write;
+    use std::io::Read;
+
+    let reader = (&b"abc"[..]).chain(FailingReader);
+    let display = radix64::DisplayFromReader::new(radix64::STD, reader);
+    let mut output = String::new();
+    assert!(write!(output, "{}", display).is_err());
+    let err = display.take_error().expect("no error recorded");
+    assert_eq!(err.kind(), std::io::ErrorKind::Other);
+    assert!(display.take_error().is_none());
+}
+
+struct FailingReader;
+
+impl std::io::Read for FailingReader {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("read failed"))
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn encode_and_decode_buf() {
+    use bytes::Buf;
+
+    let input: Vec<u8> = (0..=255).collect();
+    let encoded = STD.encode(&input);
+    let mut buf = input[..100].chain(&input[100..101]).chain(&input[101..]);
+    assert_eq!(STD.encode_buf(&mut buf), encoded);
+    assert!(!buf.has_remaining());
+
+    let buf = encoded.as_bytes()[..7].chain(&encoded.as_bytes()[7..]);
+    assert_eq!(STD.decode_buf(buf).unwrap(), input);
+    assert_eq!(
+        STD.decode_buf(&b"aGVsbG8"[..]),
+        Err(radix64::DecodeError::InvalidLength {
+            length: 7,
+            remainder: 3,
+            padding_expected: true,
+        })
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn pod_slices_roundtrip() {
+    use radix64::PodDecodeError;
+
+    let values: Vec<u64> = (0..20).map(|i| i * 0x0101_0101_0101).collect();
+    for len in 0..values.len() {
+        let encoded = STD.encode_pod_slice(&values[..len]);
+        assert_eq!(encoded.len(), STD.encoded_output_len(len * 8));
+        assert_eq!(
+            STD.decode_pod_vec::<u64, _>(&encoded).unwrap(),
+            &values[..len]
+        );
+        assert_eq!(
+            custom_configs::STD
+                .decode_pod_vec::<u64, _>(&encoded)
+                .unwrap(),
+            &values[..len]
+        );
+    }
+    let floats = [0.5f32, -1.0, f32::MAX];
+    assert_eq!(
+        URL_SAFE_NO_PAD
+            .decode_pod_vec::<f32, _>(&URL_SAFE_NO_PAD.encode_pod_slice(&floats))
+            .unwrap(),
+        floats
+    );
+    assert_eq!(
+        STD.decode_pod_vec::<u32, _>(&STD.encode(&[1, 2, 3, 4, 5])),
+        Err(PodDecodeError::InvalidLength {
+            decoded_len: 5,
+            value_size: 4
+        })
+    );
+    assert_eq!(
+        STD.decode_pod_vec::<u32, _>("AAAA!AAA"),
+        Err(PodDecodeError::Decode(radix64::DecodeError::InvalidByte(
+            b'!'
+        )))
+    );
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn borsh_base64_roundtrip() {
+    use radix64::{configs::UrlSafeNoPad, Base64};
+
+    let value: Base64<Vec<u8>, UrlSafeNoPad> = Base64::new(vec![0xfb, 0xff, 0x00]);
+    let bytes = borsh::to_vec(&value).unwrap();
+    assert_eq!(bytes, borsh::to_vec("-_8A").unwrap());
+    let decoded: Base64<Vec<u8>, UrlSafeNoPad> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, value);
+    let invalid = borsh::to_vec("-_8A=").unwrap();
+    assert!(borsh::from_slice::<Base64<Vec<u8>, UrlSafeNoPad>>(&invalid).is_err());
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv_base64_roundtrip() {
+    use radix64::Base64;
+    use rkyv::rancor::Error;
+
+    let value: Base64<Vec<u8>> = Base64::new(b"my message".to_vec());
+    let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+    let archived = rkyv::access::<rkyv::string::ArchivedString, Error>(&bytes).unwrap();
+    assert_eq!(archived.as_str(), "bXkgbWVzc2FnZQ==");
+    let decoded = rkyv::from_bytes::<Base64<Vec<u8>>, Error>(&bytes).unwrap();
+    assert_eq!(decoded, value);
+    let invalid = rkyv::to_bytes::<Error>(&String::from("bXkgbWVzc2FnZQ")).unwrap();
+    assert!(rkyv::from_bytes::<Base64<Vec<u8>>, Error>(&invalid).is_err());
+}
+
+#[cfg(feature = "unstable-block")]
+#[test]
+fn block_layer_matches_scalar() {
+    use radix64::block::{
+        BlockDecoder, BlockEncoder, IntoBlockDecoder, IntoBlockEncoder, ScalarBlockDecoder,
+        ScalarBlockEncoder,
+    };
+    use std::mem::MaybeUninit;
+    // The block layer reports how many bytes it wrote, which are initialized.
+    fn written(buf: &[MaybeUninit<u8>], len: usize) -> Vec<u8> {
+        buf[..len]
+            .iter()
+            .map(|b| unsafe { b.assume_init() })
+            .collect()
+    }
+    let input: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+    macro_rules! check {
+        ($( $cfg:ident ),+) => {$({
+            let mut encoded = vec![MaybeUninit::uninit(); 2000];
+            let mut scalar = vec![MaybeUninit::uninit(); 2000];
+            let (input_idx, output_idx) = $cfg.into_block_encoder().encode_blocks(&input, &mut encoded);
+            let (scalar_input_idx, scalar_output_idx) =
+                ScalarBlockEncoder::new($cfg).encode_blocks(&input[..input_idx], &mut scalar);
+            let encoded = written(&encoded, output_idx);
+            assert_eq!(input_idx % 3, 0);
+            assert!(input.len() - input_idx < 100);
+            assert_eq!(encoded, $cfg.encode(&input[..input_idx]).into_bytes());
+            assert!(scalar_input_idx <= input_idx);
+            assert_eq!(written(&scalar, scalar_output_idx), &encoded[..scalar_output_idx]);
+
+            let mut decoded = vec![MaybeUninit::uninit(); 1000];
+            let (encoded_idx, decoded_idx) = $cfg
+                .into_block_decoder()
+                .decode_blocks(&encoded, &mut decoded)
+                .unwrap();
+            assert_eq!(encoded_idx % 4, 0);
+            assert_eq!(written(&decoded, decoded_idx), &input[..decoded_idx]);
+            let (_, scalar_decoded_idx) = ScalarBlockDecoder::new($cfg)
+                .decode_blocks(&encoded, &mut scalar)
+                .unwrap();
+            assert_eq!(written(&scalar, scalar_decoded_idx), &input[..scalar_decoded_idx]);
+        })+};
+    }
+    check!(STD, STD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD, CRYPT, FAST);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec_output_buffers() {
+    use arrayvec::{ArrayString, ArrayVec};
+    let mut s = ArrayString::<12>::from("x=").unwrap();
+    assert_eq!(STD.try_encode_to("hello", &mut s), Ok(8));
+    assert_eq!(s.as_str(), "x=aGVsbG8=");
+    assert_eq!(
+        STD.try_encode_to("hello", &mut s).unwrap_err().required(),
+        8
+    );
+    assert_eq!(s.as_str(), "x=aGVsbG8=");
+
+    let mut v = ArrayVec::<u8, 8>::new();
+    assert_eq!(STD.try_decode_to("aGVsbG8=", &mut v), Ok(5));
+    assert_eq!(&v[..], b"hello");
+    assert!(matches!(
+        STD.try_decode_to("aGVsbG8=", &mut v),
+        Err(radix64::DecodeSliceError::BufferTooSmall(_))
+    ));
+    v.clear();
+    assert_eq!(
+        STD.try_decode_to("aGVsbG*=", &mut v),
+        Err(radix64::DecodeSliceError::Decode(
+            radix64::DecodeError::InvalidByte(b'*')
+        ))
+    );
+    assert!(v.is_empty());
+
+    let mut s = ArrayString::<8>::new();
+    assert_eq!(STD.decode_to("aGk=", &mut s), Ok(2));
+    assert!(matches!(
+        STD.decode_to("/w==", &mut s),
+        Err(radix64::DecodeUtf8Error::Utf8(_))
+    ));
+    assert_eq!(s.as_str(), "hi");
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_output_buffers() {
+    let mut s = heapless::String::<12>::new();
+    assert_eq!(URL_SAFE_NO_PAD.try_encode_to(b"\xfb\xff", &mut s), Ok(3));
+    assert_eq!(s.as_str(), "-_8");
+    let input = [0; 9];
+    assert_eq!(
+        URL_SAFE_NO_PAD
+            .try_encode_to(&input, &mut s)
+            .unwrap_err()
+            .provided(),
+        9
+    );
+
+    let mut v = heapless::Vec::<u8, 64>::new();
+    let input: Vec<u8> = (0..48).collect();
+    assert_eq!(STD.try_decode_to(&STD.encode(&input), &mut v), Ok(48));
+    assert_eq!(&v[..], &input[..]);
+    assert!(STD.try_decode_to(&STD.encode(&input), &mut v).is_err());
+    assert_eq!(v.len(), 48);
+
+    let mut s = heapless::String::<64>::new();
+    assert_eq!(
+        STD.decode_to(&STD.encode("hello world, hello world, hello world"), &mut s),
+        Ok(37)
+    );
+    assert_eq!(s.as_str(), "hello world, hello world, hello world");
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_matches_sequential() {
+    let input: Vec<u8> = (0..3_000_001u32).map(|i| (i * 7) as u8).collect();
+    let encoded = STD.encode_parallel(&input);
+    assert_eq!(encoded, STD.encode(&input));
+    assert_eq!(STD.decode_parallel(&encoded), Ok(input.clone()));
+    let encoded = URL_SAFE_NO_PAD.encode_parallel(&input);
+    assert_eq!(encoded, URL_SAFE_NO_PAD.encode(&input));
+    assert_eq!(URL_SAFE_NO_PAD.decode_parallel(&encoded), Ok(input.clone()));
+
+    // Errors, including padding in the middle of the input, match decode.
+    let mut invalid = STD.encode(&input).into_bytes();
+    invalid[2_000_000] = b'*';
+    invalid[3_000_000] = b'=';
+    assert_eq!(STD.decode_parallel(&invalid), STD.decode(&invalid));
+    invalid[2_000_000] = b'A';
+    assert!(STD.decode_parallel(&invalid).is_err());
+    assert_eq!(STD.decode_parallel(&invalid), STD.decode(&invalid));
+
+    let wrapped = radix64::CustomConfig::based_on(STD)
+        .with_line_wrap(76, b"\r\n")
+        .build()
+        .unwrap();
+    let encoded = wrapped.encode_parallel(&input);
+    assert_eq!(encoded, wrapped.encode(&input));
+    assert_eq!(wrapped.decode_parallel(&encoded), Ok(input));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn decode_to_writer_streams_field() {
+    use radix64::serde::DecodeToWriter;
+    use serde::de::{DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor};
+    use std::fmt;
+
+    // Routes the "data" field of an object to the writer.
+    struct Document<'a>(&'a mut Vec<u8>);
+
+    impl<'de> Visitor<'de> for Document<'_> {
+        type Value = u64;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a document")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<u64, A::Error> {
+            let mut written = 0;
+            while let Some(key) = map.next_key::<String>()? {
+                if key == "data" {
+                    written = map.next_value_seed(DecodeToWriter::new(STD, &mut *self.0))?;
+                } else {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+            Ok(written)
+        }
+    }
+
+    let input: Vec<u8> = (0..200_000u32).map(|i| (i * 7) as u8).collect();
+    let json = format!(
+        r#"{{"name": "a.bin", "data": "{}", "n": 1}}"#,
+        STD.encode(&input)
+    );
+    let mut sink = Vec::new();
+    let mut de = serde_json::Deserializer::from_slice(json.as_bytes());
+    let written = de.deserialize_map(Document(&mut sink)).unwrap();
+    assert_eq!(written, input.len() as u64);
+    assert_eq!(sink, input);
+
+    let mut de = serde_json::Deserializer::from_str(r#""aGVsbG8""#);
+    let err = DecodeToWriter::new(STD, Vec::new())
+        .deserialize(&mut de)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("invalid length 7"), "{}", err);
+
+    let mut de = serde_json::Deserializer::from_str(r#""aGVsbG8=""#);
+    let err = DecodeToWriter::new(STD, &mut [0u8; 2][..])
+        .deserialize(&mut de)
+        .unwrap_err();
+    assert!(
+        err.to_string().starts_with("failed to write decoded data"),
+        "{}",
+        err
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_base64_fields() {
+    use radix64::configs::{Std, UrlSafeNoPad};
+    use radix64::serde::Base64;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Message {
+        body: Base64<Vec<u8>>,
+        signature: Base64<Vec<u8>, UrlSafeNoPad>,
+    }
+
+    let msg = Message {
+        body: Base64::new(b"my message".to_vec()),
+        signature: Base64::new(vec![0xfb, 0xff]),
+    };
+    let json = serde_json::to_string(&msg).unwrap();
+    assert_eq!(json, r#"{"body":"bXkgbWVzc2FnZQ==","signature":"-_8"}"#);
+    assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), msg);
+
+    // The signature must use the url safe alphabet without padding.
+    assert!(serde_json::from_str::<Message>(r#"{"body":"","signature":"+/8="}"#).is_err());
+    assert!(serde_json::from_str::<Base64<Vec<u8>, Std>>(r#""+/8=""#).is_ok());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_with_modules() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Fields {
+        #[serde(with = "radix64::serde::std")]
+        std: Vec<u8>,
+        #[serde(with = "radix64::serde::std_no_pad")]
+        std_no_pad: Vec<u8>,
+        #[serde(with = "radix64::serde::url_safe")]
+        url_safe: [u8; 2],
+        #[serde(with = "radix64::serde::url_safe_no_pad")]
+        url_safe_no_pad: [u8; 2],
+        #[serde(with = "radix64::serde::crypt")]
+        crypt: Vec<u8>,
+        #[serde(with = "radix64::serde::fast")]
+        fast: Vec<u8>,
+    }
+
+    let bytes = vec![0xfb, 0xff];
+    let fields = Fields {
+        std: bytes.clone(),
+        std_no_pad: bytes.clone(),
+        url_safe: [0xfb, 0xff],
+        url_safe_no_pad: [0xfb, 0xff],
+        crypt: bytes.clone(),
+        fast: bytes.clone(),
+    };
+    let json = serde_json::to_value(&fields).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "std": STD.encode(&bytes),
+            "std_no_pad": STD_NO_PAD.encode(&bytes),
+            "url_safe": URL_SAFE.encode(&bytes),
+            "url_safe_no_pad": URL_SAFE_NO_PAD.encode(&bytes),
+            "crypt": CRYPT.encode(&bytes),
+            "fast": FAST.encode(&bytes),
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<Fields>(json.clone()).unwrap(),
+        fields
+    );
+
+    let mut wrong_len = json.clone();
+    wrong_len["url_safe"] = URL_SAFE.encode(&[1, 2, 3]).into();
+    let err = serde_json::from_value::<Fields>(wrong_len).unwrap_err();
+    assert!(err.to_string().contains("invalid length 3"), "{}", err);
+
+    let mut invalid = json;
+    invalid["std"] = "+/8".into();
+    assert!(serde_json::from_value::<Fields>(invalid).is_err());
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn clap_value_parser_errors() {
+    use clap::{value_parser, Arg, Command};
+    use radix64::{cli::Base64ValueParser, configs::UrlSafeNoPad, Base64};
+
+    let cmd = Command::new("test")
+        .arg(
+            Arg::new("key")
+                .long("key")
+                .value_parser(Base64ValueParser::new(STD)),
+        )
+        .arg(
+            Arg::new("nonce")
+                .long("nonce")
+                .value_parser(value_parser!(Base64<Vec<u8>, UrlSafeNoPad>)),
+        );
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--key", "aGk/Pw==", "--nonce", "aGk_Pw"])
+        .unwrap();
+    assert_eq!(matches.get_one::<Vec<u8>>("key").unwrap(), b"hi??");
+    let nonce = matches.get_one::<Base64<Vec<u8>, UrlSafeNoPad>>("nonce");
+    assert_eq!(nonce.unwrap().as_slice(), b"hi??");
+
+    let error = |args: &[&str]| {
+        let err = cmd.clone().try_get_matches_from(args).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+        err.to_string()
+    };
+    let msg = error(&["test", "--key", "a\u{e9}/Pw=="]);
+    assert!(
+        msg.contains("invalid character '\u{e9}' at position 1"),
+        "{}",
+        msg
+    );
+    let msg = error(&["test", "--key", "aGk"]);
+    assert!(msg.contains("invalid length 3"), "{}", msg);
+    let msg = error(&["test", "--key", "aGl="]);
+    assert!(
+        msg.contains("'l' at position 2 has non-zero trailing bits"),
+        "{}",
+        msg
+    );
+    let msg = error(&["test", "--nonce", "aGk/Pw"]);
+    assert!(
+        msg.contains("invalid character '/' at position 3"),
+        "{}",
+        msg
+    );
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn axum_extractors_and_response() {
+    use axum::body::{to_bytes, Body};
+    use axum::extract::{Path, Query};
+    use axum::http::{header, Request, StatusCode};
+    use axum::routing::{get, post};
+    use radix64::{configs::UrlSafeNoPad, Base64};
+    use tower::ServiceExt;
+
+    #[derive(serde::Deserialize)]
+    struct Params {
+        data: Base64<Vec<u8>, UrlSafeNoPad>,
+    }
+
+    let app = axum::Router::new()
+        .route("/echo", post(|body: Base64<Vec<u8>>| async move { body }))
+        .route(
+            "/path/{data}",
+            get(
+                |Path(data): Path<Base64<Vec<u8>, UrlSafeNoPad>>| async move {
+                    Base64::<_>::new(data.into_inner())
+                },
+            ),
+        )
+        .route(
+            "/query",
+            get(|Query(params): Query<Params>| async move {
+                Base64::<_>::new(params.data.into_inner())
+            }),
+        );
+
+    let request = |method: &str, uri: &str, body: &'static str| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::from(body))
+            .unwrap()
+    };
+    let cases = vec![
+        (
+            request("POST", "/echo", "aGk/Pw=="),
+            StatusCode::OK,
+            "aGk/Pw==",
+        ),
+        (
+            request("GET", "/path/aGk_Pw", ""),
+            StatusCode::OK,
+            "aGk/Pw==",
+        ),
+        (
+            request("GET", "/query?data=aGk_Pw", ""),
+            StatusCode::OK,
+            "aGk/Pw==",
+        ),
+        (
+            request("POST", "/echo", "aGk_Pw=="),
+            StatusCode::BAD_REQUEST,
+            "",
+        ),
+        (
+            request("GET", "/path/aGk/Pw", ""),
+            StatusCode::NOT_FOUND,
+            "",
+        ),
+        (request("GET", "/path/a", ""), StatusCode::BAD_REQUEST, ""),
+        (
+            request("GET", "/query?data=a", ""),
+            StatusCode::BAD_REQUEST,
+            "",
+        ),
+    ];
+    for (req, status, body) in cases {
+        let uri = req.uri().clone();
+        let res = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(res.status(), status, "{}", uri);
+        if status == StatusCode::OK {
+            assert_eq!(
+                res.headers()[header::CONTENT_TYPE],
+                "text/plain; charset=utf-8"
+            );
+            let bytes = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+            assert_eq!(bytes, body.as_bytes());
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+#[test]
+fn actix_extractors_and_response() {
+    use actix_web::http::{header, StatusCode};
+    use actix_web::{test, web, App};
+    use radix64::{configs::UrlSafeNoPad, Base64};
+
+    actix_web::rt::System::new().block_on(async {
+        let app = test::init_service(
+            App::new()
+                .route(
+                    "/echo",
+                    web::post().to(|body: Base64<Vec<u8>>| async move { body }),
+                )
+                .route(
+                    "/path/{data}",
+                    web::get().to(
+                        |data: web::Path<Base64<Vec<u8>, UrlSafeNoPad>>| async move {
+                            Base64::<_>::new(data.into_inner().into_inner())
+                        },
+                    ),
+                ),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/echo")
+            .set_payload("aGk/Pw==")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(test::read_body(res).await, "aGk/Pw==");
+
+        let req = test::TestRequest::get().uri("/path/aGk_Pw").to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(test::read_body(res).await, "aGk/Pw==");
+
+        let req = test::TestRequest::post()
+            .uri("/echo")
+            .set_payload("aGk_Pw==")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        // actix-web rejects paths that fail to deserialize as not found.
+        let req = test::TestRequest::get().uri("/path/a").to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    });
+}
+
+#[cfg(all(feature = "avx2", any(target_arch = "x86", target_arch = "x86_64")))]
+#[test]
+fn arch_avx2_kernels_match_config() {
+    use radix64::arch::avx2::{self, Avx2Config};
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    fn check<C: Avx2Config>(config: C) {
+        let input: Vec<u8> = (0..24u8).map(|i| i.wrapping_mul(37) ^ 0xa5).collect();
+        let mut lanes = [0u8; 32];
+        lanes[..12].copy_from_slice(&input[..12]);
+        lanes[16..28].copy_from_slice(&input[12..]);
+        let mut encoded = [0u8; 32];
+        let mut decoded = [0u8; 32];
+        unsafe {
+            let block = _mm256_loadu_si256(lanes.as_ptr() as *const __m256i);
+            let block = avx2::encode_block(config, block);
+            _mm256_storeu_si256(encoded.as_mut_ptr() as *mut __m256i, block);
+            let block = avx2::decode_block(config, block).expect("valid block");
+            _mm256_storeu_si256(decoded.as_mut_ptr() as *mut __m256i, block);
+            let mut invalid = encoded;
+            invalid[13] = b'\n';
+            invalid[20] = b'=';
+            let block = _mm256_loadu_si256(invalid.as_ptr() as *const __m256i);
+            assert_eq!(avx2::decode_block(config, block).err(), Some(13));
+        }
+        assert_eq!(&encoded[..], config.encode(&input).as_bytes());
+        assert_eq!(&decoded[..24], &input[..]);
+    }
+
+    if !is_x86_feature_detected!("avx2") {
+        return;
+    }
+    check(STD);
+    check(STD_NO_PAD);
+    check(URL_SAFE);
+    check(URL_SAFE_NO_PAD);
+    check(CRYPT);
+    check(FAST);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn testing_helpers() {
+    use radix64::{testing, CustomConfig};
+
+    testing::self_check();
+    for &(input, encoded) in testing::RFC4648_VECTORS {
+        assert_eq!(testing::scalar_reference(STD).encode(input), encoded);
+    }
+    testing::assert_decode_agrees(STD, b"Zm9v!");
+
+    let wrapped = CustomConfig::with_alphabet(
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    )
+    .with_line_wrap(7, "\r\n")
+    .build()
+    .unwrap();
+    testing::assert_backends_agree(&wrapped, &[0xfb; 100]);
+    let reference = testing::scalar_reference(&wrapped);
+    assert_eq!(reference.encode(&[0; 6]), "AAAAAAA\r\nA");
+}
+
+tests_for_configs!(STD, STD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD, CRYPT, FAST);
+
+mod line_wrap {
+    use crate::*;
+    use proptest::collection::vec;
+    use proptest::prelude::{any, proptest};
+    use proptest::sample::select;
+    use radix64::CustomConfig;
+
+    lazy_static::lazy_static! {
+        static ref WRAPPED: CustomConfig = CustomConfig::with_alphabet(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        )
+        .with_line_wrap(7, "\r\n")
+        .build()
+        .expect("failed to build custom base64 config");
+    }
+
+    // Encode without wrapping and insert separators the slow way.
+    fn expected_encoding(input: &[u8]) -> String {
+        let encoded = STD.encode(input);
+        let lines: Vec<&str> = encoded
+            .as_bytes()
+            .chunks(7)
+            .map(|line| std::str::from_utf8(line).unwrap())
+            .collect();
+        lines.join("\r\n")
+    }
+
+    // Inputs that span multiple of the blocks used by copy_encode and
+    // copy_decode.
+    #[test]
+    fn copy_encode_and_decode_large_input() {
+        use radix64::io::{copy_decode, copy_encode};
+        for &len in &[0, 1, 49_151, 49_152, 49_153, 98_306, 150_001] {
+            let input: Vec<u8> = (0..len).map(|i| (i * 31 + i / 7) as u8).collect();
+            let mut encoded = Vec::new();
+            copy_encode(&*WRAPPED, &mut input.as_slice(), &mut encoded).unwrap();
+            assert_eq!(encoded, WRAPPED.encode(&input).into_bytes());
+            let mut decoded = Vec::new();
+            copy_decode(&*WRAPPED, &mut encoded.as_slice(), &mut decoded).unwrap();
+            assert_eq!(decoded, input);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn encode_inserts_separators(input in any::<Vec<u8>>()) {
+            assert_eq!(expected_encoding(&input), WRAPPED.encode(&input));
+        }
+
+        #[test]
+        fn roundtrip(input in any::<Vec<u8>>()) {
+            let encoded = WRAPPED.encode(&input);
+            assert_eq!(input, WRAPPED.decode(&encoded).expect("decode failed"));
+        }
+
+        #[test]
+        fn slices_match_contiguous(input in any::<Vec<u8>>(), split in any::<proptest::sample::Index>()) {
+            let at = split.index(input.len() + 1);
+            let encoded = WRAPPED.encode(&input);
+            assert_eq!(WRAPPED.encode_slices(&input[..at], &input[at..]), encoded);
+            let at = split.index(encoded.len() + 1);
+            let decoded = WRAPPED.decode_slices(&encoded[..at], &encoded[at..]);
+            assert_eq!(decoded.expect("decode failed"), input);
+        }
+
+        #[test]
+        fn decode_split_matches_contiguous(input in any::<Vec<u8>>(), size in 1..20usize) {
+            let encoded = WRAPPED.encode(&input);
+            let decoded = WRAPPED.decode_split(encoded.as_bytes().chunks(size));
+            assert_eq!(decoded.expect("decode failed"), input);
+        }
+
+        #[test]
+        fn decode_split_errors_match_decode(input in vec(select(&b"AB=!\r\n"[..]), 0..40), size in 1..5usize) {
+            assert_eq!(WRAPPED.decode_split(input.chunks(size)), WRAPPED.decode(&input));
+        }
+
+        #[test]
+        fn output_buffers_match_encode_and_decode(input in any::<Vec<u8>>()) {
+            let encoded = WRAPPED.encode(&input);
+            let mut s = String::new();
+            WRAPPED.encode_to(&input, &mut s);
+            assert_eq!(s, encoded);
+            let mut buf = vec![0; input.len()];
+            assert_eq!(WRAPPED.decode_to(&encoded, &mut &mut buf[..]), Ok(input.len()));
+            assert_eq!(buf, input);
+        }
+
+        #[test]
+        fn decode_exact_matches_decode(input in proptest::collection::vec(any::<u8>(), 64)) {
+            let encoded = WRAPPED.encode(&input);
+            let decoded: [u8; 64] = WRAPPED.decode_exact(&encoded).expect("decode failed");
+            assert_eq!(&decoded[..], &input[..]);
+        }
+
+        #[test]
+        fn decode_ignores_separator_placement(input in any::<Vec<u8>>(), line_len in 1..20usize) {
+            let encoded = STD.encode(&input);
+            let lines: Vec<&str> = encoded
+                .as_bytes()
+                .chunks(line_len)
+                .map(|line| std::str::from_utf8(line).unwrap())
+                .collect();
+            let rewrapped = lines.join("\r\n") + "\r\n";
+            assert_eq!(input, WRAPPED.decode(&rewrapped).expect("decode failed"));
+        }
+
+        #[test]
+        fn decode_prefix_includes_separated_padding(input in any::<Vec<u8>>()) {
+            let encoded = WRAPPED.encode(&input);
+            let followed = encoded.clone() + "\r\n;\r\n";
+            assert_eq!(WRAPPED.decode_prefix(&followed), Ok((input, encoded.len())));
+        }
+
+        #[test]
+        fn encode_to_fmt_matches_encode(input in any::<Vec<u8>>()) {
+            let mut output = String::new();
+            WRAPPED.encode_to_fmt(&input, &mut output).expect("encode failed");
+            assert_eq!(WRAPPED.encode(&input), output);
+        }
+
+        #[test]
+        fn validate_matches_decode(input in any::<Vec<u8>>(), garbage in any::<String>()) {
+            assert_eq!(Ok(()), WRAPPED.validate(&WRAPPED.encode(&input)));
+            assert_eq!(WRAPPED.decode(&garbage).map(|_| ()), WRAPPED.validate(&garbage));
+        }
+
+        #[test]
+        fn encode_iter_matches_encode(input in any::<Vec<u8>>()) {
+            let encoded = WRAPPED.encode(&input);
+            let chars = WRAPPED.encode_iter(&input);
+            assert_eq!(encoded.len(), chars.len());
+            assert_eq!(encoded, chars.collect::<String>());
+        }
+
+        #[test]
+        fn display_matches_encode(input in any::<Vec<u8>>()) {
+            let display = radix64::Display::new(&*WRAPPED, &input).to_string();
+            assert_eq!(WRAPPED.encode(&input), display);
+        }
+
+        #[test]
+        fn decode_utf16_matches_decode(input in any::<Vec<u8>>()) {
+            let encoded = WRAPPED.encode(&input);
+            let utf16: Vec<u16> = encoded.encode_utf16().collect();
+            assert_eq!(WRAPPED.decode_utf16(&utf16).expect("decode failed"), input);
+        }
+
+        #[test]
+        fn decode_str_matches_decode(input in any::<String>()) {
+            assert_eq!(WRAPPED.decode_str(&input), WRAPPED.decode(&input));
+        }
+
+        #[test]
+        fn output_len_helpers_match(input in any::<Vec<u8>>()) {
+            let encoded = WRAPPED.encode(&input);
+            assert_eq!(WRAPPED.encoded_output_len(input.len()), encoded.len());
+            assert!(input.len() <= WRAPPED.maximum_decoded_output_len(encoded.len()));
+        }
+
+        #[test]
+        fn decoded_len_ignores_separators(input in any::<Vec<u8>>(), line_len in 1..20usize) {
+            let encoded = STD.encode(&input);
+            let lines: Vec<&str> = encoded
+                .as_bytes()
+                .chunks(line_len)
+                .map(|line| std::str::from_utf8(line).unwrap())
+                .collect();
+            let rewrapped = lines.join("\r\n") + "\r\n";
+            assert_eq!(WRAPPED.decoded_len(&rewrapped), Ok(input.len()));
+        }
+
+        #[test]
+        fn encode_writer_finish_message(messages in proptest::collection::vec(any::<Vec<u8>>(), 0..5)) {
+            use std::io::Write;
+            let mut writer = EncodeWriter::new(&*WRAPPED, Vec::new());
+            let mut expected = Vec::new();
+            for message in &messages {
+                writer.write_all(message).unwrap();
+                writer.finish_message(b"|").unwrap();
+                expected.extend_from_slice(WRAPPED.encode(message).as_bytes());
+                expected.push(b'|');
+            }
+            assert_eq!(writer.finish().unwrap(), expected);
+        }
+
+        #[test]
+        fn display_from_reader_matches_encode(input in proptest::collection::vec(any::<u8>(), 0..3000)) {
+            let display = radix64::DisplayFromReader::new(&*WRAPPED, input.as_slice()).to_string();
+            assert_eq!(WRAPPED.encode(&input), display);
+        }
+
+        #[test]
+        fn encode_writer_matches((input, flaky_behavior) in vec_and_flaky_writer_behavior()) {
+            let mut writer_encoded = Vec::new();
+            {
+                let flaky_writer = FlakyWriter::new(&mut writer_encoded, flaky_behavior.into_iter());
+                let mut writer = EncodeWriter::new(&*WRAPPED, flaky_writer);
+                write_all_with_retries(&mut writer, &input);
+                finish_encode_writer_with_retries(writer);
+            }
+            assert_eq!(WRAPPED.encode(&input).as_bytes(), writer_encoded.as_slice());
+        }
+
+        #[test]
+        fn decode_into_matches_decode(input in any::<Vec<u8>>()) {
+            let encoded = WRAPPED.encode(&input);
+            let mut output = Vec::new();
+            WRAPPED.decode_into(&encoded, &mut output).expect("decode failed");
+            assert_eq!(input, output);
+        }
+
+        #[test]
+        fn is_canonical_matches_reencoding(input in any::<Vec<u8>>(), line_len in 1..20usize) {
+            let encoded = WRAPPED.encode(&input);
+            assert!(WRAPPED.is_canonical(&encoded));
+            let rewrapped = expected_encoding(&input)
+                .replace("\r\n", "")
+                .as_bytes()
+                .chunks(line_len)
+                .map(|line| std::str::from_utf8(line).unwrap())
+                .collect::<Vec<_>>()
+                .join("\r\n");
+            assert_eq!(rewrapped == encoded, WRAPPED.is_canonical(&rewrapped));
+            assert!(!WRAPPED.is_canonical(&(encoded + "\r\n")));
+        }
+
+        #[test]
+        fn encode_chunked_roundtrip(input in any::<Vec<u8>>(), max_chars in 4..64usize) {
+            let pieces = WRAPPED.encode_chunked(&input, max_chars);
+            for piece in &pieces {
+                assert!(piece.len() <= max_chars);
+            }
+            assert_eq!(input, WRAPPED.decode_chunked(&pieces).expect("decode failed"));
+        }
+
+        #[test]
+        fn decode_range_matches_slice(input in any::<Vec<u8>>(), start in 0..300usize, len in 0..300usize) {
+            let encoded = WRAPPED.encode(&input);
+            let begin = std::cmp::min(start, input.len());
+            let end = std::cmp::min(start + len, input.len());
+            assert_eq!(WRAPPED.decode_range(&encoded, start..start + len).unwrap(), &input[begin..end]);
+        }
+
+        #[test]
+        fn indexed_decoder_matches_slice(input in any::<Vec<u8>>(), offset in 0..300u64, len in 0..300usize) {
+            use radix64::io::IndexedDecoder;
+            use std::io::Cursor;
+            let mut decoder = IndexedDecoder::new(&*WRAPPED, Cursor::new(WRAPPED.encode(&input)));
+            let start = std::cmp::min(offset as usize, input.len());
+            let expected = &input[start..std::cmp::min(start + len, input.len())];
+            let mut buf = vec![0; expected.len()];
+            decoder.read_exact_at(offset, &mut buf).expect("read failed");
+            assert_eq!(expected, buf.as_slice());
+        }
+
+        #[test]
+        fn pipelined_encode_writer_matches(len in 0..120_000usize, write_size in 1..70_000usize) {
+            use radix64::io::PipelinedEncodeWriter;
+            use std::io::Write;
+            let input: Vec<u8> = (0..len).map(|i| (i * 7) as u8).collect();
+            let mut writer = PipelinedEncodeWriter::new(&*WRAPPED, Vec::new());
+            for chunk in input.chunks(write_size) {
+                writer.write_all(chunk).expect("write failed");
+            }
+            let writer_encoded = writer.finish().expect("finish failed");
+            assert_eq!(WRAPPED.encode(&input).as_bytes(), writer_encoded.as_slice());
+        }
+
+        #[test]
+        fn decode_reader_roundtrip((input, buffer_sizes) in vec_and_buffer_sizes()) {
+            use radix64::io::DecodeReader;
+            use std::io::Cursor;
+            let encoded = WRAPPED.encode(&input);
+            let reader = DecodeReader::new(&*WRAPPED, Cursor::new(encoded));
+            let decoded = read_to_end_using_varying_buffer_sizes(reader, buffer_sizes.iter().cloned()).expect("failed to read to the end of input");
+            assert_eq!(input, decoded);
+        }
+
+        // Separators are split across reads of the inner reader.
+        #[test]
+        fn decode_reader_one_byte_inner_reads(input in any::<Vec<u8>>()) {
+            use radix64::io::DecodeReader;
+            use std::io::Read;
+            let encoded = WRAPPED.encode(&input);
+            let mut reader = DecodeReader::new(&*WRAPPED, OneByteReader(encoded.as_bytes()));
+            let mut decoded = Vec::new();
+            reader.read_to_end(&mut decoded).expect("failed to read to the end of input");
+            assert_eq!(input, decoded);
+        }
+
+        #[test]
+        fn decode_reader_eager_fill(input in any::<Vec<u8>>()) {
+            use radix64::io::DecodeReader;
+            use std::io::Read;
+            let encoded = WRAPPED.encode(&input);
+            let mut reader = DecodeReader::new(&*WRAPPED, OneByteReader(encoded.as_bytes())).with_eager_fill();
+            let mut decoded = Vec::new();
+            reader.read_to_end(&mut decoded).expect("failed to read to the end of input");
+            assert_eq!(input, decoded);
+        }
+    }
+}
+
+// A reader that returns at most one byte per read.
+struct OneByteReader<'a>(&'a [u8]);
+
+impl<'a> io::Read for OneByteReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+        buf[0] = self.0[0];
+        self.0 = &self.0[1..];
+        Ok(1)
+    }
+}